@@ -0,0 +1,63 @@
+use crate::BackendColor;
+
+/// The pixel format of a bitmap which is blitted onto a backend, see
+/// [`DrawingBackend::blit_bitmap_fmt`](crate::DrawingBackend::blit_bitmap_fmt)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitmapFormat {
+    /// 3 bytes per pixel, in the order of red, green and blue
+    Rgb,
+    /// 4 bytes per pixel, in the order of red, green, blue and alpha
+    Rgba,
+    /// 3 bytes per pixel, in the order of blue, green and red
+    Bgr,
+    /// 4 bytes per pixel, in the order of blue, green, red and alpha
+    Bgra,
+}
+
+impl BitmapFormat {
+    /// Get the number of bytes of a single pixel
+    pub fn pixel_size(&self) -> usize {
+        match self {
+            BitmapFormat::Rgb | BitmapFormat::Bgr => 3,
+            BitmapFormat::Rgba | BitmapFormat::Bgra => 4,
+        }
+    }
+
+    /// Check if the format has an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        matches!(self, BitmapFormat::Rgba | BitmapFormat::Bgra)
+    }
+
+    /// Decode a single pixel of this format
+    ///
+    /// - `pixel`: The bytes of the pixel, which should be at least [`BitmapFormat::pixel_size`] long
+    /// - *Returns* The color of the pixel
+    pub fn decode(&self, pixel: &[u8]) -> BackendColor {
+        let (rgb, alpha) = match self {
+            BitmapFormat::Rgb => ((pixel[0], pixel[1], pixel[2]), 255),
+            BitmapFormat::Rgba => ((pixel[0], pixel[1], pixel[2]), pixel[3]),
+            BitmapFormat::Bgr => ((pixel[2], pixel[1], pixel[0]), 255),
+            BitmapFormat::Bgra => ((pixel[2], pixel[1], pixel[0]), pixel[3]),
+        };
+        BackendColor {
+            rgb,
+            alpha: f64::from(alpha) / 255.0,
+        }
+    }
+
+    /// Convert a bitmap of this format into a RGB bitmap, the alpha channel is dropped
+    ///
+    /// - `src`: The source bitmap
+    /// - *Returns* The bitmap in RGB format
+    pub fn to_rgb(&self, src: &[u8]) -> Vec<u8> {
+        if *self == BitmapFormat::Rgb {
+            return src.to_vec();
+        }
+        src.chunks_exact(self.pixel_size())
+            .flat_map(|pixel| {
+                let (r, g, b) = self.decode(pixel).rgb;
+                [r, g, b]
+            })
+            .collect()
+    }
+}
@@ -63,11 +63,13 @@
 */
 use std::error::Error;
 
+mod bitmap_format;
 pub mod rasterizer;
 mod style;
 mod text;
 
-pub use style::{BackendColor, BackendStyle};
+pub use bitmap_format::BitmapFormat;
+pub use style::{BackendColor, BackendStyle, FillPattern};
 pub use text::{text_anchor, BackendTextStyle, FontFamily, FontStyle, FontTransform};
 
 use text_anchor::{HPos, VPos};
@@ -129,6 +131,20 @@ pub trait DrawingBackend: Sized {
         color: BackendColor,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
+    /// Draw many pixels on the drawing backend, which is equivalent to calling `draw_pixel`
+    /// for each of the pixels in order, but the backend may override it to reduce the per-pixel
+    /// overhead, for example, for large scatter plots.
+    /// - `points`: The coordinate and the color of each pixel
+    fn draw_pixels(
+        &mut self,
+        points: &[(BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for &(point, color) in points {
+            self.draw_pixel(point, color)?;
+        }
+        Ok(())
+    }
+
     /// Draw a line on the drawing backend
     /// - `from`: The start point of the line
     /// - `to`: The end point of the line
@@ -142,6 +158,22 @@ pub trait DrawingBackend: Sized {
         rasterizer::draw_line(self, from, to, style)
     }
 
+    /// Draw many independent line segments with the same style, which is equivalent to
+    /// calling `draw_line` for each of the segments, but the backend may override it to
+    /// set up the stroke only once, for example, for meshes and error bars.
+    /// - `segments`: The start point and the end point of each segment
+    /// - `style`: The style of the segments
+    fn draw_segments<S: BackendStyle>(
+        &mut self,
+        segments: &[(BackendCoord, BackendCoord)],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (from, to) in segments {
+            self.draw_line(*from, *to, style)?;
+        }
+        Ok(())
+    }
+
     /// Draw a rectangle on the drawing backend
     /// - `upper_left`: The coordinate of the upper-left corner of the rect
     /// - `bottom_right`: The coordinate of the bottom-right corner of the rect
@@ -157,6 +189,20 @@ pub trait DrawingBackend: Sized {
         rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    /// Fill many rectangles on the drawing backend, which is equivalent to calling
+    /// `draw_rect` with `fill` set for each of the rectangles, but the backend may
+    /// override it to reduce the per-rectangle overhead, for example, for heatmaps.
+    /// - `rects`: The upper-left corner, the bottom-right corner and the color of each rectangle
+    fn fill_rects(
+        &mut self,
+        rects: &[(BackendCoord, BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (upper_left, bottom_right, color) in rects {
+            self.draw_rect(*upper_left, *bottom_right, color, true)?;
+        }
+        Ok(())
+    }
+
     /// Draw a path on the drawing backend
     /// - `path`: The iterator of key points of the path
     /// - `style`: The style of the path
@@ -169,6 +215,16 @@ pub trait DrawingBackend: Sized {
             return Ok(());
         }
 
+        if let Some(pattern) = style.dash_pattern() {
+            // The dash phase carries on across the vertices, so the path is split into the
+            // dashes as a whole, rather than segment by segment
+            let p: Vec<_> = path.into_iter().collect();
+            for dash in rasterizer::dash_path(&p[..], pattern, style.dash_offset()) {
+                self.draw_path(dash, &rasterizer::SolidStroke::of(style))?;
+            }
+            return Ok(());
+        }
+
         if style.stroke_width() == 1 {
             let mut begin: Option<BackendCoord> = None;
             for end in path.into_iter() {
@@ -204,6 +260,23 @@ pub trait DrawingBackend: Sized {
         rasterizer::draw_circle(self, center, radius, style, fill)
     }
 
+    /// Draw an axis-aligned ellipse on the drawing backend. The default implementation
+    /// approximates the ellipse with a polygon, backends with native ellipse support may override
+    /// it
+    /// - `center`: The center coordinate of the ellipse
+    /// - `radius`: The horizontal and vertical radii of the ellipse
+    /// - `style`: The style of the shape
+    /// - `fill`: If the ellipse should be filled
+    fn draw_ellipse<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: (u32, u32),
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        rasterizer::draw_ellipse(self, center, radius, style, fill)
+    }
+
     fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
         &mut self,
         vert: I,
@@ -247,6 +320,47 @@ pub trait DrawingBackend: Sized {
         };
         let trans = style.transform();
         let (w, h) = self.get_size();
+        if let FontTransform::RotateAngle(_) = trans {
+            // Mapping the glyph pixels forward leaves holes in the text rotated by an arbitrary
+            // angle, thus the text is rendered into a buffer which is sampled backward
+            let mut buf = vec![None; (width * height).max(0) as usize];
+            if let Err(font_error) = style.draw(text, (0, 0), |x, y, color| {
+                let (x, y) = (x - min_x, y - min_y);
+                if x >= 0 && x < width && y >= 0 && y < height {
+                    buf[(y * width + x) as usize] = Some(color);
+                }
+                Ok::<(), ()>(())
+            }) {
+                return Err(DrawingErrorKind::FontError(Box::new(font_error)));
+            }
+            let (sin, cos) = trans.degrees().to_radians().sin_cos();
+            let corners = [(0, 0), (width, 0), (0, height), (width, height)]
+                .iter()
+                .map(|&(x, y)| trans.transform(x + dx, y + dy))
+                .collect::<Vec<_>>();
+            let x_range = corners.iter().map(|c| c.0).min().unwrap_or(0)
+                ..=corners.iter().map(|c| c.0).max().unwrap_or(0);
+            let y_range = corners.iter().map(|c| c.1).min().unwrap_or(0)
+                ..=corners.iter().map(|c| c.1).max().unwrap_or(0);
+            for y in y_range {
+                for x in x_range.clone() {
+                    let (px, py) = (pos.0 + x, pos.1 + y);
+                    if px < 0 || px >= w as i32 || py < 0 || py >= h as i32 {
+                        continue;
+                    }
+                    let (fx, fy) = (f64::from(x), f64::from(y));
+                    let sx = (fx * cos + fy * sin).round() as i32 - dx;
+                    let sy = (fy * cos - fx * sin).round() as i32 - dy;
+                    if sx < 0 || sx >= width || sy < 0 || sy >= height {
+                        continue;
+                    }
+                    if let Some(color) = buf[(sy * width + sx) as usize] {
+                        self.draw_pixel((px, py), color)?;
+                    }
+                }
+            }
+            return Ok(());
+        }
         match style.draw(text, (0, 0), |x, y, color| {
             let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
             let (x, y) = (pos.0 + x, pos.1 + y);
@@ -283,46 +397,134 @@ pub trait DrawingBackend: Sized {
         ))
     }
 
+    /// Blur the pixels which have been drawn within a rectangle, which makes the text drawn on
+    /// top of a dense figure readable, for example, behind a legend. Backends which are not able
+    /// to read back the pixels, such as the SVG backend, leave the rectangle untouched.
+    /// - `upper_left`: The coordinate of the upper-left corner of the rect
+    /// - `bottom_right`: The coordinate of the bottom-right corner of the rect
+    /// - `radius`: The radius of the blur in pixels
+    fn blur_rect(
+        &mut self,
+        _upper_left: BackendCoord,
+        _bottom_right: BackendCoord,
+        _radius: u32,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Start a group of the drawing operations which follow, tagged with an id. Interactive
+    /// backends, such as the SVG backend, emit the group with the id, so that the drawn element
+    /// can be picked, for example, by a click handler. Other backends ignore it.
+    /// - `id`: The id of the group
+    fn begin_group(&mut self, _id: &str) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// End the group started by the last call of `begin_group`
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Restrict the following drawing to a clipping box, until the matching `pop_clip` call.
+    /// The nested clipping boxes are intersected with each other. The backends drawing pixels can
+    /// implement this with [`rasterizer::ClipStack`], by rejecting the pixels it doesn't contain.
+    ///
+    /// - `rect`: The upper left and bottom right corners of the box, both of which are inclusive
+    ///
+    /// The default implementation does nothing, see [`DrawingBackend::supports_clipping`].
+    fn push_clip(&mut self, _rect: (BackendCoord, BackendCoord)) {}
+
+    /// Remove the clipping box pushed by the last call of `push_clip`, which does nothing if no
+    /// clipping box is pushed
+    fn pop_clip(&mut self) {}
+
+    /// Check if the backend implements `push_clip` and `pop_clip`. When it does, the high-level
+    /// code clips the shapes partially out of a drawing area to the area, rather than moving
+    /// their points onto the edges of the area.
+    ///
+    /// - *Returns* If the backend supports clipping, which is false by default
+    fn supports_clipping(&self) -> bool {
+        false
+    }
+
+    /// Check if the backend is able to render text. Backends without any font ability, for
+    /// example, a terminal that only plots markers, can return `false`, so that the high-level
+    /// code is able to draw something else instead of the labels, rather than drawing nothing.
+    ///
+    /// - *Returns* If the backend can render text, which is true by default
+    fn supports_text_rendering(&self) -> bool {
+        true
+    }
+
     /// Blit a bitmap on to the backend.
     ///
     /// - `text`: pos the left upper conner of the bitmap to blit
-    /// - `src`: The source of the image
+    /// - `src`: The source of the image, which is a RGB bitmap
     ///
-    /// TODO: The default implementation of bitmap blitting assumes that the bitmap is RGB, but
-    /// this may not be the case. But for bitmap backend it's actually ok if we use the bitmap
-    /// element that matches the pixel format, but we need to fix this.
+    /// The backends which use a different pixel format may take the bitmap in their own format,
+    /// use [`DrawingBackend::blit_bitmap_fmt`] to blit a bitmap in a known format.
     fn blit_bitmap(
         &mut self,
         pos: BackendCoord,
-        (iw, ih): (u32, u32),
+        size: (u32, u32),
         src: &[u8],
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let (w, h) = self.get_size();
+        blit_pixels(self, pos, size, src, BitmapFormat::Rgb)
+    }
 
-        for dx in 0..iw {
-            if pos.0 + dx as i32 >= w as i32 {
+    /// Blit a bitmap in the given pixel format on to the backend. The bitmaps with an alpha
+    /// channel are composited onto the existing content, rather than overwriting it.
+    ///
+    /// - `pos`: The left upper conner of the bitmap to blit
+    /// - `size`: The size of the bitmap
+    /// - `src`: The source of the image
+    /// - `format`: The pixel format of the source
+    ///
+    /// The default implementation converts the opaque bitmaps into RGB and blits them with
+    /// [`DrawingBackend::blit_bitmap`], and draws the bitmaps with an alpha channel pixel by
+    /// pixel.
+    fn blit_bitmap_fmt(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        format: BitmapFormat,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        match format {
+            BitmapFormat::Rgb => self.blit_bitmap(pos, size, src),
+            BitmapFormat::Bgr => self.blit_bitmap(pos, size, &format.to_rgb(src)),
+            BitmapFormat::Rgba | BitmapFormat::Bgra => blit_pixels(self, pos, size, src, format),
+        }
+    }
+}
+
+/// Blit a bitmap by drawing its pixels one by one, the fully transparent pixels are skipped
+fn blit_pixels<DB: DrawingBackend>(
+    backend: &mut DB,
+    pos: BackendCoord,
+    (iw, ih): (u32, u32),
+    src: &[u8],
+    format: BitmapFormat,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let (w, h) = backend.get_size();
+    let pixel_size = format.pixel_size();
+
+    for dx in 0..iw {
+        if pos.0 + dx as i32 >= w as i32 {
+            break;
+        }
+        for dy in 0..ih {
+            if pos.1 + dy as i32 >= h as i32 {
                 break;
             }
-            for dy in 0..ih {
-                if pos.1 + dy as i32 >= h as i32 {
-                    break;
-                }
-                // FIXME: This assume we have RGB image buffer
-                let r = src[(dx + dy * w) as usize * 3];
-                let g = src[(dx + dy * w) as usize * 3 + 1];
-                let b = src[(dx + dy * w) as usize * 3 + 2];
-                let color = BackendColor {
-                    alpha: 1.0,
-                    rgb: (r, g, b),
-                };
-                let result = self.draw_pixel((pos.0 + dx as i32, pos.1 + dy as i32), color);
-                #[allow(clippy::question_mark)]
-                if result.is_err() {
-                    return result;
-                }
+            let offset = (dx + dy * iw) as usize * pixel_size;
+            let color = format.decode(&src[offset..offset + pixel_size]);
+            if color.alpha == 0.0 {
+                continue;
             }
+            backend.draw_pixel((pos.0 + dx as i32, pos.1 + dy as i32), color)?;
         }
-
-        Ok(())
     }
+
+    Ok(())
 }
@@ -272,6 +272,48 @@ fn draw_annulus<B: DrawingBackend, S: BackendStyle>(
     Ok(())
 }
 
+/// Fill the circle without blending the pixels along the edge, which is faster than filling it
+/// with [`draw_circle`], but the edge is jaggy. The edge pixels which are covered by at least a
+/// half are filled.
+pub fn fill_circle_aliased<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    center: BackendCoord,
+    radius: u32,
+    style: &S,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if style.color().alpha == 0.0 {
+        return Ok(());
+    }
+
+    let min = (f64::from(radius) * (1.0 - (2f64).sqrt() / 2.0)).ceil() as i32;
+    let max = (f64::from(radius) * (1.0 + (2f64).sqrt() / 2.0)).floor() as i32;
+
+    let (up, down) = (
+        min + center.1 - radius as i32,
+        max + center.1 - radius as i32,
+    );
+
+    for dy in min..=max {
+        let dy = dy - radius as i32;
+        let y = center.1 + dy;
+
+        let lx = (f64::from(radius) * f64::from(radius)
+            - (f64::from(dy) * f64::from(dy)).max(1e-5))
+        .sqrt();
+
+        // The edge pixel is filled if it's covered by at least a half
+        let lx = lx.round() as i32;
+
+        let x = center.0 + dy;
+
+        check_result!(b.draw_line((center.0 - lx, y), (center.0 + lx, y), &style.color()));
+        check_result!(b.draw_line((x, center.1 - lx), (x, up - 1), &style.color()));
+        check_result!(b.draw_line((x, down + 1), (x, center.1 + lx), &style.color()));
+    }
+
+    Ok(())
+}
+
 pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
     b: &mut B,
     center: BackendCoord,
@@ -0,0 +1,68 @@
+use crate::BackendCoord;
+
+/// The stack of the clipping boxes of a backend, which implements
+/// [`DrawingBackend::push_clip`](crate::DrawingBackend::push_clip) and
+/// [`DrawingBackend::pop_clip`](crate::DrawingBackend::pop_clip) for the backends drawing pixels.
+///
+/// Each box is given by its upper left and bottom right corners, both of which are inclusive.
+/// A pushed box is intersected with the current box, so the nested boxes never grow the visible
+/// region, and popping an empty stack does nothing.
+#[derive(Clone, Debug, Default)]
+pub struct ClipStack {
+    stack: Vec<(BackendCoord, BackendCoord)>,
+}
+
+impl ClipStack {
+    /// Create an empty clip stack, which doesn't clip anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a clipping box, which is intersected with the current one
+    pub fn push(&mut self, (a, b): (BackendCoord, BackendCoord)) {
+        let (mut ul, mut br) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        if let Some((top_ul, top_br)) = self.current() {
+            ul = (ul.0.max(top_ul.0), ul.1.max(top_ul.1));
+            br = (br.0.min(top_br.0), br.1.min(top_br.1));
+        }
+        self.stack.push((ul, br));
+    }
+
+    /// Pop the last pushed clipping box, if any
+    pub fn pop(&mut self) {
+        self.stack.pop();
+    }
+
+    /// Get the current clipping box, `None` if nothing is clipped. The box may be empty, in which
+    /// case its upper left corner is on the right of or below its bottom right corner.
+    pub fn current(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.stack.last().copied()
+    }
+
+    /// Check if a pixel is visible through the current clipping box
+    pub fn contains(&self, (x, y): BackendCoord) -> bool {
+        match self.current() {
+            Some((ul, br)) => ul.0 <= x && x <= br.0 && ul.1 <= y && y <= br.1,
+            None => true,
+        }
+    }
+
+    /// Clip a rectangle given by its inclusive corners to the current clipping box
+    ///
+    /// - *Returns* The visible part of the rectangle, `None` if nothing is visible
+    pub fn clip_rect(
+        &self,
+        (a, b): (BackendCoord, BackendCoord),
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let (mut ul, mut br) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        if let Some((clip_ul, clip_br)) = self.current() {
+            ul = (ul.0.max(clip_ul.0), ul.1.max(clip_ul.1));
+            br = (br.0.min(clip_br.0), br.1.min(clip_br.1));
+        }
+        if ul.0 <= br.0 && ul.1 <= br.1 {
+            Some((ul, br))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{BackendColor, BackendCoord, BackendStyle};
+
+/// The stroke of a style without its dash pattern, which is used to draw the dashes once a path
+/// has been split. It's a concrete type, so drawing the dashes doesn't instantiate the drawing
+/// functions with an ever nesting style type.
+#[derive(Clone, Copy)]
+pub(crate) struct SolidStroke {
+    color: BackendColor,
+    stroke_width: u32,
+}
+
+impl SolidStroke {
+    pub(crate) fn of<S: BackendStyle>(style: &S) -> Self {
+        Self {
+            color: style.color(),
+            stroke_width: style.stroke_width(),
+        }
+    }
+
+    pub(crate) fn new(color: BackendColor, stroke_width: u32) -> Self {
+        Self {
+            color,
+            stroke_width,
+        }
+    }
+}
+
+impl BackendStyle for SolidStroke {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+}
+
+/// Split a path into the dashes described by the dash pattern.
+///
+/// - `path`: The key points of the path
+/// - `pattern`: The lengths of the on and off segments in pixels, alternately. A pattern with an
+///   odd number of lengths is repeated twice, so that the on and off segments alternate
+/// - `offset`: How far into the pattern the path starts, in pixels
+/// - **returns**: The key points of each dash. The phase of the pattern carries on across the
+///   vertices of the path, so a dash may turn the corners of the path
+pub fn dash_path(path: &[BackendCoord], pattern: &[u32], offset: u32) -> Vec<Vec<BackendCoord>> {
+    let pattern: Vec<_> = if pattern.len() % 2 == 1 {
+        pattern.iter().chain(pattern.iter()).copied().collect()
+    } else {
+        pattern.to_vec()
+    };
+
+    let total: u32 = pattern.iter().sum();
+    if total == 0 || path.is_empty() {
+        return vec![path.to_vec()];
+    }
+
+    let mut idx = 0;
+    let mut skip = offset % total;
+    while skip >= pattern[idx] {
+        skip -= pattern[idx];
+        idx = (idx + 1) % pattern.len();
+    }
+    let mut remaining = f64::from(pattern[idx] - skip);
+
+    let mut dashes = vec![];
+    let mut current = if idx % 2 == 0 { vec![path[0]] } else { vec![] };
+
+    for (&(x0, y0), &(x1, y1)) in path.iter().zip(path.iter().skip(1)) {
+        let (dx, dy) = (f64::from(x1 - x0), f64::from(y1 - y0));
+        let length = (dx * dx + dy * dy).sqrt();
+
+        let mut walked = 0.0;
+        while length - walked > remaining {
+            walked += remaining;
+            let point = (
+                (f64::from(x0) + dx * walked / length).round() as i32,
+                (f64::from(y0) + dy * walked / length).round() as i32,
+            );
+            current.push(point);
+            if idx % 2 == 0 {
+                dashes.push(std::mem::take(&mut current));
+            }
+            idx = (idx + 1) % pattern.len();
+            remaining = f64::from(pattern[idx]);
+        }
+        remaining -= length - walked;
+
+        if idx % 2 == 0 {
+            current.push((x1, y1));
+        }
+    }
+
+    if idx % 2 == 0 && current.len() > 1 {
+        dashes.push(current);
+    }
+
+    dashes
+}
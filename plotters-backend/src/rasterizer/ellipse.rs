@@ -0,0 +1,51 @@
+use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+
+/// Compute the outline of an axis-aligned ellipse, with the vertices placed about 2 pixels
+/// apart, so that the outline of a circle is as smooth as the rasterized one
+///
+/// - `center`: The center of the ellipse
+/// - `radius`: The horizontal and vertical radii of the ellipse
+/// - **returns**: The vertices of the outline, which isn't closed
+fn ellipse_outline(center: BackendCoord, radius: (u32, u32)) -> Vec<BackendCoord> {
+    let (rx, ry) = (f64::from(radius.0), f64::from(radius.1));
+    // Ramanujan's approximation of the perimeter
+    let perimeter =
+        std::f64::consts::PI * (3.0 * (rx + ry) - ((3.0 * rx + ry) * (rx + 3.0 * ry)).sqrt());
+    let segments = ((perimeter / 2.0).ceil() as usize).max(8);
+
+    let mut outline: Vec<BackendCoord> = Vec::with_capacity(segments);
+    for i in 0..segments {
+        let angle = std::f64::consts::PI * 2.0 * i as f64 / segments as f64;
+        let point = (
+            center.0 + (rx * angle.cos()).round() as i32,
+            center.1 + (ry * angle.sin()).round() as i32,
+        );
+        if outline.last() != Some(&point) {
+            outline.push(point);
+        }
+    }
+    if outline.len() > 1 && outline.first() == outline.last() {
+        outline.pop();
+    }
+    outline
+}
+
+pub fn draw_ellipse<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    center: BackendCoord,
+    radius: (u32, u32),
+    style: &S,
+    fill: bool,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if style.color().alpha == 0.0 {
+        return Ok(());
+    }
+
+    let mut outline = ellipse_outline(center, radius);
+    if fill {
+        return b.fill_polygon(outline, style);
+    }
+
+    outline.push(outline[0]);
+    b.draw_path(outline, style)
+}
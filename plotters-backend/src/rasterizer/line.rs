@@ -1,3 +1,4 @@
+use super::{dash_path, SolidStroke};
 use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 
 pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
@@ -10,6 +11,13 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         return Ok(());
     }
 
+    if let Some(pattern) = style.dash_pattern() {
+        for dash in dash_path(&[from, to], pattern, style.dash_offset()) {
+            check_result!(back.draw_line(dash[0], dash[dash.len() - 1], &SolidStroke::of(style)));
+        }
+        return Ok(());
+    }
+
     if style.stroke_width() != 1 {
         // If the line is wider than 1px, then we need to make it a polygon
         let v = (i64::from(to.0 - from.0), i64::from(to.1 - from.1));
@@ -32,10 +32,25 @@ mod rect;
 pub use rect::draw_rect;
 
 mod circle;
-pub use circle::draw_circle;
+pub use circle::{draw_circle, fill_circle_aliased};
+
+mod ellipse;
+pub use ellipse::draw_ellipse;
 
 mod polygon;
-pub use polygon::fill_polygon;
+#[cfg(feature = "rayon")]
+pub use polygon::fill_polygon_parallel;
+pub use polygon::{fill_polygon, fill_polygon_aliased};
+
+mod pattern;
+pub use pattern::fill_pattern;
 
 mod path;
 pub use path::polygonize;
+
+mod clip;
+pub use clip::ClipStack;
+
+mod dash;
+pub use dash::dash_path;
+pub(crate) use dash::SolidStroke;
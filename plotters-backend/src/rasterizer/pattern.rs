@@ -0,0 +1,79 @@
+use super::SolidStroke;
+use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, FillPattern};
+
+/// Fill a polygon with the lines of the fill pattern of the style.
+///
+/// The lines are anchored to the origin of the backend rather than to the polygon, so the
+/// patterns of adjacent shapes line up. Each line is clipped to the polygon with the even-odd
+/// rule, and the pieces are drawn with `draw_line`. A `Solid` pattern draws nothing.
+///
+/// - `back`: The drawing backend
+/// - `vertices`: The vertices of the polygon
+/// - `style`: The style, which provides the color and the pattern
+pub fn fill_pattern<DB: DrawingBackend, S: BackendStyle>(
+    back: &mut DB,
+    vertices: &[BackendCoord],
+    style: &S,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if style.color().alpha == 0.0 || vertices.len() < 3 {
+        return Ok(());
+    }
+
+    let sqrt_half = std::f64::consts::FRAC_1_SQRT_2;
+    // The unit normals of the families of lines
+    let normals: &[(f64, f64)] = match style.fill_pattern() {
+        FillPattern::Solid => &[],
+        FillPattern::HorizontalLines => &[(0.0, 1.0)],
+        FillPattern::VerticalLines => &[(1.0, 0.0)],
+        FillPattern::DiagonalLines => &[(sqrt_half, sqrt_half)],
+        FillPattern::CrossHatch => &[(0.0, 1.0), (1.0, 0.0)],
+    };
+    let spacing = f64::from(style.fill_pattern_spacing().max(1));
+    let stroke = SolidStroke::new(style.color(), style.fill_pattern_line_width());
+
+    let points: Vec<_> = vertices
+        .iter()
+        .map(|&(x, y)| (f64::from(x), f64::from(y)))
+        .collect();
+
+    for &(nx, ny) in normals {
+        let dist: Vec<_> = points.iter().map(|&(x, y)| x * nx + y * ny).collect();
+        let min = dist.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = dist.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        let mut k = (min / spacing).ceil();
+        while k * spacing <= max {
+            let c = k * spacing;
+            k += 1.0;
+
+            let mut crossings = vec![];
+            for i in 0..points.len() {
+                let j = (i + 1) % points.len();
+                let (da, db) = (dist[i] - c, dist[j] - c);
+                // Half-open, so a line through a vertex crosses only one of its edges
+                if (da < 0.0) == (db < 0.0) {
+                    continue;
+                }
+                let t = da / (da - db);
+                let (xa, ya) = points[i];
+                let (xb, yb) = points[j];
+                crossings.push((xa + (xb - xa) * t, ya + (yb - ya) * t));
+            }
+            // Sort the crossings along the line
+            crossings.sort_by(|a, b| {
+                let (ua, ub) = (a.1 * nx - a.0 * ny, b.1 * nx - b.0 * ny);
+                ua.partial_cmp(&ub).unwrap()
+            });
+
+            for pair in crossings.chunks(2) {
+                if let [from, to] = pair {
+                    let from = (from.0.round() as i32, from.1.round() as i32);
+                    let to = (to.0.round() as i32, to.1.round() as i32);
+                    check_result!(back.draw_line(from, to, &stroke));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use super::fill_pattern;
+use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, FillPattern};
 
 pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
     b: &mut B,
@@ -21,6 +22,12 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
         ),
     );
 
+    if fill && style.fill_pattern() != FillPattern::Solid {
+        let (x0, y0) = upper_left;
+        let (x1, y1) = bottom_right;
+        return fill_pattern(b, &[(x0, y0), (x1, y0), (x1, y1), (x0, y1)], style);
+    }
+
     if fill {
         if bottom_right.0 - upper_left.0 < bottom_right.1 - upper_left.1 {
             for x in upper_left.0..=bottom_right.0 {
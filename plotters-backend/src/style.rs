@@ -15,6 +15,22 @@ impl BackendColor {
     }
 }
 
+/// The pattern filling a shape, the lines of the patterns other than `Solid` are drawn with the
+/// color of the style and the shape is left transparent between them
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FillPattern {
+    /// The shape is filled with the solid color
+    Solid,
+    /// Horizontal lines
+    HorizontalLines,
+    /// Vertical lines
+    VerticalLines,
+    /// Diagonal lines, going up from the left to the right
+    DiagonalLines,
+    /// Both the horizontal and the vertical lines
+    CrossHatch,
+}
+
 /// The style data for the backend drawing API
 pub trait BackendStyle {
     /// Get the color of current style
@@ -24,6 +40,33 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// Get the dash pattern of current style, which is the lengths of the on and off segments
+    /// of the stroke in pixels, alternately. `None` means the stroke is solid
+    fn dash_pattern(&self) -> Option<&[u32]> {
+        None
+    }
+
+    /// Get the offset of the dash pattern in pixels, which is how far into the pattern the
+    /// stroke starts
+    fn dash_offset(&self) -> u32 {
+        0
+    }
+
+    /// Get the pattern filling the shapes of current style
+    fn fill_pattern(&self) -> FillPattern {
+        FillPattern::Solid
+    }
+
+    /// Get the distance between the lines of the fill pattern in pixels
+    fn fill_pattern_spacing(&self) -> u32 {
+        8
+    }
+
+    /// Get the width of the lines of the fill pattern in pixels
+    fn fill_pattern_line_width(&self) -> u32 {
+        1
+    }
 }
 
 impl BackendStyle for BackendColor {
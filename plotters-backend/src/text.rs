@@ -135,6 +135,9 @@ pub enum FontTransform {
     Rotate180,
     /// Rotating the text 270 degree clockwise
     Rotate270,
+    /// Rotating the text by an arbitrary angle in degrees clockwise, a negative angle rotates
+    /// the text counterclockwise
+    RotateAngle(f32),
 }
 
 impl FontTransform {
@@ -149,6 +152,48 @@ impl FontTransform {
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
+            FontTransform::RotateAngle(_) => {
+                let (sin, cos) = self.degrees().to_radians().sin_cos();
+                let (x, y) = (f64::from(x), f64::from(y));
+                (
+                    (x * cos - y * sin).round() as i32,
+                    (x * sin + y * cos).round() as i32,
+                )
+            }
+        }
+    }
+
+    /// Get the angle of the rotation
+    ///
+    /// - **returns**: The angle in degrees clockwise
+    pub fn degrees(&self) -> f64 {
+        match self {
+            FontTransform::None => 0.0,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+            FontTransform::RotateAngle(angle) => f64::from(*angle),
+        }
+    }
+
+    /// Compute the size of the bounding box of a rotated rectangle, such as the text
+    ///
+    /// - `w`: The width of the rectangle before transform
+    /// - `h`: The height of the rectangle before transform
+    /// - **returns**: The width and the height of the bounding box after transform
+    pub fn transform_size(&self, w: u32, h: u32) -> (u32, u32) {
+        match self {
+            FontTransform::None | FontTransform::Rotate180 => (w, h),
+            FontTransform::Rotate90 | FontTransform::Rotate270 => (h, w),
+            FontTransform::RotateAngle(_) => {
+                let (sin, cos) = self.degrees().to_radians().sin_cos();
+                let (sin, cos) = (sin.abs(), cos.abs());
+                let (w, h) = (f64::from(w), f64::from(h));
+                (
+                    (w * cos + h * sin).round() as u32,
+                    (w * sin + h * cos).round() as u32,
+                )
+            }
         }
     }
 }
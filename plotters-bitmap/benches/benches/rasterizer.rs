@@ -137,6 +137,16 @@ fn fill_circle(c: &mut Criterion) {
                 .unwrap();
         })
     });
+
+    g.bench_function("rgb-aliased", |b| {
+        let mut buffer = vec![0; (W * H * 3) as usize];
+        b.iter(|| {
+            let mut root = BitMapBackend::with_buffer(&mut buffer, (W, H));
+            root.set_edge_antialiasing(false);
+            root.draw_circle((W as i32 / 2, H as i32 / 2), W / 2, &WHITE.to_rgba(), true)
+                .unwrap();
+        })
+    });
 }
 
 fn fill_background_red(c: &mut Criterion) {
@@ -189,6 +199,57 @@ fn fill_hexagon(c: &mut Criterion) {
             root.fill_polygon(vert.clone(), &RED).unwrap();
         })
     });
+
+    g.bench_function("rgb-aliased", |b| {
+        let mut buffer = vec![0; (W * H * 3) as usize];
+        b.iter(|| {
+            let mut root = BitMapBackend::with_buffer(&mut buffer, (W, H));
+            root.set_edge_antialiasing(false);
+            root.fill_polygon(vert.clone(), &RED).unwrap();
+        })
+    });
+}
+
+fn fill_polygon_parallel(c: &mut Criterion) {
+    use plotters_backend::rasterizer;
+
+    let mut g = c.benchmark_group("rasterizer::fill_polygon_parallel");
+
+    // A hexagon, where most of the time is spent on writing pixels
+    let mut hexagon = vec![];
+    for i in 0..6 {
+        let angle = std::f64::consts::PI * i as f64 / 3.0;
+        hexagon.push((
+            (W as f64 * 0.45 * angle.cos()) as i32 + W as i32 / 2,
+            (H as f64 * 0.45 * angle.sin()) as i32 + H as i32 / 2,
+        ));
+    }
+
+    // A comb with many teeth, where each sweep line crosses many edges
+    let mut comb = vec![(0, H as i32 - 1)];
+    for i in 0..500 {
+        comb.push((i * 2, 0));
+        comb.push((i * 2 + 1, H as i32 - 10));
+    }
+    comb.push((W as i32 - 1, H as i32 - 1));
+
+    for (name, vert) in [("hexagon", &hexagon), ("comb", &comb)] {
+        g.bench_function(format!("{}-sequential", name), |b| {
+            let mut buffer = vec![0; (W * H * 3) as usize];
+            b.iter(|| {
+                let mut root = BitMapBackend::with_buffer(&mut buffer, (W, H));
+                rasterizer::fill_polygon(&mut root, vert, &RED.mix(0.5)).unwrap();
+            })
+        });
+
+        g.bench_function(format!("{}-parallel", name), |b| {
+            let mut buffer = vec![0; (W * H * 3) as usize];
+            b.iter(|| {
+                let mut root = BitMapBackend::with_buffer(&mut buffer, (W, H));
+                rasterizer::fill_polygon_parallel(&mut root, vert, &RED.mix(0.5)).unwrap();
+            })
+        });
+    }
 }
 
 criterion_group! {
@@ -202,4 +263,5 @@ criterion_group! {
         fill_circle,
         fill_background_red,
         fill_hexagon,
+        fill_polygon_parallel,
 }
@@ -1,5 +1,6 @@
 use plotters_backend::{
-    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+    rasterizer::ClipStack, BackendColor, BackendCoord, BackendStyle, DrawingBackend,
+    DrawingErrorKind, FillPattern,
 };
 use std::marker::PhantomData;
 
@@ -18,6 +19,10 @@ use image_encoding_support::*;
 
 mod target;
 
+/// The largest supersampling factor of [`BitMapBackend::new_supersampled`]
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+const MAX_SUPERSAMPLE: u32 = 16;
+
 use target::{Buffer, Target};
 
 /// The backend that drawing a bitmap
@@ -40,6 +45,12 @@ pub struct BitMapBackend<'a, P: PixelFormat = RGBPixel> {
     buffer: Buffer<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// Flag indicates if the edges of filled polygons and circles are anti-aliased
+    edge_antialiasing: bool,
+    /// The clipping boxes pushed by `push_clip`
+    clip: ClipStack,
+    /// The factor of supersampling, the image is downsampled by it when it's saved
+    supersample: u32,
     _pantomdata: PhantomData<P>,
 }
 
@@ -57,10 +68,49 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            edge_antialiasing: true,
+            clip: ClipStack::new(),
+            supersample: 1,
             _pantomdata: PhantomData,
         }
     }
 
+    /// Create a new bitmap backend which renders the image at a higher resolution and
+    /// downsamples it when it's saved, which anti-aliases everything drawn on it
+    ///
+    /// The backend reports the size of `factor` times the image size in each dimension, thus
+    /// the drawing code works without any change, but the sizes given in pixels, such as the
+    /// font sizes and the stroke widths, are in the enlarged resolution. Each pixel of the saved
+    /// image is the average of a `factor` x `factor` block of the rendered pixels. The memory and
+    /// the time of rendering grow with the square of `factor`.
+    ///
+    /// - `path`: The path to the image
+    /// - `dimension`: The size of the saved image
+    /// - `factor`: The number of rendered pixels in each dimension for a single saved pixel,
+    ///   which is clamped to `1..=16`, and lowered further if the rendered image would have more
+    ///   pixels than a `u32` can count
+    /// - **returns**: The newly created bitmap backend
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn new_supersampled<T: AsRef<Path> + ?Sized>(
+        path: &'a T,
+        (w, h): (u32, u32),
+        factor: u32,
+    ) -> Self {
+        let fits = |f: u32| {
+            w.checked_mul(f)
+                .zip(h.checked_mul(f))
+                .and_then(|(w, h)| w.checked_mul(h))
+                .is_some()
+        };
+        let factor = (1..=factor.clamp(1, MAX_SUPERSAMPLE))
+            .rev()
+            .find(|&f| fits(f))
+            .unwrap_or(1);
+        let mut ret = Self::new(path, (w * factor, h * factor));
+        ret.supersample = factor;
+        ret
+    }
+
     /// Create a new bitmap backend that generate GIF animation
     ///
     /// When this is used, the bitmap backend acts similar to a real-time rendering backend.
@@ -85,6 +135,9 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            edge_antialiasing: true,
+            clip: ClipStack::new(),
+            supersample: 1,
             _pantomdata: PhantomData,
         })
     }
@@ -104,6 +157,30 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
     pub fn with_buffer(buf: &'a mut [u8], (w, h): (u32, u32)) -> Self {
         Self::with_buffer_and_format(buf, (w, h)).expect("Wrong buffer size")
     }
+
+    /// Create a new bitmap backend which renders to an owned in-memory RGB image
+    ///
+    /// When this is used, the bitmap backend allocates its own buffer, and the rendered image
+    /// is taken with [`BitMapBackend::into_image`] after it's presented. When the backend is
+    /// turned into a drawing area, the backend is taken back with `DrawingArea::into_backend`
+    /// once the charts built on the area are dropped. This is useful for
+    /// passing the pixels to another library, such as a GPU texture or a video encoder, without
+    /// managing an external buffer.
+    ///
+    /// - `dimension`: The size of the image in pixels
+    /// - **returns**: The newly created bitmap backend
+    pub fn new_in_memory((w, h): (u32, u32)) -> Self {
+        Self {
+            target: Target::Memory,
+            size: (w, h),
+            buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
+            saved: false,
+            edge_antialiasing: true,
+            clip: ClipStack::new(),
+            supersample: 1,
+            _pantomdata: PhantomData,
+        }
+    }
 }
 
 impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
@@ -128,15 +205,103 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             size: (w, h),
             buffer: Buffer::Borrowed(buf),
             saved: false,
+            edge_antialiasing: true,
+            clip: ClipStack::new(),
+            supersample: 1,
             _pantomdata: PhantomData,
         })
     }
 
+    /// Take the rendered image out of the backend, which is the pixels in the pixel format of
+    /// the backend, row by row from the top left corner.
+    ///
+    /// The backend must be presented with [`DrawingBackend::present`] first, otherwise
+    /// [`BitMapBackendError::NotPresented`] is returned. The backends which draw to a user
+    /// provided buffer return [`BitMapBackendError::InvalidBuffer`], since the image is already
+    /// in that buffer.
+    pub fn into_image(mut self) -> Result<Vec<u8>, BitMapBackendError> {
+        match std::mem::replace(&mut self.buffer, Buffer::Borrowed(&mut [])) {
+            Buffer::Owned(buf) if self.saved => Ok(buf),
+            Buffer::Owned(_) => Err(BitMapBackendError::NotPresented),
+            Buffer::Borrowed(_) => Err(BitMapBackendError::InvalidBuffer),
+        }
+    }
+
+    /// Set if the edges of the filled polygons and circles should be anti-aliased, which is
+    /// enabled by default.
+    ///
+    /// Only the pixels along the edges are blended with the coverage of the shape, the interior
+    /// is always filled with the fast solid fill. Disabling it makes the edges jaggy, but saves
+    /// the cost of blending the edge pixels.
+    ///
+    /// - `enabled`: If the edge anti-aliasing is enabled
+    pub fn set_edge_antialiasing(&mut self, enabled: bool) {
+        self.edge_antialiasing = enabled;
+    }
+
     #[inline(always)]
     pub(crate) fn get_raw_pixel_buffer(&mut self) -> &mut [u8] {
         self.buffer.borrow_buffer()
     }
 
+    /// Clip a rectangle with an exclusive bottom right corner to the current clipping box
+    fn clip_rect_exclusive(
+        &self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        if self.clip.current().is_none() {
+            return Some((upper_left, bottom_right));
+        }
+        let (x0, x1) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.0.max(bottom_right.0),
+        );
+        let (y0, y1) = (
+            upper_left.1.min(bottom_right.1),
+            upper_left.1.max(bottom_right.1),
+        );
+        if x0 == x1 || y0 == y1 {
+            return None;
+        }
+        let (ul, br) = self.clip.clip_rect(((x0, y0), (x1 - 1, y1 - 1)))?;
+        Some((ul, (br.0 + 1, br.1 + 1)))
+    }
+
+    /// Fill a rectangle with an exclusive bottom right corner within the clipping box
+    fn fill_rect_clipped(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        color: BackendColor,
+    ) {
+        let (r, g, b) = color.rgb;
+        if let Some((upper_left, bottom_right)) = self.clip_rect_exclusive(upper_left, bottom_right)
+        {
+            if color.alpha >= 1.0 {
+                P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
+            } else {
+                P::blend_rect_fast(self, upper_left, bottom_right, r, g, b, color.alpha);
+            }
+        }
+    }
+
+    /// Draw a vertical or horizontal line of a single pixel width within the clipping box
+    fn draw_straight_line(&mut self, from: BackendCoord, to: BackendCoord, color: BackendColor) {
+        if color.alpha >= 1.0 && from.0 == to.0 {
+            let (r, g, b) = color.rgb;
+            if let Some((ul, br)) = self.clip.clip_rect((from, to)) {
+                P::fill_vertical_line_fast(self, ul.0, (ul.1, br.1), r, g, b);
+            }
+        } else {
+            let (ul, br) = (
+                (from.0.min(to.0), from.1.min(to.1)),
+                (from.0.max(to.0), from.1.max(to.1)),
+            );
+            self.fill_rect_clipped(ul, (br.0 + 1, br.1 + 1), color);
+        }
+    }
+
     /// Split a bitmap backend vertically into several sub drawing area which allows
     /// multi-threading rendering.
     ///
@@ -144,6 +309,7 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
     /// - **returns**: The splitted backends that can be rendered in parallel
     pub fn split(&mut self, area_size: &[u32]) -> Vec<BitMapBackend<P>> {
         let (w, h) = self.get_size();
+        let edge_antialiasing = self.edge_antialiasing;
         let buf = self.get_raw_pixel_buffer();
 
         let base_addr = &mut buf[0] as *mut u8;
@@ -167,7 +333,10 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
                         ((end - begin) * w) as usize * Self::PIXEL_SIZE,
                     )
                 };
-                Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap()
+                let mut backend =
+                    Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap();
+                backend.edge_antialiasing = edge_antialiasing;
+                backend
             })
             .collect()
     }
@@ -187,19 +356,35 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
     #[cfg(any(target_arch = "wasm32", not(feature = "image")))]
     fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if let Target::Memory = self.target {
+            self.saved = true;
+        }
         Ok(())
     }
 
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if let Target::Memory = self.target {
+            self.saved = true;
+            return Ok(());
+        }
         if !P::can_be_saved() {
             return Ok(());
         }
         let (w, h) = self.get_size();
         match &mut self.target {
             Target::File(path) => {
-                if let Some(img) = BorrowedImage::from_raw(w, h, self.buffer.borrow_buffer()) {
-                    img.save(&path).map_err(|x| {
+                let factor = self.supersample;
+                let buf = self.buffer.borrow_buffer();
+                let result = if factor > 1 {
+                    let pixels = downsample(buf, (w, h), factor);
+                    ImageBuffer::<Rgb<u8>, _>::from_raw(w / factor, h / factor, pixels)
+                        .map(|img| img.save(&path))
+                } else {
+                    BorrowedImage::from_raw(w, h, buf).map(|img| img.save(&path))
+                };
+                if let Some(result) = result {
+                    result.map_err(|x| {
                         DrawingErrorKind::DrawingError(BitMapBackendError::ImageError(x))
                     })?;
                     self.saved = true;
@@ -210,7 +395,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
                     ))
                 }
             }
-            Target::Buffer(_) => Ok(()),
+            Target::Buffer(_) | Target::Memory => Ok(()),
 
             #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
             Target::Gif(target) => {
@@ -232,6 +417,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
             || point.1 < 0
             || point.0 as u32 >= self.size.0
             || point.1 as u32 >= self.size.1
+            || !self.clip.contains(point)
         {
             return Ok(());
         }
@@ -244,31 +430,58 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         Ok(())
     }
 
+    fn draw_pixels(
+        &mut self,
+        points: &[(BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        for &(point, color) in points {
+            if point.0 < 0 || point.1 < 0 || point.0 >= w || point.1 >= h {
+                continue;
+            }
+            if !self.clip.contains(point) {
+                continue;
+            }
+            P::draw_pixel(self, point, color.rgb, color.alpha);
+        }
+        Ok(())
+    }
+
     fn draw_line<S: BackendStyle>(
         &mut self,
         from: (i32, i32),
         to: (i32, i32),
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let alpha = style.color().alpha;
-        let (r, g, b) = style.color().rgb;
-
-        if (from.0 == to.0 || from.1 == to.1) && style.stroke_width() == 1 {
-            if alpha >= 1.0 {
-                if from.1 == to.1 {
-                    P::fill_rect_fast(self, from, (to.0 + 1, to.1 + 1), r, g, b);
-                } else {
-                    P::fill_vertical_line_fast(self, from.0, (from.1, to.1), r, g, b);
-                }
-            } else {
-                P::blend_rect_fast(self, from, (to.0 + 1, to.1 + 1), r, g, b, alpha);
-            }
+        if (from.0 == to.0 || from.1 == to.1)
+            && style.stroke_width() == 1
+            && style.dash_pattern().is_none()
+        {
+            self.draw_straight_line(from, to, style.color());
             return Ok(());
         }
 
         plotters_backend::rasterizer::draw_line(self, from, to, style)
     }
 
+    fn draw_segments<S: BackendStyle>(
+        &mut self,
+        segments: &[((i32, i32), (i32, i32))],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let thin = style.stroke_width() == 1 && style.dash_pattern().is_none();
+
+        for &(from, to) in segments {
+            if thin && (from.0 == to.0 || from.1 == to.1) {
+                self.draw_straight_line(from, to, style.color());
+                continue;
+            }
+
+            plotters_backend::rasterizer::draw_line(self, from, to, style)?;
+        }
+        Ok(())
+    }
+
     fn draw_rect<S: BackendStyle>(
         &mut self,
         upper_left: (i32, i32),
@@ -276,19 +489,109 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         style: &S,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let alpha = style.color().alpha;
-        let (r, g, b) = style.color().rgb;
-        if fill {
-            if alpha >= 1.0 {
-                P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
-            } else {
-                P::blend_rect_fast(self, upper_left, bottom_right, r, g, b, alpha);
-            }
+        if fill && style.fill_pattern() == FillPattern::Solid {
+            self.fill_rect_clipped(upper_left, bottom_right, style.color());
             return Ok(());
         }
         plotters_backend::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert_buf: Vec<_> = vert.into_iter().collect();
+        if self.edge_antialiasing {
+            plotters_backend::rasterizer::fill_polygon(self, &vert_buf[..], style)
+        } else {
+            plotters_backend::rasterizer::fill_polygon_aliased(self, &vert_buf[..], style)
+        }
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if fill && !self.edge_antialiasing {
+            return plotters_backend::rasterizer::fill_circle_aliased(self, center, radius, style);
+        }
+        plotters_backend::rasterizer::draw_circle(self, center, radius, style, fill)
+    }
+
+    fn fill_rects(
+        &mut self,
+        rects: &[(BackendCoord, BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for &(upper_left, bottom_right, color) in rects {
+            if color.alpha > 0.0 {
+                self.fill_rect_clipped(upper_left, bottom_right, color);
+            }
+        }
+        Ok(())
+    }
+
+    fn blur_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (w, h) = self.get_size();
+        let ((x0, y0), (x1, y1)) = match self.clip.clip_rect((upper_left, bottom_right)) {
+            Some(rect) => rect,
+            None => return Ok(()),
+        };
+        let (x0, y0) = (x0.max(0), y0.max(0));
+        let (x1, y1) = (x1.min(w as i32 - 1), y1.min(h as i32 - 1));
+
+        if radius == 0 || x0 > x1 || y0 > y1 {
+            return Ok(());
+        }
+
+        let (rw, rh) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+        let offset = |x: usize, y: usize| {
+            ((y0 as usize + y) * w as usize + x0 as usize + x) * Self::PIXEL_SIZE
+        };
+
+        let buf = self.get_raw_pixel_buffer();
+        let mut pixels = Vec::with_capacity(rw * rh);
+        for y in 0..rh {
+            for x in 0..rw {
+                let (r, g, b, _) = P::decode_pixel(&buf[offset(x, y)..]);
+                pixels.push([u32::from(r), u32::from(g), u32::from(b)]);
+            }
+        }
+
+        // The box blur is separable, thus we blur the rows first and then the columns
+        let radius = radius as usize;
+        for row in pixels.chunks_mut(rw) {
+            let blurred = box_blur(row.iter().copied(), radius);
+            row.copy_from_slice(&blurred);
+        }
+        for x in 0..rw {
+            let blurred = box_blur((0..rh).map(|y| pixels[y * rw + x]), radius);
+            for (y, value) in blurred.into_iter().enumerate() {
+                pixels[y * rw + x] = value;
+            }
+        }
+
+        for y in 0..rh {
+            for x in 0..rw {
+                let [r, g, b] = pixels[y * rw + x];
+                let start = offset(x, y);
+                for idx in 0..Self::PIXEL_SIZE {
+                    buf[start + idx] = P::byte_at(r as u8, g as u8, b as u8, 0, idx);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn blit_bitmap(
         &mut self,
         pos: BackendCoord,
@@ -300,9 +603,13 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         let (x0, y0) = pos;
         let (x1, y1) = (x0 + sw as i32, y0 + sh as i32);
 
+        let ((x0, y0), (x1, y1)) = match self.clip_rect_exclusive((x0, y0), (x1, y1)) {
+            Some(rect) => rect,
+            None => return Ok(()),
+        };
         let (x0, y0, x1, y1) = (x0.max(0), y0.max(0), x1.min(dw as i32), y1.min(dh as i32));
 
-        if x0 == x1 || y0 == y1 {
+        if x0 >= x1 || y0 >= y1 {
             return Ok(());
         }
 
@@ -315,8 +622,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
         let mut dst = &mut self.get_raw_pixel_buffer()[dst_start..];
 
-        let src_start =
-            Self::PIXEL_SIZE * ((sh as i32 + y0 - y1) * sw as i32 + (sw as i32 + x0 - x1)) as usize;
+        let src_start = Self::PIXEL_SIZE * ((y0 - pos.1) * sw as i32 + (x0 - pos.0)) as usize;
         let mut src = &src[src_start..];
 
         if src_gap == 0 && dst_gap == 0 {
@@ -334,6 +640,65 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
         Ok(())
     }
+
+    fn push_clip(&mut self, rect: (BackendCoord, BackendCoord)) {
+        self.clip.push(rect);
+    }
+
+    fn pop_clip(&mut self) {
+        self.clip.pop();
+    }
+
+    fn supports_clipping(&self) -> bool {
+        true
+    }
+}
+
+/// Average each value with its neighbors within `radius`, the window is cut at both ends
+fn box_blur<I: ExactSizeIterator<Item = [u32; 3]>>(values: I, radius: usize) -> Vec<[u32; 3]> {
+    let len = values.len();
+    let mut prefix = Vec::with_capacity(len + 1);
+    prefix.push([0u32; 3]);
+    for (idx, value) in values.enumerate() {
+        let last = prefix[idx];
+        prefix.push([last[0] + value[0], last[1] + value[1], last[2] + value[2]]);
+    }
+
+    (0..len)
+        .map(|idx| {
+            let (start, end) = (idx.saturating_sub(radius), (idx + radius + 1).min(len));
+            let count = (end - start) as u32;
+            let mut result = [0; 3];
+            for (c, value) in result.iter_mut().enumerate() {
+                *value = (prefix[end][c] - prefix[start][c] + count / 2) / count;
+            }
+            result
+        })
+        .collect()
+}
+
+/// Downsample a RGB image by averaging each `factor` x `factor` block of pixels
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+fn downsample(src: &[u8], (w, h): (u32, u32), factor: u32) -> Vec<u8> {
+    let (w, h, factor) = (w as usize, h as usize, factor as usize);
+    let (dw, dh) = (w / factor, h / factor);
+    let count = (factor * factor) as u32;
+    let mut ret = Vec::with_capacity(dw * dh * 3);
+    for y in 0..dh {
+        for x in 0..dw {
+            let mut sum = [0u32; 3];
+            for sy in y * factor..(y + 1) * factor {
+                let row = &src[(sy * w + x * factor) * 3..(sy * w + (x + 1) * factor) * 3];
+                for pixel in row.chunks_exact(3) {
+                    for (s, value) in sum.iter_mut().zip(pixel) {
+                        *s += u32::from(*value);
+                    }
+                }
+            }
+            ret.extend(sum.iter().map(|s| ((s + count / 2) / count) as u8));
+        }
+    }
+    ret
 }
 
 impl<P: PixelFormat> Drop for BitMapBackend<'_, P> {
@@ -611,4 +611,173 @@ mod test {
             height as u32,
         );
     }
+
+    #[test]
+    fn test_supersampled() {
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_path = Path::new(DST_DIR).join("test_supersampled.png");
+        {
+            let mut backend = BitMapBackend::new_supersampled(&file_path, (4, 4), 2);
+            assert_eq!(backend.get_size(), (8, 8));
+            backend.draw_rect((0, 0), (8, 8), &WHITE, true).unwrap();
+            backend.draw_rect((0, 0), (4, 8), &BLACK, true).unwrap();
+            backend.draw_rect((0, 0), (5, 1), &BLACK, true).unwrap();
+            backend.present().unwrap();
+        }
+
+        let img = image::open(&file_path).unwrap().to_rgb8();
+        assert_eq!(img.dimensions(), (4, 4));
+        assert_eq!(img.get_pixel(0, 3).0, [0, 0, 0]);
+        assert_eq!(img.get_pixel(3, 3).0, [255, 255, 255]);
+        // Only one of the four rendered pixels is black
+        assert_eq!(img.get_pixel(2, 0).0, [191, 191, 191]);
+    }
+
+    #[test]
+    fn test_supersampled_factor_clamped() {
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_path = Path::new(DST_DIR).join("test_supersampled_factor_clamped.png");
+        let mut backend = BitMapBackend::new_supersampled(&file_path, (4, 4), u32::MAX);
+        assert_eq!(backend.get_size(), (64, 64));
+        backend.present().unwrap();
+    }
+}
+
+#[test]
+fn test_bitmap_fill_rects() {
+    use plotters::prelude::*;
+
+    let rects = [
+        ((0, 0), (4, 4), RED.to_backend_color()),
+        ((5, 0), (9, 4), BLUE.mix(0.5).to_backend_color()),
+        ((0, 5), (7, 9), GREEN.to_backend_color()),
+    ];
+
+    let mut batched = vec![0; 10 * 10 * 3];
+    BitMapBackend::with_buffer(&mut batched, (10, 10))
+        .fill_rects(&rects)
+        .unwrap();
+
+    let mut individual = vec![0; 10 * 10 * 3];
+    {
+        let mut back = BitMapBackend::with_buffer(&mut individual, (10, 10));
+        for (upper_left, bottom_right, color) in rects.iter() {
+            back.draw_rect(*upper_left, *bottom_right, color, true)
+                .unwrap();
+        }
+    }
+
+    assert_eq!(batched, individual);
+}
+
+#[test]
+fn test_bitmap_draw_segments() {
+    use plotters::prelude::*;
+
+    let segments = [((0, 0), (7, 0)), ((2, 1), (2, 9)), ((0, 0), (9, 9))];
+    let styles = [
+        Color::stroke_width(&RED, 1),
+        Color::stroke_width(&BLUE.mix(0.5), 1),
+        Color::stroke_width(&GREEN, 2),
+    ];
+
+    for style in styles.iter() {
+        let mut batched = vec![0; 10 * 10 * 3];
+        BitMapBackend::with_buffer(&mut batched, (10, 10))
+            .draw_segments(&segments, style)
+            .unwrap();
+
+        let mut individual = vec![0; 10 * 10 * 3];
+        {
+            let mut back = BitMapBackend::with_buffer(&mut individual, (10, 10));
+            for (from, to) in segments.iter() {
+                back.draw_line(*from, *to, style).unwrap();
+            }
+        }
+
+        assert_eq!(batched, individual);
+    }
+}
+
+#[test]
+fn test_bitmap_blur_rect() {
+    let mut buffer = vec![0; 10 * 10 * 3];
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        // A white vertical line in the middle
+        back.draw_line((5, 0), (5, 9), &plotters::prelude::WHITE)
+            .unwrap();
+        back.blur_rect((0, 0), (9, 4), 1).unwrap();
+    }
+
+    let red = |x: usize, y: usize| buffer[(y * 10 + x) * 3];
+    // The line is spread over its neighbors within the rect
+    assert_eq!(red(5, 2), 85);
+    assert_eq!(red(4, 2), 85);
+    assert_eq!(red(6, 2), 85);
+    assert_eq!(red(3, 2), 0);
+    // And untouched outside of the rect
+    assert_eq!(red(5, 5), 255);
+    assert_eq!(red(4, 5), 0);
+}
+
+#[test]
+fn test_bitmap_in_memory() {
+    use plotters::prelude::{Color, RED, WHITE};
+
+    let mut backend = BitMapBackend::new_in_memory((4, 4));
+    backend.draw_rect((0, 0), (4, 4), &WHITE, true).unwrap();
+    backend.draw_pixel((1, 2), RED.to_backend_color()).unwrap();
+
+    let mut backend = match backend.into_image() {
+        Err(BitMapBackendError::NotPresented) => BitMapBackend::new_in_memory((4, 4)),
+        _ => panic!("The image is taken before it's presented"),
+    };
+    backend.draw_rect((0, 0), (4, 4), &WHITE, true).unwrap();
+    backend.draw_pixel((1, 2), RED.to_backend_color()).unwrap();
+    backend.present().unwrap();
+
+    let image = backend.into_image().unwrap();
+    assert_eq!(image.len(), 4 * 4 * 3);
+    for (idx, pixel) in image.chunks(3).enumerate() {
+        if idx == 2 * 4 + 1 {
+            assert_eq!(pixel, &[255, 0, 0]);
+        } else {
+            assert_eq!(pixel, &[255, 255, 255]);
+        }
+    }
+
+    let mut buffer = vec![0; 4 * 4 * 3];
+    let mut backend = BitMapBackend::with_buffer(&mut buffer, (4, 4));
+    backend.present().unwrap();
+    assert!(matches!(
+        backend.into_image(),
+        Err(BitMapBackendError::InvalidBuffer)
+    ));
+}
+
+#[test]
+fn test_bitmap_in_memory_chart() {
+    use plotters::prelude::*;
+
+    let root = BitMapBackend::new_in_memory((100, 80)).into_drawing_area();
+    root.fill(&WHITE).unwrap();
+    {
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .draw_series([Rectangle::new([(0, 0), (5, 10)], BLACK.filled())])
+            .unwrap();
+        root.present().unwrap();
+    }
+
+    // The area can't give the backend away while another area shares it
+    let (upper, lower) = root.split_vertically(40);
+    let upper = upper.into_backend().err().unwrap();
+    drop((root, lower));
+    let image = upper.into_backend().ok().unwrap().into_image().unwrap();
+    assert_eq!(image.len(), 100 * 80 * 3);
+    assert_eq!(&image[..3], &[0, 0, 0]);
+    assert_eq!(&image[image.len() - 3..], &[255, 255, 255]);
 }
@@ -6,6 +6,8 @@ use image::ImageError;
 pub enum BitMapBackendError {
     /// The buffer provided is invalid, for example, wrong pixel buffer size
     InvalidBuffer,
+    /// The image is taken from the backend before it's presented
+    NotPresented,
     /// Some IO error occurs while the bitmap maniuplation
     IOError(std::io::Error),
     #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
@@ -5,9 +5,11 @@ The SVG image drawing backend
 use plotters_backend::{
     text_anchor::{HPos, VPos},
     BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
-    FontStyle, FontTransform,
+    FillPattern, FontStyle, FontTransform,
 };
 
+#[allow(unused_imports)]
+use plotters_backend::BitmapFormat;
 use std::fmt::Write as _;
 use std::fs::File;
 #[allow(unused_imports)]
@@ -24,6 +26,11 @@ fn make_svg_opacity(color: BackendColor) -> String {
     return format!("{}", color.alpha);
 }
 
+fn make_svg_dasharray(pattern: &[u32]) -> String {
+    let lengths: Vec<_> = pattern.iter().map(|l| l.to_string()).collect();
+    lengths.join(" ")
+}
+
 enum Target<'a> {
     File(String, &'a Path),
     Buffer(&'a mut String),
@@ -47,8 +54,14 @@ impl Target<'_> {
 
 enum SVGTag {
     Svg,
+    ClipPath,
+    Group,
+    /// The group opened by `push_clip`, kept apart from the ones opened by `begin_group`
+    ClipGroup,
     Circle,
+    Ellipse,
     Line,
+    Path,
     Polygon,
     Polyline,
     Rectangle,
@@ -61,12 +74,16 @@ impl SVGTag {
     fn to_tag_name(&self) -> &'static str {
         match self {
             SVGTag::Svg => "svg",
+            SVGTag::ClipPath => "clipPath",
+            SVGTag::Group | SVGTag::ClipGroup => "g",
             SVGTag::Circle => "circle",
+            SVGTag::Ellipse => "ellipse",
             SVGTag::Line => "line",
             SVGTag::Polyline => "polyline",
             SVGTag::Rectangle => "rect",
             SVGTag::Text => "text",
             SVGTag::Image => "image",
+            SVGTag::Path => "path",
             SVGTag::Polygon => "polygon",
         }
     }
@@ -78,6 +95,12 @@ pub struct SVGBackend<'a> {
     size: (u32, u32),
     tag_stack: Vec<SVGTag>,
     saved: bool,
+    /// The number of clip paths defined so far, which is used for the unique IDs of them
+    num_clip_paths: usize,
+    /// The number of `end_group` calls whose group is still covered by a clip group
+    pending_group_ends: usize,
+    /// The number of `pop_clip` calls whose clip group is still covered by an element group
+    pending_clip_pops: usize,
 }
 
 impl<'a> SVGBackend<'a> {
@@ -110,6 +133,21 @@ impl<'a> SVGBackend<'a> {
         }
     }
 
+    /// Open a self-closing stroke tag, with the dash attributes of the style if it's dashed
+    fn open_stroke_tag<S: BackendStyle>(&mut self, tag: SVGTag, attr: &[(&str, &str)], style: &S) {
+        match style.dash_pattern() {
+            Some(pattern) => {
+                let dasharray = make_svg_dasharray(pattern);
+                let dashoffset = format!("{}", style.dash_offset());
+                let mut attr = attr.to_vec();
+                attr.push(("stroke-dasharray", &dasharray));
+                attr.push(("stroke-dashoffset", &dashoffset));
+                self.open_tag(tag, &attr, true);
+            }
+            None => self.open_tag(tag, attr, true),
+        }
+    }
+
     fn close_tag(&mut self) -> bool {
         if let Some(tag) = self.tag_stack.pop() {
             let buf = self.target.get_mut();
@@ -121,6 +159,27 @@ impl<'a> SVGBackend<'a> {
         false
     }
 
+    fn count_open_tags(&self, pred: fn(&SVGTag) -> bool) -> usize {
+        self.tag_stack.iter().filter(|tag| pred(tag)).count()
+    }
+
+    /// Close the groups and clip groups whose closing was deferred because
+    /// they were covered by another kind of group
+    fn close_pending_groups(&mut self) {
+        loop {
+            match self.tag_stack.last() {
+                Some(SVGTag::Group) if self.pending_group_ends > 0 => {
+                    self.pending_group_ends -= 1;
+                }
+                Some(SVGTag::ClipGroup) if self.pending_clip_pops > 0 => {
+                    self.pending_clip_pops -= 1;
+                }
+                _ => break,
+            }
+            self.close_tag();
+        }
+    }
+
     fn init_svg_file(&mut self, size: (u32, u32)) {
         self.open_tag(
             SVGTag::Svg,
@@ -141,6 +200,9 @@ impl<'a> SVGBackend<'a> {
             size,
             tag_stack: vec![],
             saved: false,
+            num_clip_paths: 0,
+            pending_group_ends: 0,
+            pending_clip_pops: 0,
         };
 
         ret.init_svg_file(size);
@@ -158,6 +220,9 @@ impl<'a> SVGBackend<'a> {
             size,
             tag_stack: vec![],
             saved: false,
+            num_clip_paths: 0,
+            pending_group_ends: 0,
+            pending_clip_pops: 0,
         };
 
         ret.init_svg_file(size);
@@ -172,12 +237,101 @@ impl<'a> SVGBackend<'a> {
             size,
             tag_stack: vec![],
             saved: false,
+            num_clip_paths: 0,
+            pending_group_ends: 0,
+            pending_clip_pops: 0,
         };
 
         ret.init_svg_file(size);
 
         ret
     }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    /// Embed a bitmap as a base64 encoded PNG image
+    fn blit_png(
+        &mut self,
+        pos: BackendCoord,
+        (w, h): (u32, u32),
+        src: &[u8],
+        color: image::ColorType,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        use image::codecs::png::PngEncoder;
+        use image::ImageEncoder;
+
+        let mut data = vec![0; 0];
+
+        {
+            let cursor = Cursor::new(&mut data);
+
+            let encoder = PngEncoder::new(cursor);
+
+            encoder.write_image(src, w, h, color).map_err(|e| {
+                DrawingErrorKind::DrawingError(Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("Image error: {}", e),
+                ))
+            })?;
+        }
+
+        let padding = (3 - data.len() % 3) % 3;
+        for _ in 0..padding {
+            data.push(0);
+        }
+
+        let mut rem_bits = 0;
+        let mut rem_num = 0;
+
+        fn cvt_base64(from: u8) -> char {
+            (if from < 26 {
+                b'A' + from
+            } else if from < 52 {
+                b'a' + from - 26
+            } else if from < 62 {
+                b'0' + from - 52
+            } else if from == 62 {
+                b'+'
+            } else {
+                b'/'
+            })
+            .into()
+        }
+
+        let mut buf = String::new();
+        buf.push_str("data:png;base64,");
+
+        for byte in data {
+            let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
+            rem_bits = byte & ((1 << (2 + rem_num)) - 1);
+            rem_num += 2;
+
+            buf.push(cvt_base64(value));
+            if rem_num == 6 {
+                buf.push(cvt_base64(rem_bits));
+                rem_bits = 0;
+                rem_num = 0;
+            }
+        }
+
+        for _ in 0..padding {
+            buf.pop();
+            buf.push('=');
+        }
+
+        self.open_tag(
+            SVGTag::Image,
+            &[
+                ("x", &format!("{}", pos.0)),
+                ("y", &format!("{}", pos.1)),
+                ("width", &format!("{}", w)),
+                ("height", &format!("{}", h)),
+                ("href", buf.as_str()),
+            ],
+            true,
+        );
+
+        Ok(())
+    }
 }
 
 impl<'a> DrawingBackend for SVGBackend<'a> {
@@ -247,7 +401,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.color().alpha == 0.0 {
             return Ok(());
         }
-        self.open_tag(
+        self.open_stroke_tag(
             SVGTag::Line,
             &[
                 ("opacity", &make_svg_opacity(style.color())),
@@ -258,7 +412,35 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 ("x2", &format!("{}", to.0)),
                 ("y2", &format!("{}", to.1)),
             ],
-            true,
+            style,
+        );
+        Ok(())
+    }
+
+    fn draw_segments<S: BackendStyle>(
+        &mut self,
+        segments: &[(BackendCoord, BackendCoord)],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 || segments.is_empty() {
+            return Ok(());
+        }
+        let d = segments
+            .iter()
+            .fold(String::new(), |mut s, ((x0, y0), (x1, y1))| {
+                write!(s, "M{} {}L{} {}", x0, y0, x1, y1).ok();
+                s
+            });
+        self.open_stroke_tag(
+            SVGTag::Path,
+            &[
+                ("d", &d),
+                ("fill", "none"),
+                ("opacity", &make_svg_opacity(style.color())),
+                ("stroke", &make_svg_color(style.color())),
+                ("stroke-width", &format!("{}", style.stroke_width())),
+            ],
+            style,
         );
         Ok(())
     }
@@ -274,6 +456,16 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             return Ok(());
         }
 
+        if fill && style.fill_pattern() != FillPattern::Solid {
+            return plotters_backend::rasterizer::draw_rect(
+                self,
+                upper_left,
+                bottom_right,
+                style,
+                fill,
+            );
+        }
+
         let (fill, stroke) = if !fill {
             ("none".to_string(), make_svg_color(style.color()))
         } else {
@@ -297,6 +489,107 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         Ok(())
     }
 
+    fn begin_group(&mut self, id: &str) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.open_tag(SVGTag::Group, &[("id", id)], false);
+        Ok(())
+    }
+
+    fn push_clip(&mut self, (ul, br): (BackendCoord, BackendCoord)) {
+        let id = format!("plotters-clip-{}", self.num_clip_paths);
+        self.num_clip_paths += 1;
+        // The corners are inclusive, thus the box covers the whole pixels at the bottom right
+        let (x0, y0) = (ul.0.min(br.0), ul.1.min(br.1));
+        let (x1, y1) = (ul.0.max(br.0) + 1, ul.1.max(br.1) + 1);
+        self.open_tag(SVGTag::ClipPath, &[("id", &id)], false);
+        self.open_tag(
+            SVGTag::Rectangle,
+            &[
+                ("x", &format!("{}", x0)),
+                ("y", &format!("{}", y0)),
+                ("width", &format!("{}", x1 - x0)),
+                ("height", &format!("{}", y1 - y0)),
+            ],
+            true,
+        );
+        self.close_tag();
+        // The nested groups intersect their clip paths
+        self.open_tag(
+            SVGTag::ClipGroup,
+            &[("clip-path", &format!("url(#{})", id))],
+            false,
+        );
+    }
+
+    fn pop_clip(&mut self) {
+        if let Some(SVGTag::ClipGroup) = self.tag_stack.last() {
+            self.close_tag();
+            self.close_pending_groups();
+        } else if self.count_open_tags(|tag| matches!(tag, SVGTag::ClipGroup))
+            > self.pending_clip_pops
+        {
+            // The clip group can't be closed before the element groups opened inside it
+            self.pending_clip_pops += 1;
+        }
+    }
+
+    fn supports_clipping(&self) -> bool {
+        true
+    }
+
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(SVGTag::Group) = self.tag_stack.last() {
+            self.close_tag();
+            self.close_pending_groups();
+        } else if self.count_open_tags(|tag| matches!(tag, SVGTag::Group)) > self.pending_group_ends
+        {
+            // The group can't be closed before the clip groups opened inside it
+            self.pending_group_ends += 1;
+        }
+        Ok(())
+    }
+
+    fn fill_rects(
+        &mut self,
+        rects: &[(BackendCoord, BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The consecutive rectangles with the same color are merged into a single path
+        let mut idx = 0;
+        while idx < rects.len() {
+            let color = rects[idx].2;
+            let run = rects[idx..]
+                .iter()
+                .take_while(|(_, _, c)| c.rgb == color.rgb && c.alpha == color.alpha)
+                .count();
+
+            if color.alpha != 0.0 {
+                let mut d = String::new();
+                for ((x0, y0), (x1, y1), _) in &rects[idx..idx + run] {
+                    d.push_str(&format!(
+                        "M{} {}h{}v{}h{}z",
+                        x0,
+                        y0,
+                        x1 - x0,
+                        y1 - y0,
+                        x0 - x1
+                    ));
+                }
+                self.open_tag(
+                    SVGTag::Path,
+                    &[
+                        ("d", &d),
+                        ("opacity", &make_svg_opacity(color)),
+                        ("fill", &make_svg_color(color)),
+                        ("stroke", "none"),
+                    ],
+                    true,
+                );
+            }
+
+            idx += run;
+        }
+        Ok(())
+    }
+
     fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
         &mut self,
         path: I,
@@ -305,7 +598,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.color().alpha == 0.0 {
             return Ok(());
         }
-        self.open_tag(
+        self.open_stroke_tag(
             SVGTag::Polyline,
             &[
                 ("fill", "none"),
@@ -320,7 +613,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     }),
                 ),
             ],
-            true,
+            style,
         );
         Ok(())
     }
@@ -333,6 +626,10 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.color().alpha == 0.0 {
             return Ok(());
         }
+        if style.fill_pattern() != FillPattern::Solid {
+            let vert: Vec<_> = path.into_iter().collect();
+            return plotters_backend::rasterizer::fill_pattern(self, &vert, style);
+        }
         self.open_tag(
             SVGTag::Polygon,
             &[
@@ -382,6 +679,38 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         Ok(())
     }
 
+    fn draw_ellipse<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: (u32, u32),
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let (stroke, fill) = if !fill {
+            (make_svg_color(style.color()), "none".to_string())
+        } else {
+            ("none".to_string(), make_svg_color(style.color()))
+        };
+        self.open_tag(
+            SVGTag::Ellipse,
+            &[
+                ("cx", &format!("{}", center.0)),
+                ("cy", &format!("{}", center.1)),
+                ("rx", &format!("{}", radius.0)),
+                ("ry", &format!("{}", radius.1)),
+                ("opacity", &make_svg_opacity(style.color())),
+                ("fill", &fill),
+                ("stroke", &stroke),
+                ("stroke-width", &format!("{}", style.stroke_width())),
+            ],
+            true,
+        );
+        Ok(())
+    }
+
     fn draw_text<S: BackendTextStyle>(
         &mut self,
         text: &str,
@@ -459,6 +788,9 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             FontTransform::Rotate270 => {
                 attrs.push(("transform", format!("rotate(270, {}, {})", x0, y0)));
             }
+            FontTransform::RotateAngle(angle) => {
+                attrs.push(("transform", format!("rotate({}, {}, {})", angle, x0, y0)));
+            }
             _ => {}
         }
 
@@ -481,89 +813,38 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
     }
 
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
-    fn blit_bitmap<'b>(
+    fn blit_bitmap(
         &mut self,
         pos: BackendCoord,
-        (w, h): (u32, u32),
-        src: &'b [u8],
+        size: (u32, u32),
+        src: &[u8],
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        use image::codecs::png::PngEncoder;
-        use image::ImageEncoder;
-
-        let mut data = vec![0; 0];
-
-        {
-            let cursor = Cursor::new(&mut data);
-
-            let encoder = PngEncoder::new(cursor);
-
-            let color = image::ColorType::Rgb8;
-
-            encoder.write_image(src, w, h, color).map_err(|e| {
-                DrawingErrorKind::DrawingError(Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("Image error: {}", e),
-                ))
-            })?;
-        }
-
-        let padding = (3 - data.len() % 3) % 3;
-        for _ in 0..padding {
-            data.push(0);
-        }
-
-        let mut rem_bits = 0;
-        let mut rem_num = 0;
-
-        fn cvt_base64(from: u8) -> char {
-            (if from < 26 {
-                b'A' + from
-            } else if from < 52 {
-                b'a' + from - 26
-            } else if from < 62 {
-                b'0' + from - 52
-            } else if from == 62 {
-                b'+'
-            } else {
-                b'/'
-            })
-            .into()
-        }
-
-        let mut buf = String::new();
-        buf.push_str("data:png;base64,");
-
-        for byte in data {
-            let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
-            rem_bits = byte & ((1 << (2 + rem_num)) - 1);
-            rem_num += 2;
+        self.blit_png(pos, size, src, image::ColorType::Rgb8)
+    }
 
-            buf.push(cvt_base64(value));
-            if rem_num == 6 {
-                buf.push(cvt_base64(rem_bits));
-                rem_bits = 0;
-                rem_num = 0;
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn blit_bitmap_fmt(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        format: BitmapFormat,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The transparency is kept in the PNG image, which is composited by the SVG renderer
+        match format {
+            BitmapFormat::Rgb => self.blit_png(pos, size, src, image::ColorType::Rgb8),
+            BitmapFormat::Rgba => self.blit_png(pos, size, src, image::ColorType::Rgba8),
+            BitmapFormat::Bgr => {
+                self.blit_png(pos, size, &format.to_rgb(src), image::ColorType::Rgb8)
+            }
+            BitmapFormat::Bgra => {
+                let rgba: Vec<_> = src
+                    .chunks_exact(4)
+                    .flat_map(|p| [p[2], p[1], p[0], p[3]])
+                    .collect();
+                self.blit_png(pos, size, &rgba, image::ColorType::Rgba8)
             }
         }
-
-        for _ in 0..padding {
-            buf.pop();
-            buf.push('=');
-        }
-
-        self.open_tag(
-            SVGTag::Image,
-            &[
-                ("x", &format!("{}", pos.0)),
-                ("y", &format!("{}", pos.1)),
-                ("width", &format!("{}", w)),
-                ("height", &format!("{}", h)),
-                ("href", buf.as_str()),
-            ],
-            true,
-        );
-
-        Ok(())
     }
 }
 
@@ -579,10 +860,10 @@ impl Drop for SVGBackend<'_> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use plotters::element::Circle;
+    use plotters::element::{Circle, Tagged};
     use plotters::prelude::{
-        ChartBuilder, Color, IntoDrawingArea, IntoFont, SeriesLabelPosition, TextStyle, BLACK,
-        BLUE, RED, WHITE,
+        ChartBuilder, Color, IntoDrawingArea, IntoFont, SeriesLabelPosition, ShapeStyle, TextStyle,
+        BLACK, BLUE, RED, WHITE,
     };
     use plotters::style::text_anchor::{HPos, Pos, VPos};
     use std::fs;
@@ -669,6 +950,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_draw_ellipse() {
+        let mut content = String::new();
+        {
+            let mut root = SVGBackend::with_string(&mut content, (100, 100));
+            root.draw_ellipse((50, 40), (30, 10), &RED, true).unwrap();
+        }
+        assert!(content.contains(r#"<ellipse cx="50" cy="40" rx="30" ry="10""#));
+    }
+
+    #[test]
+    fn test_draw_pattern_fill() {
+        let mut content = String::new();
+        {
+            let mut root = SVGBackend::with_string(&mut content, (100, 100));
+            let style = RED.filled().pattern(FillPattern::VerticalLines);
+            root.fill_polygon(vec![(10, 10), (50, 10), (50, 50)], &style)
+                .unwrap();
+        }
+        assert!(!content.contains("<polygon"));
+        assert_eq!(content.matches("<line").count(), 5);
+    }
+
+    #[test]
+    fn test_text_rotate_angle() {
+        let mut content = String::new();
+        {
+            let mut root = SVGBackend::with_string(&mut content, (100, 100));
+            let style = TextStyle::from(("sans-serif", 20).into_font())
+                .transform(FontTransform::RotateAngle(-45.0));
+            root.draw_text("slanted", &style, (50, 60)).unwrap();
+        }
+        assert!(content.contains(r#"transform="rotate(-45, 50, 60)""#));
+    }
+
     #[test]
     fn test_text_draw() {
         let mut content: String = Default::default();
@@ -836,4 +1152,115 @@ mod test {
 
         checked_save_file("test_draw_pixel_alphas", &content);
     }
+
+    #[test]
+    fn test_fill_rects() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            let (red, blue) = (RED.to_backend_color(), BLUE.to_backend_color());
+            backend
+                .fill_rects(&[
+                    ((0, 0), (10, 10), red),
+                    ((10, 0), (20, 10), red),
+                    ((20, 0), (30, 10), blue),
+                ])
+                .unwrap();
+        }
+
+        checked_save_file("test_fill_rects", &content);
+
+        assert_eq!(content.matches("<path").count(), 2);
+        assert!(content.contains("d=\"M0 0h10v10h-10zM10 0h10v10h-10z\""));
+    }
+
+    #[test]
+    fn test_draw_segments() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            backend
+                .draw_segments(&[((0, 0), (10, 10)), ((20, 0), (20, 30))], &RED)
+                .unwrap();
+        }
+
+        checked_save_file("test_draw_segments", &content);
+
+        assert_eq!(content.matches("<path").count(), 1);
+        assert!(content.contains("d=\"M0 0L10 10M20 0L20 30\""));
+    }
+
+    #[test]
+    fn test_clip_path() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            backend.push_clip(((10, 10), (49, 49)));
+            backend.draw_rect((0, 0), (99, 99), &RED, true).unwrap();
+            backend.pop_clip();
+            backend.pop_clip();
+        }
+
+        checked_save_file("test_clip_path", &content);
+
+        assert_eq!(content.matches("<clipPath").count(), 1);
+        assert!(content.contains("width=\"40\""));
+        assert!(content.contains("clip-path=\"url(#plotters-clip-0)\""));
+        assert_eq!(content.matches("</g>").count(), 1);
+    }
+
+    #[test]
+    fn test_clip_inside_group() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            backend.begin_group("a").unwrap();
+            backend.push_clip(((10, 10), (49, 49)));
+            backend.draw_rect((0, 0), (99, 99), &RED, true).unwrap();
+            backend.end_group().unwrap();
+            assert_eq!(backend.tag_stack.len(), 3);
+            backend.pop_clip();
+            assert_eq!(backend.tag_stack.len(), 1);
+            backend.draw_rect((0, 0), (9, 9), &RED, true).unwrap();
+        }
+
+        checked_save_file("test_clip_inside_group", &content);
+
+        assert_eq!(content.matches("</g>").count(), 2);
+        let rect_after = content.rfind("<rect").unwrap();
+        assert!(content.rfind("</g>").unwrap() < rect_after);
+    }
+
+    #[test]
+    fn test_dashed_path() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            let style = ShapeStyle::from(&RED).dashed(&[6, 3]).dash_offset(2);
+            backend
+                .draw_path(vec![(0, 0), (50, 50), (100, 0)], &style)
+                .unwrap();
+            backend.draw_line((0, 10), (100, 10), &RED).unwrap();
+        }
+
+        checked_save_file("test_dashed_path", &content);
+
+        assert_eq!(content.matches("stroke-dasharray=\"6 3\"").count(), 1);
+        assert!(content.contains("stroke-dashoffset=\"2\""));
+    }
+
+    #[test]
+    fn test_tagged_element() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(&Tagged::new("point-<0>", Circle::new((50, 50), 5, RED)))
+                .unwrap();
+        }
+
+        checked_save_file("test_tagged_element", &content);
+
+        assert!(content.contains("<g id=\"point-&lt;0&gt;\">\n<circle"));
+        assert!(content.contains("/>\n</g>"));
+    }
 }
@@ -1,11 +1,14 @@
 use super::context::ChartContext;
+use super::series::{LegendStyle, SeriesLabelPosition};
 
-use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
+use crate::coord::cartesian::{Cartesian2d, Cartesian3d, PixelRounding};
+use crate::coord::polar::Polar;
 use crate::coord::ranged1d::AsRangedCoord;
 use crate::coord::Shift;
 
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, TextOverflow};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontFamily, IntoTextStyle, SizeDesc, TextStyle};
 
 use plotters_backend::DrawingBackend;
 
@@ -47,6 +50,37 @@ pub enum LabelAreaPosition {
     Right = 3,
 }
 
+/**
+Specifies where the caption of the chart is drawn.
+
+This is used to configure the caption with function [`ChartBuilder::caption_position()`].
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("caption_position.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.caption("Figure 1: A bottom caption", ("sans-serif", 15))
+    .caption_position(CaptionPosition::Bottom)
+    .set_left_and_bottom_label_area_size(35);
+let mut chart_context = chart_builder.build_cartesian_2d(0.0..4.0, 0.0..3.0).unwrap();
+chart_context.configure_mesh().draw().unwrap();
+```
+*/
+#[derive(Copy, Clone)]
+pub enum CaptionPosition {
+    /// Centered at the top of the chart, the space of the caption is reserved
+    Top,
+    /// Centered at the bottom of the chart, the space of the caption is reserved
+    Bottom,
+    /// The top center of the caption is placed at the given pixel position, which is relative to
+    /// the chart area inside the margins. No space is reserved, so the caption is overlaid on
+    /// the chart
+    Custom(i32, i32),
+}
+
 /**
 The helper object to create a chart context, which is used for the high-level figure drawing.
 
@@ -60,7 +94,13 @@ pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     overlap_plotting_area: [bool; 4],
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
+    title_autofit: bool,
+    title_min_size: f64,
+    caption_position: CaptionPosition,
     margin: [u32; 4],
+    default_stroke_width: u32,
+    pixel_rounding: PixelRounding,
+    series_label_area: Option<(SeriesLabelPosition, Vec<String>)>,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -92,8 +132,14 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             label_area_size: [0; 4],
             root_area: root,
             title: None,
+            title_autofit: false,
+            title_min_size: 10.0,
+            caption_position: CaptionPosition::Top,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            default_stroke_width: 1,
+            pixel_rounding: PixelRounding::default(),
+            series_label_area: None,
         }
     }
 
@@ -264,7 +310,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     - `caption`: The caption of the chart
     - `style`: The text style
 
-    The title or caption will be centered at the top of the drawing area.
+    The title or caption will be centered at the top of the drawing area, unless it's placed
+    elsewhere with [`ChartBuilder::caption_position()`].
 
     See [`ChartBuilder::on()`] for more information and examples.
     */
@@ -277,9 +324,237 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             caption.as_ref().to_string(),
             style.into_text_style(self.root_area),
         ));
+        self.title_autofit = false;
+        self
+    }
+
+    /**
+    Sets the caption of the chart, the font size is reduced until the caption fits in the
+    width of the chart.
+
+    - `caption`: The caption of the chart
+    - `family`: The font family of the caption
+
+    The caption starts at 30 pixels and is shrunk no further than the size set by
+    [`ChartBuilder::caption_min_size()`], 10 pixels by default. If the caption still doesn't
+    fit at the minimum size, it's truncated with an ellipsis.
+    */
+    pub fn caption_autofit<S: AsRef<str>, F: Into<FontFamily<'b>>>(
+        &mut self,
+        caption: S,
+        family: F,
+    ) -> &mut Self {
+        self.caption(caption, (family.into(), 30));
+        self.title_autofit = true;
+        self
+    }
+
+    /**
+    Sets the minimum font size of the caption set by [`ChartBuilder::caption_autofit()`].
+
+    - `size`: The minimum font size in pixels
+    */
+    pub fn caption_min_size(&mut self, size: f64) -> &mut Self {
+        self.title_min_size = size;
+        self
+    }
+
+    /**
+    Sets where the caption of the chart is drawn.
+
+    - `position`: The position of the caption, [`CaptionPosition::Top`] by default
+
+    The space of the caption is reserved with [`CaptionPosition::Top`] and
+    [`CaptionPosition::Bottom`]. A caption at [`CaptionPosition::Custom`] is overlaid on the
+    chart, it's drawn when the chart is built, so anything drawn on the chart afterwards is on
+    top of it.
+
+    See [`CaptionPosition`] for more information and examples.
+    */
+    pub fn caption_position(&mut self, position: CaptionPosition) -> &mut Self {
+        self.caption_position = position;
         self
     }
 
+    /**
+    Sets the default stroke width of the chart.
+
+    - `width`: The stroke width in pixels, 1 by default
+
+    The series and the legend of the chart are drawn with this stroke width when their styles
+    don't set one, for example `&RED` or `RED.mix(0.5)`, see [`ChartContext::style()`] as well.
+    This is useful to make all the series thicker for a presentation, or thinner for print,
+    without repeating the stroke width on each element. Styles that specify the stroke width
+    explicitly are not affected.
+    */
+    pub fn default_stroke_width(&mut self, width: u32) -> &mut Self {
+        self.default_stroke_width = width;
+        self
+    }
+
+    /**
+    Sets how the coordinates of a 2D Cartesian chart are rounded to pixels.
+
+    - `rounding`: The rounding mode, [`PixelRounding::Truncate`] by default
+
+    With [`PixelRounding::Round`], the mapped coordinates are rounded to the nearest pixel
+    instead of being truncated, which improves the alignment of the grid lines, the axes and
+    the thin lines of the data.
+    */
+    pub fn pixel_rounding(&mut self, rounding: PixelRounding) -> &mut Self {
+        self.pixel_rounding = rounding;
+        self
+    }
+
+    /**
+    Reserves a strip outside the plotting area for the series labels, so that the legend doesn't
+    overlap the data. The plotting area shrinks to leave room for the strip, and the series labels
+    are drawn into the strip by [`ChartContext::configure_series_labels()`] with the same position.
+
+    - `pos`: [`SeriesLabelPosition::OutsideRight`] or [`SeriesLabelPosition::OutsideBottom`],
+      no space is reserved for the other positions
+    - `labels`: The labels of the series, the strip is sized from the legend box of the labels
+      in the default font of the series labels. No space is reserved if there's no label
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("series_label_area.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .series_label_area(SeriesLabelPosition::OutsideRight, &["Rising", "Falling"])
+        .build_cartesian_2d(0..10, 0..10)
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    chart
+        .draw_series(LineSeries::new((0..10).map(|x| (x, x)), RED))
+        .unwrap()
+        .label("Rising")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+    chart
+        .draw_series(LineSeries::new((0..10).map(|x| (x, 9 - x)), BLUE))
+        .unwrap()
+        .label("Falling")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::OutsideRight)
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn series_label_area<S: AsRef<str>>(
+        &mut self,
+        pos: SeriesLabelPosition,
+        labels: &[S],
+    ) -> &mut Self {
+        let labels = labels.iter().map(|l| l.as_ref().to_string()).collect();
+        self.series_label_area = Some((pos, labels));
+        self
+    }
+
+    /// Reserve the strip of the series labels, shrink the area and return the strip
+    fn reserve_series_label_area(
+        &self,
+        drawing_area: &mut DrawingArea<DB, Shift>,
+    ) -> Result<Option<DrawingArea<DB, Shift>>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (pos, labels) = match self.series_label_area {
+            Some((ref pos, ref labels)) => (pos, labels),
+            None => return Ok(None),
+        };
+
+        let (legend_w, legend_h) = match LegendStyle::new().measure::<DB, _>(labels)? {
+            Some(size) => size,
+            None => return Ok(None),
+        };
+
+        // The legend box is placed 5 pixels away from the edges of the strip
+        let (w, h) = drawing_area.dim_in_pixel();
+        let (plotting_area, strip) = match pos {
+            SeriesLabelPosition::OutsideRight => {
+                drawing_area.split_horizontally(w.saturating_sub(legend_w + 10))
+            }
+            SeriesLabelPosition::OutsideBottom => {
+                drawing_area.split_vertically(h.saturating_sub(legend_h + 10))
+            }
+            _ => return Ok(None),
+        };
+
+        *drawing_area = plotting_area;
+        Ok(Some(strip))
+    }
+
+    /// Draw the caption on the drawing area, shrink the area and return the offset of it
+    fn draw_caption(
+        &self,
+        drawing_area: &mut DrawingArea<DB, Shift>,
+    ) -> Result<(i32, i32), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (title, style) = match self.title {
+            Some((ref title, ref style)) => (title, style),
+            None => return Ok((0, 0)),
+        };
+
+        let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+        let (w, h) = drawing_area.dim_in_pixel();
+
+        let pos = match self.caption_position {
+            CaptionPosition::Custom(x, y) => (x, y),
+            _ => (w as i32 / 2, 0),
+        };
+
+        let mut title = title.clone();
+        let overflow = if self.title_autofit {
+            let min_size = self.title_min_size;
+            let mut min_style = style.pos(Pos::new(HPos::Center, VPos::Top));
+            min_style.font = min_style.font.resize(min_size);
+            if !drawing_area.text_fits(&title, &min_style, pos)? {
+                let mut chars: Vec<_> = title.chars().collect();
+                while !chars.is_empty() {
+                    chars.pop();
+                    title = chars.iter().collect::<String>() + "...";
+                    if drawing_area.text_fits(&title, &min_style, pos)? {
+                        break;
+                    }
+                }
+            }
+            TextOverflow::ShrinkToFit { min_size }
+        } else {
+            TextOverflow::Ignore
+        };
+
+        match self.caption_position {
+            CaptionPosition::Top => {
+                *drawing_area = drawing_area.titled_fit(&title, style.clone(), overflow)?;
+            }
+            CaptionPosition::Bottom => {
+                let style = style.pos(Pos::new(HPos::Center, VPos::Top));
+                let style = drawing_area.fit_text_style(&title, style, pos, overflow)?;
+
+                let (_, text_h) = drawing_area.estimate_text_size(&title, &style)?;
+                let y_padding = (text_h / 2).min(5) as i32;
+                let caption_h = y_padding * 2 + text_h as i32;
+
+                drawing_area.draw_text(
+                    &title,
+                    &style,
+                    (pos.0, h as i32 - caption_h + y_padding),
+                )?;
+                *drawing_area = drawing_area
+                    .clone()
+                    .shrink((0, 0), (w as i32, (h as i32 - caption_h).max(0)));
+            }
+            CaptionPosition::Custom(..) => {
+                let style = style.pos(Pos::new(HPos::Center, VPos::Top));
+                let style = drawing_area.fit_text_style(&title, style, pos, overflow)?;
+                drawing_area.draw_text(&title, &style, pos)?;
+            }
+        }
+
+        let (current_dx, current_dy) = drawing_area.get_base_pixel();
+        Ok((current_dx - origin_dx, current_dy - origin_dy))
+    }
+
     /// This function has been renamed to [`ChartBuilder::build_cartesian_2d()`] and is to be removed in the future.
     #[allow(clippy::type_complexity)]
     #[deprecated(
@@ -327,14 +602,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
+        let (title_dx, title_dy) = self.draw_caption(&mut drawing_area)?;
+        let series_label_area = self.reserve_series_label_area(&mut drawing_area)?;
 
         let (w, h) = drawing_area.dim_in_pixel();
 
@@ -354,6 +623,14 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             actual_drawing_area_pos[idx] += split_point;
         }
 
+        // If the label areas don't fit, the plotting area collapses rather than being flipped
+        for (start, end, size) in [(0, 1, h as i32), (2, 3, w as i32)] {
+            actual_drawing_area_pos[start] = actual_drawing_area_pos[start].max(0).min(size);
+            actual_drawing_area_pos[end] = actual_drawing_area_pos[end]
+                .max(actual_drawing_area_pos[start])
+                .min(size);
+        }
+
         // Now the root drawing area is to be split into
         //
         // +----------+------------------------------+------+
@@ -423,19 +700,25 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         std::mem::swap(&mut y_label_area[0], &mut label_areas[2]);
         std::mem::swap(&mut y_label_area[1], &mut label_areas[3]);
 
+        let mut coord = Cartesian2d::new(x_spec, y_spec, pixel_range);
+        coord.set_pixel_rounding(self.pixel_rounding);
+
         Ok(ChartContext {
             x_label_area,
             y_label_area,
-            drawing_area: drawing_area.apply_coord_spec(Cartesian2d::new(
-                x_spec,
-                y_spec,
-                pixel_range,
-            )),
+            drawing_area: drawing_area.apply_coord_spec(coord),
             series_anno: vec![],
             drawing_area_pos: (
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
                 actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
             ),
+            default_stroke_width: self.default_stroke_width,
+            deferred_grid: None,
+            held_grid: None,
+            data_extent: None,
+            mesh_cache: None,
+            mesh_labels: None,
+            series_label_area,
         })
     }
 
@@ -470,14 +753,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
+        let (title_dx, title_dy) = self.draw_caption(&mut drawing_area)?;
+        let series_label_area = self.reserve_series_label_area(&mut drawing_area)?;
 
         let pixel_range = drawing_area.get_pixel_range();
 
@@ -495,6 +772,91 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
             ),
+            default_stroke_width: self.default_stroke_width,
+            deferred_grid: None,
+            held_grid: None,
+            data_extent: None,
+            mesh_cache: None,
+            mesh_labels: None,
+            series_label_area,
+        })
+    }
+
+    /**
+    Builds a chart with a polar coordinate system, such as a radar chart.
+
+    The plotting area is the drawing area without the margins and the caption, the center of
+    the polar coordinate is the center of the plotting area, and the end of the radius range is
+    mapped to the largest circle which fits in the plotting area. The label area sizes are not
+    used by the polar coordinate.
+
+    - `r_spec`: Specifies the radius range and data properties
+    - `a_spec`: Specifies the angle range and data properties, which is mapped to a full turn
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("build_polar.svg", (300, 300)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(10)
+        .build_polar(0.0..5.0, 0.0..6.0)
+        .unwrap();
+    chart
+        .draw_series(std::iter::once(Polygon::new(
+            (0..6).map(|i| ((i % 3 + 3) as f64, i as f64)).collect::<Vec<_>>(),
+            BLUE.mix(0.3),
+        )))
+        .unwrap();
+    ```
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_polar<R: AsRangedCoord, A: AsRangedCoord>(
+        &mut self,
+        r_spec: R,
+        a_spec: A,
+    ) -> Result<
+        ChartContext<'a, DB, Polar<R::CoordDescType, A::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        let (title_dx, title_dy) = self.draw_caption(&mut drawing_area)?;
+        let series_label_area = self.reserve_series_label_area(&mut drawing_area)?;
+
+        let (x_range, y_range) = drawing_area.get_pixel_range();
+        let (w, h) = (x_range.end - x_range.start, y_range.end - y_range.start);
+        let center = (
+            (x_range.start + x_range.end - 1) / 2,
+            (y_range.start + y_range.end - 1) / 2,
+        );
+        let radius = ((w.min(h) - 1) / 2).max(0) as u32;
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area.apply_coord_spec(Polar::new(r_spec, a_spec, center, radius)),
+            series_anno: vec![],
+            drawing_area_pos: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+            default_stroke_width: self.default_stroke_width,
+            deferred_grid: None,
+            held_grid: None,
+            data_extent: None,
+            mesh_cache: None,
+            mesh_labels: None,
+            series_label_area,
         })
     }
 }
@@ -565,4 +927,112 @@ mod test {
         chart.caption("This is a test case", ("serif", 10));
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
+
+    #[test]
+    fn test_caption_autofit() {
+        let drawing_area = create_mocked_drawing_area(100, 200, |m| {
+            m.check_draw_text(|_, _, size, _, text| {
+                assert!(size >= 12.0);
+                assert!(size < 30.0);
+                assert!(text.ends_with("..."));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        ChartBuilder::on(&drawing_area)
+            .caption_autofit(
+                "This is a very long caption which never fits the chart",
+                "sans-serif",
+            )
+            .caption_min_size(12.0)
+            .build_cartesian_2d(0..1, 0..1)
+            .expect("Build");
+    }
+    #[test]
+    fn test_caption_position() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, _| {
+                assert!(pos.1 > 50);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .caption("Bottom", ("serif", 10))
+            .caption_position(CaptionPosition::Bottom)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build");
+        let (x_range, y_range) = chart.plotting_area().get_pixel_range();
+        assert_eq!((x_range, y_range.start), (0..100, 0));
+        assert!(y_range.end < 100);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, _| {
+                assert_eq!(pos, (30, 40));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .caption("Overlay", ("serif", 10))
+            .caption_position(CaptionPosition::Custom(30, 40))
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build");
+        assert_eq!(chart.plotting_area().get_pixel_range(), (0..100, 0..100));
+    }
+
+    #[test]
+    fn test_pixel_rounding() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build");
+        assert_eq!(chart.backend_coord(&(4.0, 4.0)), (39, 60));
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .pixel_rounding(PixelRounding::Round)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build");
+        assert_eq!(chart.backend_coord(&(4.0, 4.0)), (40, 59));
+        assert_eq!(
+            chart.backend_coord(&(f64::NAN, 4.0)).0,
+            crate::coord::NON_FINITE_COORD
+        );
+
+        let chart = chart.set_secondary_coord(0.0..10.0, 0.0..10.0);
+        assert_eq!(
+            chart.secondary_plotting_area().map_coordinate(&(4.0, 4.0)),
+            (40, 60)
+        );
+    }
+
+    #[test]
+    fn test_build_polar() {
+        use crate::coord::ReverseCoordTranslate;
+
+        let drawing_area = create_mocked_drawing_area(200, 100, |_| {});
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .build_polar(0.0..10.0, 0.0..360.0)
+            .expect("Build");
+        assert_eq!(
+            chart.plotting_area().map_coordinate(&(0.0, 123.0)),
+            (99, 49)
+        );
+        assert_eq!(chart.plotting_area().map_coordinate(&(10.0, 0.0)), (99, 10));
+        assert_eq!(
+            chart.plotting_area().map_coordinate(&(10.0, 90.0)),
+            (138, 49)
+        );
+        assert_eq!(
+            chart
+                .as_coord_spec()
+                .reverse_translate((99, 30))
+                .map(|(r, _): (f64, f64)| r.round()),
+            Some(5.0)
+        );
+    }
 }
@@ -2,10 +2,12 @@ use std::borrow::Borrow;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
+use crate::chart::mesh::{clip_mesh_line, DeferredGrid, HeldGrid, MeshCache};
 use crate::chart::{SeriesAnno, SeriesLabelStyle};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::element::{BackendCoordOnly, CoordMapper, Drawable, PointCollection};
+use crate::style::{with_default_stroke_width, Color, ShapeStyle};
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
@@ -28,6 +30,13 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) drawing_area: DrawingArea<DB, CT>,
     pub(crate) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(crate) drawing_area_pos: (i32, i32),
+    pub(crate) default_stroke_width: u32,
+    pub(crate) deferred_grid: Option<DeferredGrid>,
+    pub(crate) held_grid: Option<HeldGrid>,
+    pub(crate) data_extent: Option<(BackendCoord, BackendCoord)>,
+    pub(crate) mesh_cache: Option<MeshCache>,
+    pub(crate) mesh_labels: Option<(usize, usize)>,
+    pub(crate) series_label_area: Option<DrawingArea<DB, Shift>>,
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -79,6 +88,45 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         &self.drawing_area
     }
 
+    /// Get the default stroke width of the chart, see [`ChartBuilder::default_stroke_width()`]
+    pub fn default_stroke_width(&self) -> u32 {
+        self.default_stroke_width
+    }
+
+    /// Set the default stroke width of the chart, see [`ChartBuilder::default_stroke_width()`]
+    pub fn set_default_stroke_width(&mut self, width: u32) -> &mut Self {
+        self.default_stroke_width = width;
+        self
+    }
+
+    /**
+    Make a shape style from a color, using the default stroke width of the chart.
+
+    The styles made from a color, such as `&RED`, are drawn with the default stroke width when
+    they're used by a series or a legend of this chart, see
+    [`StrokeWidthKind`](crate::style::StrokeWidthKind). This makes the
+    default explicit, so the style is drawn with it anywhere.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("chart_default_stroke_width.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .default_stroke_width(3)
+        .build_cartesian_2d(0..10, 0..10)
+        .unwrap();
+    // Drawn with the 3 pixel default stroke width
+    chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), chart.style(&RED))).unwrap();
+    // An explicit stroke width still wins
+    chart.draw_series(LineSeries::new((0..10).map(|x| (x, 10 - x)), BLUE.stroke_width(1))).unwrap();
+    ```
+    */
+    pub fn style<C: Color>(&self, color: C) -> ShapeStyle {
+        color.stroke_width(self.default_stroke_width)
+    }
+
     /// Cast the reference to a chart context to a reference to underlying coordinate specification.
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
@@ -92,6 +140,7 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub(crate) fn draw_series_impl<B, E, R, S>(
         &mut self,
         series: S,
+        overflow: u32,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         B: CoordMapper,
@@ -100,10 +149,42 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
-        }
-        Ok(())
+        // The styles without their own stroke width are drawn with the default of the chart
+        with_default_stroke_width(self.default_stroke_width, || {
+            for element in series {
+                let element = element.borrow();
+                // Only the point-like elements, which have a single point, may overflow
+                let overflow = if overflow > 0 && element.point_iter().into_iter().nth(1).is_none()
+                {
+                    overflow
+                } else {
+                    0
+                };
+                let extent = &mut self.data_extent;
+                self.drawing_area
+                    .draw_and_inspect(element, overflow, |output| {
+                        let (x, y) = match B::backend_coord(output) {
+                            Some(coord) if !B::is_non_finite(output) => coord,
+                            _ => return,
+                        };
+                        *extent = Some(match *extent {
+                            Some((min, max)) => {
+                                ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+                            }
+                            None => ((x, y), (x, y)),
+                        });
+                    })?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Get the bounding box of all the data series drawn on this chart so far, in the backend
+    /// coordinate. The points out of the plotting area count as where they are drawn, see
+    /// [`ChartContext::draw_series_with_overflow`]. Returns `None` if no data point has been
+    /// drawn yet.
+    pub fn data_bounding_box(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.data_extent
     }
 
     pub(crate) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
@@ -128,9 +209,72 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.draw_series_impl(series)?;
+        self.draw_held_grid()?;
+        self.draw_series_impl(series, 0)?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /**
+    Draws a data series like [`ChartContext::draw_series()`], but the point-like elements of the
+    series, such as the markers of a [`crate::series::PointSeries`], may overflow the plotting
+    area by the given number of pixels. By default, the points out of the plotting area are
+    moved onto its edges, which cuts off the markers near the edges. With some overflow, such
+    markers are drawn fully. The elements with more than one point, such as lines, areas and
+    rectangles, never overflow the plotting area.
+
+    - `series`: The data series
+    - `px`: The overflow in pixels
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("draw_series_with_overflow.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(0.0..1.0, 0.0..1.0).unwrap();
+    chart.draw_series_with_overflow([Circle::new((1.02, 0.5), 5, RED.filled())], 5).unwrap();
+    ```
+    */
+    pub fn draw_series_with_overflow<E, R, S>(
+        &mut self,
+        series: S,
+        px: u32,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, CT::From, BackendCoordOnly>,
+        E: Drawable<DB, BackendCoordOnly>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_held_grid()?;
+        self.draw_series_impl(series, px)?;
         Ok(self.alloc_series_anno())
     }
+
+    /// Draw the grid lines held back by [`ChartContext::draw_series_behind_grid`], if any
+    pub(crate) fn draw_held_grid(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let grid = match self.held_grid.take() {
+            Some(grid) => grid,
+            None => return Ok(()),
+        };
+        let extent = if grid.within_data {
+            self.data_extent
+        } else {
+            None
+        };
+        if self.drawing_area.is_empty() {
+            return Ok(());
+        }
+        self.drawing_area.backend_ops(|b| {
+            for &(is_x_mesh, from, to, style) in grid.lines.iter() {
+                let line = match extent {
+                    Some(extent) => clip_mesh_line((from, to), is_x_mesh, extent),
+                    None => Some((from, to)),
+                };
+                if let Some((from, to)) = line {
+                    b.draw_line(from, to, &style)?;
+                }
+            }
+            Ok(())
+        })
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +330,30 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[test]
+    fn test_secondary_mesh_without_labels() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 0);
+                assert!(b.num_draw_line_call > 10);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Top, 20)
+            .set_label_area_size(LabelAreaPosition::Right, 20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart")
+            .set_secondary_coord(0.0..1.0, 0.0..1.0);
+
+        chart
+            .configure_secondary_axes()
+            .enable_mesh()
+            .disable_secondary_labels()
+            .draw()
+            .expect("Draw secondary axes");
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -218,4 +386,819 @@ mod test {
             .draw_series(std::iter::once(Circle::new((5, 5, 5), 5, &RED)))
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_default_stroke_width() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, s, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 3);
+            });
+            m.check_draw_path(|c, s, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(s, 1);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .default_stroke_width(3)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        assert_eq!(chart.default_stroke_width(), 3);
+
+        let style = chart.style(RED);
+        chart
+            .draw_series(LineSeries::new((0..10).map(|x| (x, x)), style))
+            .expect("Drawing error");
+        chart
+            .draw_series(LineSeries::new(
+                (0..10).map(|x| (x, x)),
+                BLUE.stroke_width(1),
+            ))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_default_stroke_width_for_plain_color() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, s, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 3);
+            });
+            m.check_draw_path(|c, s, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(s, 1);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .default_stroke_width(3)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+            .expect("Drawing error");
+        chart
+            .draw_series(LineSeries::new(
+                (0..10).map(|x| (x, x)),
+                BLUE.stroke_width(1),
+            ))
+            .expect("Drawing error");
+
+        // Outside of the chart, a plain color keeps its own stroke width.
+        let style: ShapeStyle = RED.into();
+        assert_eq!(plotters_backend::BackendStyle::stroke_width(&style), 1);
+    }
+
+    #[test]
+    fn test_grid_on_top() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        for grid_on_top in [false, true] {
+            let log = Rc::new(RefCell::new(vec![]));
+            let (path_log, line_log, rect_log) = (log.clone(), log.clone(), log.clone());
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_path(move |c, _, _| {
+                    // The grid lines are translucent, the axes are opaque
+                    path_log
+                        .borrow_mut()
+                        .push(if c.3 < 1.0 { "grid" } else { "axis" });
+                });
+                m.check_draw_line(move |c, _, _, _| {
+                    line_log
+                        .borrow_mut()
+                        .push(if c.3 < 1.0 { "grid" } else { "axis" });
+                });
+                m.check_draw_rect(move |_, _, _, _, _| rect_log.borrow_mut().push("data"));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .set_all_label_area_size(20)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+            chart
+                .configure_mesh()
+                .grid_on_top(grid_on_top)
+                .draw()
+                .expect("Draw mesh");
+            chart
+                .draw_series(std::iter::once(Rectangle::new(
+                    [(2, 2), (8, 8)],
+                    RED.filled(),
+                )))
+                .expect("Drawing error");
+            chart.draw_deferred_grid().expect("Draw grid");
+
+            let log = log.borrow();
+            let data_idx = log.iter().position(|x| *x == "data").unwrap();
+            assert!(log.contains(&"axis"));
+            if grid_on_top {
+                assert!(log[..data_idx].iter().all(|x| *x == "axis"));
+                assert!(log[data_idx + 1..].iter().all(|x| *x == "grid"));
+                assert!(!log[data_idx + 1..].is_empty());
+            } else {
+                assert_eq!(data_idx, log.len() - 1);
+                assert!(log.contains(&"grid"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_draw_series_behind_grid() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let log = Rc::new(RefCell::new(vec![]));
+        let (path_log, line_log, rect_log) = (log.clone(), log.clone(), log.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |c, _, _| {
+                path_log
+                    .borrow_mut()
+                    .push(if c.3 < 1.0 { "grid" } else { "axis" });
+            });
+            m.check_draw_line(move |c, _, _, _| {
+                line_log
+                    .borrow_mut()
+                    .push(if c.3 < 1.0 { "grid" } else { "axis" });
+            });
+            m.check_draw_rect(move |c, _, _, _, _| {
+                rect_log.borrow_mut().push(if c == RED.to_rgba() {
+                    "behind"
+                } else {
+                    "front"
+                });
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .grid_on_top(true)
+            .draw()
+            .expect("Draw mesh");
+        chart
+            .draw_series_behind_grid(std::iter::once(Rectangle::new(
+                [(2, 2), (8, 8)],
+                RED.filled(),
+            )))
+            .expect("Drawing error");
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [(3, 3), (7, 7)],
+                BLUE.filled(),
+            )))
+            .expect("Drawing error");
+        // The grid is already drawn in front of the series behind it
+        chart.draw_deferred_grid().expect("Draw grid");
+
+        let log = log.borrow();
+        let behind_idx = log.iter().position(|x| *x == "behind").unwrap();
+        let front_idx = log.iter().position(|x| *x == "front").unwrap();
+        assert!(log[..behind_idx].iter().all(|x| *x == "axis"));
+        assert!(log[behind_idx + 1..front_idx].iter().all(|x| *x == "grid"));
+        assert!(front_idx > behind_idx + 1);
+        assert_eq!(front_idx, log.len() - 1);
+    }
+
+    #[test]
+    fn test_set_range() {
+        use crate::coord::ReverseCoordTranslate;
+
+        let drawing_area = create_mocked_drawing_area(220, 220, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0..100, 0..100)
+            .expect("Create chart");
+
+        let origin = chart.backend_coord(&(0, 0));
+        let corner = chart.backend_coord(&(100, 100));
+
+        chart.set_x_range(50..150);
+        chart.set_y_range(-100..100);
+        assert_eq!(chart.x_range(), 50..150);
+        assert_eq!(chart.y_range(), -100..100);
+
+        assert_eq!(chart.backend_coord(&(50, -100)), origin);
+        assert_eq!(chart.backend_coord(&(150, 100)), corner);
+        // The reverse translation uses the new range as well
+        let (x, y): (i32, i32) = chart
+            .as_coord_spec()
+            .reverse_translate(origin)
+            .expect("Reverse translate");
+        assert!((x - 50).abs() <= 1 && (y + 100).abs() <= 1);
+
+        chart.configure_mesh().draw().expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_label_formatter_precision() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                let (_, decimals) = text.split_once('.').expect("Decimal point");
+                assert_eq!(decimals.len(), 2, "Label {:?}", text);
+            });
+            m.drop_check(|b| assert!(b.num_draw_text_call > 0));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(40)
+            .build_cartesian_2d(0.0..1.0, 0f32..3f32)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_label_formatter_precision(2)
+            .y_label_formatter_precision(2)
+            .draw()
+            .expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_axes_at_origin() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        for (y_range, crossing) in [(-1.0..1.0, true), (1.0..2.0, false)] {
+            let axes = Rc::new(RefCell::new(vec![]));
+            let axes_log = axes.clone();
+            let drawing_area = create_mocked_drawing_area(400, 400, move |m| {
+                m.check_draw_path(move |c, _, path| {
+                    if c.3 == 1.0 {
+                        axes_log.borrow_mut().push(path);
+                    }
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .set_label_area_size(LabelAreaPosition::Left, 40)
+                .set_label_area_size(LabelAreaPosition::Bottom, 40)
+                .build_cartesian_2d(-1.0..1.0, y_range)
+                .expect("Create chart");
+            let (ox, oy) = chart.backend_coord(&(0.0, 0.0));
+            chart
+                .configure_mesh()
+                .axes_at_origin(true)
+                .draw()
+                .expect("Draw mesh");
+
+            // The spines are the paths that span most of the plotting area
+            let axes = axes.borrow();
+            let long_paths: Vec<_> = axes
+                .iter()
+                .filter(|p| (p[0].0 - p[1].0).abs() + (p[0].1 - p[1].1).abs() > 100)
+                .collect();
+            assert_eq!(long_paths.len(), 2);
+            assert!(long_paths.iter().any(|p| p[0].0 == ox && p[1].0 == ox));
+            let x_axis = long_paths.iter().find(|p| p[0].1 == p[1].1).unwrap();
+            if crossing {
+                assert_eq!(x_axis[0].1, oy);
+            } else {
+                assert_eq!(x_axis[0].1, 360);
+            }
+        }
+    }
+
+    #[test]
+    fn test_axis_arrows() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        for at_origin in [false, true] {
+            let tips = Rc::new(RefCell::new(vec![]));
+            let tips_log = tips.clone();
+            let drawing_area = create_mocked_drawing_area(400, 400, move |m| {
+                m.check_fill_polygon(move |_, points| {
+                    assert_eq!(points.len(), 3);
+                    tips_log.borrow_mut().push(points[0]);
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .set_label_area_size(LabelAreaPosition::Left, 40)
+                .set_label_area_size(LabelAreaPosition::Bottom, 40)
+                .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+                .expect("Create chart");
+            let (ox, oy) = chart.backend_coord(&(0.0, 0.0));
+            chart
+                .configure_mesh()
+                .axes_at_origin(at_origin)
+                .axis_arrow_size(10)
+                .draw()
+                .expect("Draw mesh");
+
+            let tips = tips.borrow();
+            assert_eq!(tips.len(), 2);
+            let x_tip = *tips.iter().max_by_key(|p| p.0).unwrap();
+            let y_tip = *tips.iter().min_by_key(|p| p.1).unwrap();
+            if at_origin {
+                assert_eq!(x_tip.1, oy);
+                assert_eq!(y_tip.0, ox);
+            } else {
+                assert_eq!(x_tip.1, 360);
+                assert_eq!(y_tip.0, 39);
+            }
+            assert!(x_tip.0 >= 398);
+            assert!(y_tip.1 <= 1);
+        }
+    }
+
+    #[test]
+    fn test_plot_background() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                assert_eq!(c, GREEN.to_rgba());
+                assert_eq!((u, d), ((30, 0), (200, 170)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert!(b.num_draw_line_call > 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Left, 30)
+            .set_label_area_size(LabelAreaPosition::Bottom, 30)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .plot_background(&GREEN)
+            .draw()
+            .expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_grid_within_data() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let lines_log = lines.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_line(move |_, _, from, to| lines_log.borrow_mut().push((from, to)));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        assert_eq!(chart.data_bounding_box(), None);
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 3, RED)))
+            .expect("Drawing error");
+        chart
+            .draw_series(std::iter::once(Circle::new((3, 7), 3, RED)))
+            .expect("Drawing error");
+
+        let (min, max) = chart.data_bounding_box().expect("Bounding box");
+        assert_eq!(min, chart.backend_coord(&(3, 7)));
+        assert_eq!(max, chart.backend_coord(&(5, 5)));
+
+        chart
+            .configure_mesh()
+            .grid_within_data(true)
+            .draw()
+            .expect("Draw mesh");
+
+        let lines = lines.borrow();
+        assert!(!lines.is_empty());
+        for &(from, to) in lines.iter() {
+            for p in [from, to] {
+                assert!(min.0 <= p.0 && p.0 <= max.0);
+                assert!(min.1 <= p.1 && p.1 <= max.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_trim_trailing_zeros() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                assert!(
+                    !text.contains('.') || !text.ends_with('0'),
+                    "Label {:?}",
+                    text
+                );
+            });
+            m.drop_check(|b| assert!(b.num_draw_text_call > 0));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(40)
+            .build_cartesian_2d(0.0..1.0, 0f32..3f32)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .y_label_formatter_precision(2)
+            .trim_trailing_zeros(true)
+            .draw()
+            .expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_draw_series_with_overflow() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, _, center, _| {
+                if c == RED.to_rgba() || c == GREEN.to_rgba() {
+                    assert_eq!(center.0, 200);
+                } else {
+                    assert!(center.0 > 200 && center.0 <= 205);
+                }
+            });
+            m.check_draw_path(|_, _, path| {
+                assert!(path.iter().all(|p| p.0 <= 200));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .expect("Create chart");
+        chart
+            .draw_series([Circle::new((1.02, 0.5), 5, RED.filled())])
+            .expect("Draw series");
+        chart
+            .draw_series_with_overflow([Circle::new((1.02, 0.5), 5, BLUE.filled())], 5)
+            .expect("Draw series");
+        // A huge overflow doesn't wrap around
+        chart
+            .draw_series_with_overflow([Circle::new((1.02, 0.5), 5, BLUE.filled())], u32::MAX)
+            .expect("Draw series");
+
+        // The overflow doesn't stick to the following series, and lines never overflow
+        chart
+            .draw_series([Circle::new((1.02, 0.5), 5, GREEN.filled())])
+            .expect("Draw series");
+        chart
+            .draw_series_with_overflow(LineSeries::new(vec![(0.5, 0.5), (1.02, 0.5)], &BLACK), 5)
+            .expect("Draw series");
+    }
+
+    #[test]
+    fn test_key_points() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..100)
+            .expect("Create chart");
+
+        assert!(chart.x_key_points().is_empty());
+
+        chart
+            .configure_mesh()
+            .x_labels(3)
+            .y_labels(5)
+            .draw()
+            .expect("Drawing error");
+
+        assert_eq!(chart.x_key_points(), vec![0, 5, 10]);
+        assert_eq!(chart.y_key_points(), vec![0, 50, 100]);
+    }
+
+    #[test]
+    fn test_mesh_cache() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let formatter = |v: &i32| {
+            calls.set(calls.get() + 1);
+            format!("{}", v)
+        };
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_label_formatter(&formatter)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        let first = calls.get();
+        assert!(first > 0);
+
+        // The labels of a custom formatter are formatted again, since the formatter can't be
+        // told apart from a different one
+        let state = chart.into_chart_state();
+        let mut chart = state.clone().restore(&drawing_area);
+        chart
+            .configure_mesh()
+            .x_label_formatter(&formatter)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert_eq!(calls.get(), 2 * first);
+        let first = calls.get();
+
+        // Changing the range drops the cache
+        chart.set_x_range(0..20);
+        chart
+            .configure_mesh()
+            .x_label_formatter(&formatter)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(calls.get() > first);
+
+        // So does moving the plotting area
+        let before = calls.get();
+        let moved = drawing_area.margin(10, 0, 10, 0);
+        let mut chart = state.restore(&moved);
+        chart
+            .configure_mesh()
+            .x_label_formatter(&formatter)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(calls.get() > before);
+    }
+
+    #[test]
+    fn test_mesh_cache_label_options() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        let log = texts.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| log.borrow_mut().push(text.to_string()));
+        });
+        let has_label = |label: &str| texts.borrow_mut().drain(..).any(|text| text == label);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(40)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_label_formatter_precision(1)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(has_label("10.0"));
+
+        // Change the range behind the back of the cache, so that reused labels are visible
+        chart.drawing_area.as_coord_spec_mut().set_x_spec(0.0..20.0);
+        chart
+            .configure_mesh()
+            .x_label_formatter_precision(1)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(!has_label("20.0"));
+
+        chart
+            .configure_mesh()
+            .x_label_formatter_precision(2)
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(has_label("20.00"));
+
+        chart
+            .configure_mesh()
+            .x_label_formatter(&|x| format!("{} km", x))
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(has_label("20 km"));
+
+        chart
+            .configure_mesh()
+            .x_label_formatter(&|x| format!("{} m", x))
+            .cache(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(has_label("20 m"));
+    }
+
+    #[test]
+    fn test_max_label_width() {
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                if text.starts_with('C') {
+                    assert!(text.ends_with("..."));
+                    assert!(!text[..text.len() - 3].ends_with(' '));
+                }
+            });
+        });
+        let formatter = |v: &i32| format!("Category number {}", v);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(40)
+            .build_cartesian_2d(0..5, 0..5)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_label_formatter(&formatter)
+            .x_max_label_width(40)
+            .draw()
+            .expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_emphasize_zero_line() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, [(99, 0), (99, 199)]);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, [(0, 100), (199, 100)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .emphasize_zero_line(RED)
+            .draw()
+            .expect("Draw mesh");
+
+        // Zero is out of the range, thus nothing is drawn
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 0);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(1.0..2.0, 1.0..2.0)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .emphasize_zero_line(RED)
+            .draw()
+            .expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_series_label_order() {
+        fn draw_labels(order: SeriesLabelOrder) -> Vec<String> {
+            let log = record_draw_calls(200, 200, |root| {
+                let mut chart = ChartBuilder::on(root)
+                    .build_cartesian_2d(0..10, 0..10)
+                    .expect("Create chart");
+                for (label, key) in [("b", Some(1)), ("c", None), ("a", Some(2))].iter() {
+                    let anno = chart
+                        .draw_series(std::iter::once(Circle::new((5, 5), 5, &RED)))
+                        .expect("Drawing error");
+                    anno.label(*label);
+                    if let Some(key) = key {
+                        anno.sort_key(*key);
+                    }
+                }
+                chart
+                    .configure_series_labels()
+                    .order(order)
+                    .draw()
+                    .expect("Drawing error");
+            });
+            log.iter()
+                .filter(|call| call.starts_with("draw_text"))
+                .map(|call| call.rsplit(' ').next().unwrap().to_string())
+                .collect()
+        }
+
+        assert_eq!(
+            draw_labels(SeriesLabelOrder::DrawOrder),
+            ["\"b\"", "\"c\"", "\"a\""]
+        );
+        assert_eq!(
+            draw_labels(SeriesLabelOrder::Alphabetical),
+            ["\"a\"", "\"b\"", "\"c\""]
+        );
+        assert_eq!(
+            draw_labels(SeriesLabelOrder::SortKey),
+            ["\"b\"", "\"a\"", "\"c\""]
+        );
+    }
+
+    #[test]
+    fn test_series_label_background_opacity() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert!(filled);
+                assert_eq!(c, WHITE.mix(0.5));
+            });
+            // The border
+            m.check_draw_rect(|_, _, filled, _, _| {
+                assert!(!filled);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, &RED)))
+            .expect("Drawing error")
+            .label("Test label");
+        chart
+            .configure_series_labels()
+            .background_style(WHITE)
+            .background_opacity(0.5)
+            .backdrop_blur(3)
+            .draw()
+            .expect("Drawing error");
+    }
+    #[test]
+    fn test_standalone_legend() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert!(text == "Rising" || text == "Falling");
+                assert!(pos.1 >= 260);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert!(c == RED.to_rgba() || c == BLUE.to_rgba());
+                assert_eq!(path.len(), 2);
+                assert!(path[0].1 >= 260);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let (_, lower) = drawing_area.split_vertically(260);
+        Legend::on(&lower)
+            .add_entry("Rising", |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], RED)
+            })
+            .add_entry("Falling", |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], BLUE)
+            })
+            .position(SeriesLabelPosition::MiddleMiddle)
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_series_label_area() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Series");
+                assert!(pos.0 >= 300);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .series_label_area(SeriesLabelPosition::OutsideRight, &["Series"])
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        let (w, h) = chart.plotting_area().dim_in_pixel();
+        let (strip_w, strip_h) = chart
+            .series_label_area
+            .as_ref()
+            .expect("Reserved strip")
+            .dim_in_pixel();
+        assert!(w < 400 && w + strip_w == 400);
+        assert_eq!((h, strip_h), (300, 300));
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, &RED)))
+            .expect("Drawing error")
+            .label("Series");
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::OutsideRight)
+            .draw()
+            .expect("Drawing error");
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .series_label_area(SeriesLabelPosition::OutsideBottom, &["Series"])
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        let (w, h) = chart.plotting_area().dim_in_pixel();
+        assert!(w == 400 && h < 300);
+
+        let no_labels: [&str; 0] = [];
+        let chart = ChartBuilder::on(&drawing_area)
+            .series_label_area(SeriesLabelPosition::OutsideBottom, &no_labels)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        assert!(chart.series_label_area.is_none());
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (400, 300));
+    }
 }
@@ -1,64 +1,272 @@
 use std::ops::Range;
 
-use plotters_backend::DrawingBackend;
+use plotters_backend::{BackendCoord, DrawingBackend};
 
+use crate::chart::mesh::{clip_mesh_line, DeferredGrid, HeldGrid, MeshPass, MeshPassKey};
 use crate::chart::ChartContext;
 use crate::coord::{
     cartesian::{Cartesian2d, MeshLine},
-    ranged1d::{KeyPointHint, Ranged},
+    ranged1d::{BoldPoints, KeyPointHint, LightPoints, Ranged},
     Shift,
 };
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::PathElement;
+use crate::element::{PathElement, Polygon};
 use crate::style::{
     text_anchor::{HPos, Pos, VPos},
     FontTransform, ShapeStyle, TextStyle,
 };
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
-    /// The actual function that draws the mesh lines.
-    /// It also returns the label that suppose to be there.
-    #[allow(clippy::type_complexity)]
-    fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
-        &mut self,
+    /// Find the mesh lines and labels with the given key point hints. When the number of minor
+    /// segments of an axis is given, the lines of that axis are the minor lines which subdivide
+    /// the intervals between the bold lines, rather than the key points of the hint.
+    fn compute_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
         (r, c): (YH, XH),
-        (x_mesh, y_mesh): (bool, bool),
-        mesh_line_style: &ShapeStyle,
+        (x_minor, y_minor): (usize, usize),
         mut fmt_label: FmtLabel,
-    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+    ) -> Result<MeshPass, DrawingAreaErrorKind<DB::ErrorType>>
     where
         FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
     {
-        let mut x_labels = vec![];
-        let mut y_labels = vec![];
+        let mut pass = MeshPass::default();
+        let bold = (r.bold_points(), c.bold_points());
         let xr = self.drawing_area.as_coord_spec().x_spec();
         let yr = self.drawing_area.as_coord_spec().y_spec();
         self.drawing_area.draw_mesh(
-            |b, l| {
-                let draw = match l {
-                    MeshLine::XMesh((x, _), _, _) => {
+            |_, l| {
+                match l {
+                    MeshLine::XMesh(from, to, _) => {
                         if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            x_labels.push((x, label_text));
+                            pass.x_labels.push((from.0, label_text));
                         }
-                        x_mesh
+                        pass.lines.push((true, from, to));
                     }
-                    MeshLine::YMesh((_, y), _, _) => {
+                    MeshLine::YMesh(from, to, _) => {
                         if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            y_labels.push((y, label_text));
+                            pass.y_labels.push((from.1, label_text));
                         }
-                        y_mesh
+                        pass.lines.push((false, from, to));
                     }
-                };
-                if draw {
-                    l.draw(b, mesh_line_style)
-                } else {
-                    Ok(())
                 }
+                Ok(())
             },
             r,
             c,
         )?;
-        Ok((x_labels, y_labels))
+        if x_minor > 0 || y_minor > 0 {
+            let minor = self
+                .drawing_area
+                .as_coord_spec()
+                .minor_mesh_lines(bold, (x_minor, y_minor));
+            let (mut x_lines, mut y_lines): (Vec<_>, Vec<_>) =
+                pass.lines.into_iter().partition(|line| line.0);
+            let (x_minor_lines, y_minor_lines): (Vec<_>, Vec<_>) =
+                minor.into_iter().partition(|line| line.0);
+            if x_minor > 0 {
+                x_lines = x_minor_lines;
+            }
+            if y_minor > 0 {
+                y_lines = y_minor_lines;
+            }
+            x_lines.append(&mut y_lines);
+            pass.lines = x_lines;
+        }
+        Ok(pass)
+    }
+
+    /// The actual function that draws the mesh lines.
+    /// It also returns the label that suppose to be there.
+    /// When the mesh cache is enabled, the lines and labels are taken from the cache if they
+    /// have been computed with the same key point hints before.
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
+    fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+        &mut self,
+        (r, c): (YH, XH),
+        minor: (usize, usize),
+        (x_mesh, y_mesh): (bool, bool),
+        mesh_line_style: &ShapeStyle,
+        extent: Option<(BackendCoord, BackendCoord)>,
+        with_labels: bool,
+        fmt_label: FmtLabel,
+    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+    {
+        let key = MeshPassKey {
+            x_hint: (c.max_num_points(), c.weight().allow_light_points()),
+            y_hint: (r.max_num_points(), r.weight().allow_light_points()),
+            minor,
+            with_labels,
+        };
+        let area = (
+            self.drawing_area.get_base_pixel(),
+            self.drawing_area.dim_in_pixel(),
+        );
+        if let Some(cache) = self.mesh_cache.as_mut() {
+            cache.validate(area);
+        }
+
+        if let Some(pass) = self.mesh_cache.as_ref().and_then(|cache| cache.pass(&key)) {
+            self.draw_mesh_pass(pass, (x_mesh, y_mesh), mesh_line_style, extent)?;
+            return Ok((pass.x_labels.clone(), pass.y_labels.clone()));
+        }
+
+        let pass = self.compute_mesh_lines((r, c), minor, fmt_label)?;
+        self.draw_mesh_pass(&pass, (x_mesh, y_mesh), mesh_line_style, extent)?;
+        match self.mesh_cache.as_mut() {
+            Some(cache) => {
+                let labels = (pass.x_labels.clone(), pass.y_labels.clone());
+                cache.insert_pass(key, pass);
+                Ok(labels)
+            }
+            None => Ok((pass.x_labels, pass.y_labels)),
+        }
+    }
+
+    /// Draw the mesh lines found by a single pass of mesh drawing
+    fn draw_mesh_pass(
+        &self,
+        pass: &MeshPass,
+        (x_mesh, y_mesh): (bool, bool),
+        mesh_line_style: &ShapeStyle,
+        extent: Option<(BackendCoord, BackendCoord)>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if self.drawing_area.is_empty() {
+            return Ok(());
+        }
+        self.drawing_area.backend_ops(|b| {
+            for &(is_x_mesh, from, to) in pass.lines.iter() {
+                let visible = if is_x_mesh { x_mesh } else { y_mesh };
+                if !visible {
+                    continue;
+                }
+                let line = match extent {
+                    Some(extent) => clip_mesh_line((from, to), is_x_mesh, extent),
+                    None => Some((from, to)),
+                };
+                if let Some((from, to)) = line {
+                    b.draw_line(from, to, mesh_line_style)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Draw the light and bold grid lines only, without any axis or label
+    pub(crate) fn draw_grid_lines(
+        &mut self,
+        grid: &DeferredGrid,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mesh = (grid.draw_x_mesh, grid.draw_y_mesh);
+        let extent = if grid.within_data {
+            self.data_extent
+        } else {
+            None
+        };
+        self.draw_mesh_lines(
+            (
+                LightPoints::new(grid.n_y_labels, grid.n_y_labels * grid.y_light_lines_limit),
+                LightPoints::new(grid.n_x_labels, grid.n_x_labels * grid.x_light_lines_limit),
+            ),
+            grid.minor_lines,
+            mesh,
+            &grid.light_style,
+            extent,
+            false,
+            |_, _, _| None,
+        )?;
+        self.draw_mesh_lines(
+            (BoldPoints(grid.n_y_labels), BoldPoints(grid.n_x_labels)),
+            (0, 0),
+            mesh,
+            &grid.bold_style,
+            extent,
+            false,
+            |_, _, _| None,
+        )?;
+        Ok(())
+    }
+
+    /// Find the light and bold grid lines of a deferred grid in the order they are drawn, so that
+    /// they can be held back and drawn later, see [`HeldGrid`]
+    pub(crate) fn hold_grid_lines(
+        &self,
+        grid: &DeferredGrid,
+    ) -> Result<HeldGrid, DrawingAreaErrorKind<DB::ErrorType>> {
+        let light = self.compute_mesh_lines(
+            (
+                LightPoints::new(grid.n_y_labels, grid.n_y_labels * grid.y_light_lines_limit),
+                LightPoints::new(grid.n_x_labels, grid.n_x_labels * grid.x_light_lines_limit),
+            ),
+            grid.minor_lines,
+            |_, _, _| None,
+        )?;
+        let bold = self.compute_mesh_lines(
+            (BoldPoints(grid.n_y_labels), BoldPoints(grid.n_x_labels)),
+            (0, 0),
+            |_, _, _| None,
+        )?;
+        let mut lines = vec![];
+        for (pass, style) in [(light, grid.light_style), (bold, grid.bold_style)] {
+            for (is_x_mesh, from, to) in pass.lines {
+                if (is_x_mesh && grid.draw_x_mesh) || (!is_x_mesh && grid.draw_y_mesh) {
+                    lines.push((is_x_mesh, from, to, style));
+                }
+            }
+        }
+        Ok(HeldGrid {
+            lines,
+            within_data: grid.within_data,
+        })
+    }
+
+    /// Draw the emphasized zero lines across the plotting area, the first one is the vertical
+    /// line at `x = 0` and the second one is the horizontal line at `y = 0`
+    pub(crate) fn draw_zero_lines(
+        &self,
+        lines: &[Option<(i32, ShapeStyle)>; 2],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let y_range = self.drawing_area.get_y_axis_pixel_range();
+
+        if let Some((x, style)) = &lines[0] {
+            let points = vec![(x - x0, y_range.start - y0), (x - x0, y_range.end - y0)];
+            area.draw(&PathElement::new(points, *style))?;
+        }
+        if let Some((y, style)) = &lines[1] {
+            let points = vec![(x_range.start - x0, y - y0), (x_range.end - x0, y - y0)];
+            area.draw(&PathElement::new(points, *style))?;
+        }
+        Ok(())
+    }
+
+    /// Draw an arrowhead whose tip is at `tip`, which points to the right for a horizontal axis
+    /// and points up for a vertical axis
+    fn draw_axis_arrowhead(
+        area: &DrawingArea<DB, Shift>,
+        tip: BackendCoord,
+        horizontal: bool,
+        size: i32,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let half = size / 2;
+        let points = if horizontal {
+            vec![
+                tip,
+                (tip.0 - size, tip.1 - half),
+                (tip.0 - size, tip.1 + half),
+            ]
+        } else {
+            vec![
+                tip,
+                (tip.0 - half, tip.1 + size),
+                (tip.0 + half, tip.1 + size),
+            ]
+        };
+        area.draw(&Polygon::new(points, style.filled()))
     }
 
     fn draw_axis(
@@ -67,6 +275,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         axis_style: Option<&ShapeStyle>,
         orientation: (i16, i16),
         inward_labels: bool,
+        arrow_size: Option<i32>,
     ) -> Result<Range<i32>, DrawingAreaErrorKind<DB::ErrorType>> {
         let (x0, y0) = self.drawing_area.get_base_pixel();
         let (tw, th) = area.dim_in_pixel();
@@ -120,11 +329,144 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             }
 
             area.draw(&PathElement::new(vec![(x0, y0), (x1, y1)], *axis_style))?;
+
+            if let Some(size) = arrow_size {
+                if orientation.0 == 0 {
+                    Self::draw_axis_arrowhead(area, (x0.max(x1), y0), true, size, axis_style)?;
+                } else {
+                    Self::draw_axis_arrowhead(area, (x0, y0.min(y1)), false, size, axis_style)?;
+                }
+            }
         }
 
         Ok(axis_range)
     }
 
+    /// Draw an axis which crosses the plotting area at the origin, rather than sitting on the
+    /// border. The tick mark and label at `cross`, where the other axis crosses, are skipped so
+    /// that they don't overlap the other axis.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_origin_axis(
+        &self,
+        axis_style: &ShapeStyle,
+        labels: &[(i32, String)],
+        label_style: &TextStyle,
+        label_offset: i32,
+        horizontal: bool,
+        pos: i32,
+        cross: Option<i32>,
+        tick_size: i32,
+        arrow_size: Option<i32>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let label_dist = tick_size * 2;
+
+        let (axis_range, base) = if horizontal {
+            (self.drawing_area.get_x_axis_pixel_range(), x0)
+        } else {
+            (self.drawing_area.get_y_axis_pixel_range(), y0)
+        };
+        let (start, end) = (axis_range.start - base, axis_range.end - base);
+
+        let spine = if horizontal {
+            vec![(start, pos - y0), (end, pos - y0)]
+        } else {
+            vec![(pos - x0, start), (pos - x0, end)]
+        };
+        area.draw(&PathElement::new(spine, *axis_style))?;
+
+        if let Some(size) = arrow_size {
+            let tip = if horizontal {
+                (start.max(end), pos - y0)
+            } else {
+                (pos - x0, start.min(end))
+            };
+            Self::draw_axis_arrowhead(&area, tip, horizontal, size, axis_style)?;
+        }
+
+        for (p, t) in labels {
+            let rp = *p - base;
+            if rp < start.min(end) || start.max(end) < rp || Some(*p) == cross {
+                continue;
+            }
+
+            let (tick, text_pos, h_pos, v_pos) = if horizontal {
+                let y = pos - y0;
+                (
+                    [(rp, y), (rp, y + tick_size)],
+                    (rp + label_offset, y + label_dist),
+                    HPos::Center,
+                    VPos::Top,
+                )
+            } else {
+                let x = pos - x0;
+                (
+                    [(x - tick_size, rp), (x, rp)],
+                    (x - label_dist, rp + label_offset),
+                    HPos::Right,
+                    VPos::Center,
+                )
+            };
+
+            area.draw_text(t, &label_style.pos(Pos::new(h_pos, v_pos)), text_pos)?;
+            if tick_size != 0 {
+                area.draw(&PathElement::new(tick.to_vec(), *axis_style))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Measure the width of a label, the width is taken from the mesh cache when it's enabled
+    fn measure_label(&mut self, text: &str, style: &TextStyle) -> i32 {
+        let drawing_area = &self.drawing_area;
+        let measure = || {
+            drawing_area
+                .estimate_text_size(text, style)
+                .map(|(w, _)| w)
+                .unwrap_or(0) as i32
+        };
+        match self.mesh_cache.as_mut() {
+            Some(cache) => cache.text_width(text, style, measure),
+            None => measure(),
+        }
+    }
+
+    /// Measure the width of the labels, which are used for aligning the labels in the right label
+    /// area
+    fn measure_label_widths(&mut self, labels: &[(i32, String)], style: &TextStyle) -> Vec<i32> {
+        labels
+            .iter()
+            .map(|(_, text)| self.measure_label(text, style))
+            .collect()
+    }
+
+    /// Shorten the labels which are wider than `max_width` with an ellipsis. The labels are cut
+    /// on grapheme boundaries, and as many graphemes as possible are kept.
+    fn truncate_labels(&mut self, labels: &mut [(i32, String)], style: &TextStyle, max_width: i32) {
+        for (_, text) in labels.iter_mut() {
+            if self.measure_label(text, style) <= max_width {
+                continue;
+            }
+
+            let boundaries = grapheme_boundaries(text);
+            let shortened = |end: usize| format!("{}{}", text[..end].trim_end(), ELLIPSIS);
+
+            // Find the longest prefix which fits with the ellipsis appended
+            let (mut fit, mut unfit) = (0, boundaries.len());
+            while unfit - fit > 1 {
+                let mid = (fit + unfit) / 2;
+                if self.measure_label(&shortened(boundaries[mid]), style) <= max_width {
+                    fit = mid;
+                } else {
+                    unfit = mid;
+                }
+            }
+            *text = shortened(boundaries[fit]);
+        }
+    }
+
     // TODO: consider make this function less complicated
     #[allow(clippy::too_many_arguments)]
     #[allow(clippy::cognitive_complexity)]
@@ -138,6 +480,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         orientation: (i16, i16),
         axis_desc: Option<(&str, &TextStyle)>,
         tick_size: i32,
+        arrow_size: Option<i32>,
+        label_width: Option<&[i32]>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -153,27 +497,18 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
 
         /* Draw the axis and get the axis range so that we can do further label
          * and tick mark drawing */
-        let axis_range = self.draw_axis(area, axis_style, orientation, tick_size < 0)?;
+        let axis_range =
+            self.draw_axis(area, axis_style, orientation, tick_size < 0, arrow_size)?;
 
         /* To make the right label area looks nice, it's a little bit tricky, since for a that is
          * very long, we actually prefer left alignment instead of right alignment.
          * Otherwise, the right alignment looks better. So we estimate the max and min label width
-         * So that we are able decide if we should apply right alignment for the text. */
-        let label_width: Vec<_> = labels
-            .iter()
-            .map(|(_, text)| {
-                if orientation.0 > 0 && orientation.1 == 0 && tick_size >= 0 {
-                    self.drawing_area
-                        .estimate_text_size(text, label_style)
-                        .map(|(w, _)| w)
-                        .unwrap_or(0) as i32
-                } else {
-                    // Don't ever do the layout estimationfor the drawing area that is either not
-                    // the right one or the tick mark is inward.
-                    0
-                }
-            })
-            .collect();
+         * So that we are able decide if we should apply right alignment for the text.
+         * The widths are only measured for the right label area, see `measure_label_widths`. */
+        let label_width = match label_width {
+            Some(width) => width.to_vec(),
+            None => vec![0; labels.len()],
+        };
 
         let min_width = *label_width.iter().min().unwrap_or(&1).max(&1);
         let max_width = *label_width
@@ -241,6 +576,12 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 }
             };
 
+            let h_pos = if orientation.0 == 0 {
+                slanted_label_h_pos(label_style, v_pos).unwrap_or(h_pos)
+            } else {
+                h_pos
+            };
+
             let (text_x, text_y) = if orientation.0 == 0 {
                 (cx + label_offset, cy)
             } else {
@@ -317,6 +658,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
     pub(crate) fn draw_mesh<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
         &mut self,
         (r, c): (YH, XH),
+        minor_lines: (usize, usize),
         mesh_line_style: &ShapeStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
@@ -333,37 +675,213 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         y_desc: Option<String>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        (origin_x, origin_y): (Option<i32>, Option<i32>),
+        axis_arrow_size: Option<i32>,
+        grid_extent: Option<(BackendCoord, BackendCoord)>,
+        (x_max_label_width, y_max_label_width): (Option<i32>, Option<i32>),
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        let (mut x_labels, mut y_labels) = self.draw_mesh_lines(
+            (r, c),
+            minor_lines,
+            (x_mesh, y_mesh),
+            mesh_line_style,
+            grid_extent,
+            true,
+            fmt_label,
+        )?;
+
+        if let Some(width) = x_max_label_width {
+            self.truncate_labels(&mut x_labels, x_label_style, width);
+        }
+        if let Some(width) = y_max_label_width {
+            self.truncate_labels(&mut y_labels, y_label_style, width);
+        }
+
+        // The X axis crosses the plotting area at y = 0 and the Y axis at x = 0
+        let x_at_origin = x_axis && origin_y.is_some();
+        let y_at_origin = y_axis && origin_x.is_some();
+
+        if let (true, Some(y)) = (x_at_origin, origin_y) {
+            self.draw_origin_axis(
+                axis_style,
+                &x_labels[..],
+                x_label_style,
+                x_label_offset,
+                true,
+                y,
+                origin_x.filter(|_| y_at_origin),
+                x_tick_size[1].abs(),
+                axis_arrow_size,
+            )?;
+        }
+
+        if let (true, Some(x)) = (y_at_origin, origin_x) {
+            self.draw_origin_axis(
+                axis_style,
+                &y_labels[..],
+                y_label_style,
+                y_label_offset,
+                false,
+                x,
+                origin_y.filter(|_| x_at_origin),
+                y_tick_size[0].abs(),
+                axis_arrow_size,
+            )?;
+        }
+
+        let x_labels = if x_at_origin { &[][..] } else { &x_labels[..] };
+        let y_labels = if y_at_origin { &[][..] } else { &y_labels[..] };
+
+        // Don't ever do the layout estimation for the drawing area that is either not the right
+        // one or the tick mark is inward.
+        let y_label_width = if self.y_label_area[1].is_some() && y_tick_size[1] >= 0 {
+            Some(self.measure_label_widths(y_labels, y_label_style))
+        } else {
+            None
+        };
 
         for idx in 0..2 {
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
-                if x_axis { Some(axis_style) } else { None },
-                &x_labels[..],
+                if x_axis && !x_at_origin {
+                    Some(axis_style)
+                } else {
+                    None
+                },
+                x_labels,
                 x_label_style,
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 x_tick_size[idx],
+                axis_arrow_size,
+                None,
             )?;
 
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
-                if y_axis { Some(axis_style) } else { None },
-                &y_labels[..],
+                if y_axis && !y_at_origin {
+                    Some(axis_style)
+                } else {
+                    None
+                },
+                y_labels,
                 y_label_style,
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 y_tick_size[idx],
+                axis_arrow_size,
+                y_label_width.as_deref().filter(|_| idx == 1),
             )?;
         }
 
         Ok(())
     }
 }
+
+/// The text appended to the labels which are shortened
+const ELLIPSIS: &str = "...";
+
+/// Find the horizontal anchor of a tick label on the X axis which is slanted by
+/// [`FontTransform::RotateAngle`]. The label is anchored at the corner which is the closest to the
+/// axis, so that the label hangs from its tick mark instead of crossing the neighbouring labels.
+/// For example, a label below the axis rotated counterclockwise has its top right corner under
+/// the tick mark.
+///
+/// - `style`: The style of the label
+/// - `v_pos`: The vertical anchor of the label, `VPos::Top` for the labels below the axis
+/// - Returns: The horizontal anchor, or `None` if the label isn't slanted
+fn slanted_label_h_pos(style: &TextStyle, v_pos: VPos) -> Option<HPos> {
+    let transform = style.font.get_transform();
+    if !matches!(transform, FontTransform::RotateAngle(_)) {
+        return None;
+    }
+    let angle = transform.degrees().rem_euclid(360.0);
+    if angle == 0.0 || angle == 180.0 {
+        return None;
+    }
+    let clockwise = angle < 180.0;
+    Some(match (clockwise, v_pos) {
+        (false, VPos::Top) | (true, VPos::Bottom) => HPos::Right,
+        (true, VPos::Top) | (false, VPos::Bottom) => HPos::Left,
+        (_, VPos::Center) => HPos::Center,
+    })
+}
+
+/// Check if the character is a part of the grapheme in front of it, that is, a combining mark,
+/// a variation selector, a zero width joiner or an emoji modifier
+fn is_grapheme_extend(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036F
+            | 0x0483..=0x0489
+            | 0x0591..=0x05BD
+            | 0x0610..=0x061A
+            | 0x064B..=0x065F
+            | 0x1AB0..=0x1AFF
+            | 0x1DC0..=0x1DFF
+            | 0x200C..=0x200D
+            | 0x20D0..=0x20FF
+            | 0x3099..=0x309A
+            | 0xFE00..=0xFE0F
+            | 0xFE20..=0xFE2F
+            | 0x1F3FB..=0x1F3FF
+            | 0xE0100..=0xE01EF
+    )
+}
+
+/// The byte offsets where the graphemes of the text start, the text is never cut in the middle of
+/// a grapheme when it's cut at one of those offsets
+fn grapheme_boundaries(text: &str) -> Vec<usize> {
+    let mut boundaries = vec![0];
+    let mut joined = false;
+    for (idx, c) in text.char_indices() {
+        if idx > 0 && !joined && !is_grapheme_extend(c) {
+            boundaries.push(idx);
+        }
+        // The character after a zero width joiner is joined into the same grapheme
+        joined = c == '\u{200D}';
+    }
+    boundaries
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_boundaries() {
+        assert_eq!(grapheme_boundaries("abc"), [0, 1, 2]);
+        // The combining acute accent belongs to the "e" in front of it
+        assert_eq!(grapheme_boundaries("e\u{301}x"), [0, 3]);
+        // The emoji joined by the zero width joiner are a single grapheme
+        assert_eq!(grapheme_boundaries("\u{1F468}\u{200D}\u{1F469}a"), [0, 11]);
+    }
+
+    #[test]
+    fn test_truncate_labels() {
+        use crate::prelude::*;
+
+        let drawing_area = create_mocked_drawing_area(300, 300, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..5, 0..5)
+            .expect("Create chart");
+
+        let style = ("sans-serif", 12).into_text_style(&drawing_area);
+        let mut labels = vec![(0, "Category number 1".to_string()), (0, "Cat".to_string())];
+        chart.truncate_labels(&mut labels, &style, 40);
+        assert!(labels[0].1.starts_with("Cat") && labels[0].1.ends_with("..."));
+        assert!(
+            drawing_area
+                .estimate_text_size(&labels[0].1, &style)
+                .unwrap()
+                .0
+                <= 40
+        );
+        assert_eq!(labels[1].1, "Cat");
+    }
+}
@@ -1,14 +1,17 @@
+use std::borrow::Borrow;
 use std::ops::Range;
 
+use num_traits::Zero;
 use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle};
+use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle, SeriesAnno};
 use crate::coord::{
     cartesian::Cartesian2d,
-    ranged1d::{AsRangedCoord, Ranged, ValueFormatter},
+    ranged1d::{AsRangedCoord, BoldPoints, Ranged, ValueFormatter},
     Shift,
 };
-use crate::drawing::DrawingArea;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{CoordMapper, Drawable, PointCollection};
 
 mod draw_impl;
 
@@ -44,6 +47,69 @@ where
     pub fn configure_mesh(&mut self) -> MeshStyle<'a, '_, X, Y, DB> {
         MeshStyle::new(self)
     }
+
+    /// Draw the grid lines which are deferred by [`MeshStyle::grid_on_top`]. This should be called
+    /// after all the data series are drawn, so that the grid lines are on top of them.
+    /// If there's no deferred grid, this function does nothing.
+    pub fn draw_deferred_grid(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if self.held_grid.is_some() {
+            return self.draw_held_grid();
+        }
+        if let Some(grid) = self.deferred_grid.take() {
+            self.draw_grid_lines(&grid)?;
+        }
+        Ok(())
+    }
+
+    /**
+    Draws a data series behind the grid lines, such as a shaded region, while the other series
+    are drawn in front of them.
+
+    This works with the grid deferred by [`MeshStyle::grid_on_top`]. The grid lines are held
+    back under the series drawn by this function, and they are drawn right before the next
+    series drawn by [`ChartContext::draw_series`], or by [`ChartContext::draw_deferred_grid`].
+    Thus the z-order is: the axes, the series behind the grid, the grid lines and then the other
+    series. If the grid isn't deferred, this is the same as [`ChartContext::draw_series`].
+
+    ```rust
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("draw_series_behind_grid.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_all_label_area_size(20)
+        .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+        .unwrap();
+    chart.configure_mesh().grid_on_top(true).draw().unwrap();
+    // The shaded region is under the grid lines
+    chart
+        .draw_series_behind_grid(std::iter::once(Rectangle::new(
+            [(2.0, 0.0), (4.0, 10.0)],
+            BLUE.mix(0.2).filled(),
+        )))
+        .unwrap();
+    // The line is on top of the grid lines
+    chart
+        .draw_series(LineSeries::new((0..=10).map(|x| (x as f64, x as f64)), RED))
+        .unwrap();
+    ```
+    */
+    pub fn draw_series_behind_grid<B, E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, (XT, YT), B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        if let Some(grid) = self.deferred_grid.take() {
+            self.held_grid = Some(self.hold_grid_lines(&grid)?);
+        }
+        self.draw_series_impl(series, 0)?;
+        Ok(self.alloc_series_anno())
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
@@ -57,11 +123,128 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         self.drawing_area.get_y_range()
     }
 
+    /**
+    Change the range of X axis in place, without rebuilding the chart.
+
+    The label areas and the plotting area are kept as they are, and the new range is used by
+    everything drawn afterwards, including the mesh and the reverse coordinate translation.
+    This is typically used for zooming and panning an interactive chart.
+
+    ```rust
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("set_x_range.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_all_label_area_size(20)
+        .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+        .unwrap();
+    // Zoom into the left half of the chart
+    chart.set_x_range(0.0..5.0);
+    chart.configure_mesh().draw().unwrap();
+    assert_eq!(chart.x_range(), 0.0..5.0);
+    ```
+    */
+    pub fn set_x_range<R: Into<X>>(&mut self, range: R) {
+        self.drawing_area.as_coord_spec_mut().set_x_spec(range);
+        if let Some(cache) = self.mesh_cache.as_mut() {
+            cache.clear();
+        }
+    }
+
+    /// Change the range of Y axis in place, without rebuilding the chart.
+    /// See [`ChartContext::set_x_range`] for details.
+    pub fn set_y_range<R: Into<Y>>(&mut self, range: R) {
+        self.drawing_area.as_coord_spec_mut().set_y_spec(range);
+        if let Some(cache) = self.mesh_cache.as_mut() {
+            cache.clear();
+        }
+    }
+
+    /**
+    Get the key points of X axis used by the last drawn mesh, which are the values of the bold
+    grid lines and the tick marks. This is useful for placing custom elements exactly at the ticks.
+
+    The key points are computed with the label count of the last [`MeshStyle::draw`] call
+    against the current range of X axis. If the mesh hasn't been drawn, the result is empty.
+
+    ```rust
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_key_points.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0..10, 0..10)
+        .unwrap();
+    chart.configure_mesh().x_labels(3).draw().unwrap();
+    for x in chart.x_key_points() {
+        chart.draw_series(std::iter::once(Circle::new((x, 5), 3, RED))).unwrap();
+    }
+    ```
+    */
+    pub fn x_key_points(&self) -> Vec<X::ValueType> {
+        match self.mesh_labels {
+            Some((n, _)) => self
+                .drawing_area
+                .as_coord_spec()
+                .x_spec()
+                .key_points(BoldPoints(n)),
+            None => vec![],
+        }
+    }
+
+    /// Get the key points of Y axis used by the last drawn mesh.
+    /// See [`ChartContext::x_key_points`] for details.
+    pub fn y_key_points(&self) -> Vec<Y::ValueType> {
+        match self.mesh_labels {
+            Some((_, n)) => self
+                .drawing_area
+                .as_coord_spec()
+                .y_spec()
+                .key_points(BoldPoints(n)),
+            None => vec![],
+        }
+    }
+
     /// Maps the coordinate to the backend coordinate. This is typically used
     /// with an interactive chart.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
+
+    /// Get the backend coordinate of the origin, each component is `None` if zero is out of the
+    /// range of the corresponding axis
+    pub(crate) fn origin_in_pixels(&self) -> (Option<i32>, Option<i32>)
+    where
+        X::ValueType: Zero,
+        Y::ValueType: Zero,
+    {
+        (self.x_zero_in_pixels(), self.y_zero_in_pixels())
+    }
+
+    /// Get the backend X coordinate of `x = 0`, or `None` if zero is out of the range of X axis
+    pub(crate) fn x_zero_in_pixels(&self) -> Option<i32>
+    where
+        X::ValueType: Zero,
+    {
+        let x = self
+            .drawing_area
+            .as_coord_spec()
+            .map_x(&X::ValueType::zero());
+        Some(x).filter(|&x| pixel_range_contains(self.drawing_area.get_x_axis_pixel_range(), x))
+    }
+
+    /// Get the backend Y coordinate of `y = 0`, or `None` if zero is out of the range of Y axis
+    pub(crate) fn y_zero_in_pixels(&self) -> Option<i32>
+    where
+        Y::ValueType: Zero,
+    {
+        let y = self
+            .drawing_area
+            .as_coord_spec()
+            .map_y(&Y::ValueType::zero());
+        Some(y).filter(|&y| pixel_range_contains(self.drawing_area.get_y_axis_pixel_range(), y))
+    }
+}
+
+fn pixel_range_contains(range: Range<i32>, value: i32) -> bool {
+    range.start.min(range.end) <= value && value <= range.start.max(range.end)
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
@@ -85,6 +268,9 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let mut pixel_range = self.drawing_area.get_pixel_range();
         pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
 
-        DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
+        let mut coord = Cartesian2d::new(x_coord, y_coord, pixel_range);
+        coord.set_pixel_rounding(self.drawing_area.as_coord_spec().pixel_rounding());
+
+        DualCoordChartContext::new(self, coord)
     }
 }
@@ -113,6 +113,7 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
 
         std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
         std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        let default_stroke_width = primary.default_stroke_width;
 
         Self {
             primary,
@@ -122,6 +123,13 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
                 drawing_area_pos: (0, 0),
+                default_stroke_width,
+                deferred_grid: None,
+                held_grid: None,
+                data_extent: None,
+                mesh_cache: None,
+                mesh_labels: None,
+                series_label_area: None,
             },
         }
     }
@@ -166,6 +174,41 @@ impl<DB: DrawingBackend, CT1: ReverseCoordTranslate, CT2: ReverseCoordTranslate>
             move |coord| coord_spec_2.reverse_translate(coord),
         )
     }
+
+    /// Convert the chart context into a closure that maps the pixel coordinate into the logical
+    /// coordinate of both the primary and the secondary coordinate system at once.
+    /// The pixels outside of the plotting area are mapped to `(None, None)`.
+    pub fn into_dual_coord_trans(
+        self,
+    ) -> impl Fn(BackendCoord) -> (Option<CT1::From>, Option<CT2::From>) {
+        let (x_range, y_range) = self.primary.drawing_area.get_pixel_range();
+        let (primary, secondary) = self.into_coord_trans_pair();
+        move |coord| {
+            if !x_range.contains(&coord.0) || !y_range.contains(&coord.1) {
+                return (None, None);
+            }
+            (primary(coord), secondary(coord))
+        }
+    }
+
+    /**
+    Find the logical coordinates of a pixel in both coordinate systems, for example to show a
+    tooltip for the pointer position on a dual axis chart.
+
+    - `coord`: The pixel coordinate, relative to the backend
+    - **returns**: The logical coordinate in the primary and the secondary coordinate system,
+      or `(None, None)` if the pixel is outside of the plotting area
+    */
+    pub fn hit_test(&self, coord: BackendCoord) -> (Option<CT1::From>, Option<CT2::From>) {
+        let (x_range, y_range) = self.primary.drawing_area.get_pixel_range();
+        if !x_range.contains(&coord.0) || !y_range.contains(&coord.1) {
+            return (None, None);
+        }
+        (
+            self.primary.as_coord_spec().reverse_translate(coord),
+            self.secondary.as_coord_spec().reverse_translate(coord),
+        )
+    }
 }
 
 impl<
@@ -203,7 +246,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, SX: Ranged, SY: Ranged>
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.secondary.draw_series_impl(series)?;
+        self.secondary.draw_series_impl(series, 0)?;
         Ok(self.primary.alloc_series_anno())
     }
 }
@@ -240,3 +283,32 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate> DerefMut
         self.borrow_mut()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dual_coord_hit_test() {
+        let drawing_area = create_mocked_drawing_area(120, 120, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .build_cartesian_2d(0..100, 0..100)
+            .expect("Create chart")
+            .set_secondary_coord(0.0..1.0, 100.0..200.0);
+
+        let (x, y) = chart.backend_coord(&(50, 50));
+        let (primary, secondary) = chart.hit_test((x, y));
+        let (px, py): (i32, i32) = primary.expect("Inside the plotting area");
+        let (sx, sy): (f64, f64) = secondary.expect("Inside the plotting area");
+        assert!((px - 50).abs() <= 1 && (py - 50).abs() <= 1);
+        assert!((sx - 0.5).abs() < 0.02 && (sy - 150.0).abs() < 2.0);
+
+        assert_eq!(chart.hit_test((5, 5)), (None, None));
+        assert_eq!(chart.hit_test((115, 60)), (None, None));
+
+        let trans = chart.into_dual_coord_trans();
+        assert!(trans((x, y)).0.is_some() && trans((x, y)).1.is_some());
+        assert_eq!(trans((60, 115)), (None, None));
+    }
+}
@@ -1,16 +1,19 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use super::builder::LabelAreaPosition;
 use super::context::ChartContext;
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
-use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::coord::ranged1d::{AsRangedCoord, BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::data::float::trim_trailing_zeros;
 use crate::drawing::DrawingAreaErrorKind;
 use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+    AsRelative, Color, FontDesc, FontFamily, FontResult, FontStyle, IntoFont, IntoTextStyle,
+    RGBAColor, RGBColor, ShapeStyle, SizeDesc, TextStyle,
 };
 
-use plotters_backend::DrawingBackend;
+use num_traits::Zero;
+use plotters_backend::{BackendCoord, DrawingBackend};
 
 /// The style used to describe the mesh and axis for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
@@ -37,6 +40,45 @@ where
         self
     }
 
+    /// Draw the grid lines of the secondary coordinate system, which are not drawn by default
+    pub fn enable_mesh(&mut self) -> &mut Self {
+        self.style.draw_x_mesh = true;
+        self.style.draw_y_mesh = true;
+        self
+    }
+
+    /// Set the style of both the bold and the light grid lines of the secondary coordinate
+    /// system, see [`MeshStyle::grid_style`]
+    /// - `style`: The style of the grid lines
+    pub fn grid_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.grid_style(style);
+        self
+    }
+
+    /// Set the style of the bold grid lines of the secondary coordinate system, see
+    /// [`SecondaryMeshStyle::enable_mesh`]
+    /// - `style`: The style of the bold grid lines
+    pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.bold_line_style(style);
+        self
+    }
+
+    /// Set the style of the light grid lines of the secondary coordinate system, see
+    /// [`SecondaryMeshStyle::enable_mesh`]
+    /// - `style`: The style of the light grid lines
+    pub fn light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.light_line_style(style);
+        self
+    }
+
+    /// Don't draw the tick marks and the labels of the secondary axes. This is useful to show
+    /// the grid lines of the secondary coordinate system as a reference without cluttering the
+    /// chart with another set of labels, see [`SecondaryMeshStyle::enable_mesh`]
+    pub fn disable_secondary_labels(&mut self) -> &mut Self {
+        self.style.draw_labels = false;
+        self
+    }
+
     /// The offset of x labels. This is used when we want to place the label in the middle of
     /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
     /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
@@ -55,6 +97,20 @@ where
         self
     }
 
+    /// Draw the minor grid lines of the secondary X axis, see [`MeshStyle::x_minor_lines`]
+    /// - `value`: The number of segments between two consecutive X labels
+    pub fn x_minor_lines(&mut self, value: usize) -> &mut Self {
+        self.style.x_minor_lines(value);
+        self
+    }
+
+    /// Draw the minor grid lines of the secondary Y axis, see [`MeshStyle::y_minor_lines`]
+    /// - `value`: The number of segments between two consecutive Y labels
+    pub fn y_minor_lines(&mut self, value: usize) -> &mut Self {
+        self.style.y_minor_lines(value);
+        self
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -83,6 +139,34 @@ where
         self
     }
 
+    /// Format the X labels with a fixed number of decimal places
+    /// - `decimals`: The number of decimal places
+    pub fn x_label_formatter_precision(&mut self, decimals: usize) -> &mut Self
+    where
+        X::ValueType: Into<f64> + Clone,
+    {
+        self.style.x_label_formatter_precision(decimals);
+        self
+    }
+
+    /// Format the Y labels with a fixed number of decimal places
+    /// - `decimals`: The number of decimal places
+    pub fn y_label_formatter_precision(&mut self, decimals: usize) -> &mut Self
+    where
+        Y::ValueType: Into<f64> + Clone,
+    {
+        self.style.y_label_formatter_precision(decimals);
+        self
+    }
+
+    /// Remove the trailing zeros and the trailing decimal point of numeric labels, see
+    /// [`MeshStyle::trim_trailing_zeros`]
+    /// - `value`: If the trailing zeros should be trimmed
+    pub fn trim_trailing_zeros(&mut self, value: bool) -> &mut Self {
+        self.style.trim_trailing_zeros(value);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -141,6 +225,94 @@ where
     }
 }
 
+/**
+Measures the tick labels of an axis without drawing anything, which is useful to set the size of
+the label areas exactly before the chart is built.
+
+- `range`: The range of the axis, as it would be passed to [`ChartBuilder::build_cartesian_2d()`](super::ChartBuilder::build_cartesian_2d)
+- `n_labels`: The maximum number of labels, see [`MeshStyle::x_labels()`], 11 by default
+- `font`: The font of the labels
+- Returns: The maximum width and the maximum height of the labels in pixels
+
+The labels are found and formatted in the same way as the mesh does by default. The label areas
+also need room for the tick marks, the labels are drawn twice the tick mark size away from the
+axis.
+
+```
+use plotters::chart::measure_tick_labels;
+let (w, _) = measure_tick_labels(0.0..1000.0, 11, ("sans-serif", 12)).unwrap();
+assert!(w > 0);
+```
+*/
+pub fn measure_tick_labels<'f, R, F>(range: R, n_labels: usize, font: F) -> FontResult<(u32, u32)>
+where
+    R: AsRangedCoord,
+    R::CoordDescType: ValueFormatter<R::Value>,
+    F: IntoFont<'f>,
+{
+    let coord: R::CoordDescType = range.into();
+    measure_tick_labels_with(coord, n_labels, font, |coord, value| {
+        coord.format_ext(value)
+    })
+}
+
+/**
+Measures the tick labels of an axis with a customized label formatter, see
+[`measure_tick_labels()`] and [`MeshStyle::x_label_formatter()`].
+
+- `range`: The range of the axis
+- `n_labels`: The maximum number of labels
+- `font`: The font of the labels
+- `fmt`: The formatter function, which takes the coordinate and the value of the label
+- Returns: The maximum width and the maximum height of the labels in pixels
+*/
+pub fn measure_tick_labels_with<'f, R, F, Fmt>(
+    range: R,
+    n_labels: usize,
+    font: F,
+    fmt: Fmt,
+) -> FontResult<(u32, u32)>
+where
+    R: AsRangedCoord,
+    F: IntoFont<'f>,
+    Fmt: Fn(&R::CoordDescType, &R::Value) -> String,
+{
+    let mut size = (0, 0);
+    if n_labels == 0 {
+        return Ok(size);
+    }
+    let coord: R::CoordDescType = range.into();
+    let font = font.into_font();
+    for value in coord.key_points(BoldPoints(n_labels)) {
+        let (w, h) = font.box_size(&fmt(&coord, &value))?;
+        size = (size.0.max(w), size.1.max(h));
+    }
+    Ok(size)
+}
+
+type LabelFormatter<'b, V> = Box<dyn Fn(&V) -> String + 'b>;
+
+/// How the labels of an axis are formatted. A custom formatter function can't be compared with
+/// the one used by a previous draw, thus the labels formatted by it are never reused from the
+/// mesh cache.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum LabelFormat {
+    Default,
+    Precision(usize),
+    Custom,
+}
+
+/// All the mesh options that change the text of the tick labels, the labels in the mesh cache
+/// are only reused by a draw with the same options, see [`MeshCache::validate_labels`]
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct MeshLabelKey {
+    pub(crate) format: (LabelFormat, LabelFormat),
+    pub(crate) trim_trailing_zeros: bool,
+    pub(crate) draw_labels: bool,
+    pub(crate) draw_axes: (bool, bool),
+    pub(crate) max_label_width: (Option<i32>, Option<i32>),
+}
+
 /// The struct that is used for tracking the configuration of a mesh of any chart
 pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) parent_size: (u32, u32),
@@ -152,6 +324,7 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) y_label_offset: i32,
     pub(super) x_light_lines_limit: usize,
     pub(super) y_light_lines_limit: usize,
+    pub(super) minor_lines: (usize, usize),
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
@@ -162,12 +335,140 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
-    pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
-    pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) format_x: Option<LabelFormatter<'b, X::ValueType>>,
+    pub(super) format_y: Option<LabelFormatter<'b, Y::ValueType>>,
+    pub(super) label_format: (LabelFormat, LabelFormat),
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) grid_on_top: bool,
+    pub(super) trim_trailing_zeros: bool,
+    pub(super) origin: (Option<i32>, Option<i32>),
+    pub(super) axis_arrows: bool,
+    pub(super) axis_arrow_size: i32,
+    pub(super) plot_background: Option<RGBAColor>,
+    pub(super) grid_within_data: bool,
+    pub(super) cache: bool,
+    pub(super) max_label_width: (Option<i32>, Option<i32>),
+    pub(super) zero_lines: [Option<(i32, ShapeStyle)>; 2],
+    pub(super) draw_labels: bool,
+}
+
+/// The grid lines of a mesh which are drawn after the data series, see [`MeshStyle::grid_on_top`]
+#[derive(Clone, Copy)]
+pub(crate) struct DeferredGrid {
+    pub(crate) light_style: ShapeStyle,
+    pub(crate) bold_style: ShapeStyle,
+    pub(crate) n_x_labels: usize,
+    pub(crate) n_y_labels: usize,
+    pub(crate) x_light_lines_limit: usize,
+    pub(crate) y_light_lines_limit: usize,
+    pub(crate) minor_lines: (usize, usize),
+    pub(crate) draw_x_mesh: bool,
+    pub(crate) draw_y_mesh: bool,
+    pub(crate) within_data: bool,
+}
+
+/// The grid lines of a deferred grid which are held back under the series drawn by
+/// [`ChartContext::draw_series_behind_grid`], until the next series is drawn in front of them.
+/// Each line is tagged with whether it is an X mesh line, and the lines are clipped to the data
+/// extent only when they are finally drawn.
+#[derive(Clone)]
+pub(crate) struct HeldGrid {
+    pub(crate) lines: Vec<(bool, BackendCoord, BackendCoord, ShapeStyle)>,
+    pub(crate) within_data: bool,
+}
+
+/// The key points of the mesh lines computed by a single pass of mesh drawing
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct MeshPassKey {
+    pub(crate) x_hint: (usize, bool),
+    pub(crate) y_hint: (usize, bool),
+    pub(crate) minor: (usize, usize),
+    pub(crate) with_labels: bool,
+}
+
+/// The mesh lines and labels found by a single pass of mesh drawing. The lines are kept in the
+/// order they are drawn, and each line is tagged with whether it is an X mesh line.
+#[derive(Clone, Default)]
+pub(crate) struct MeshPass {
+    pub(crate) lines: Vec<(bool, BackendCoord, BackendCoord)>,
+    pub(crate) x_labels: Vec<(i32, String)>,
+    pub(crate) y_labels: Vec<(i32, String)>,
+}
+
+/// The mesh lines, labels and label sizes computed by the previous draw of a chart, which are
+/// reused by the following draws, see [`MeshStyle::cache`]
+#[derive(Clone, Default)]
+pub(crate) struct MeshCache {
+    area: Option<(BackendCoord, (u32, u32))>,
+    passes: Vec<(MeshPassKey, MeshPass)>,
+    labels: Option<MeshLabelKey>,
+    font: Option<(String, f64, String)>,
+    text_widths: HashMap<String, i32>,
+}
+
+impl MeshCache {
+    /// Drop everything but the label options in the cache if the plotting area has been moved or
+    /// resized since the cache was filled
+    pub(crate) fn validate(&mut self, area: (BackendCoord, (u32, u32))) {
+        if self.area != Some(area) {
+            *self = MeshCache {
+                area: Some(area),
+                labels: self.labels,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Drop everything in the cache, this is used when the range of the chart changes
+    pub(crate) fn clear(&mut self) {
+        *self = MeshCache::default();
+    }
+
+    /// Drop the cached passes with labels if the labels have been formatted with different
+    /// options, or with a custom formatter which can't be compared, since the last draw
+    pub(crate) fn validate_labels(&mut self, key: MeshLabelKey) {
+        let custom = key.format.0 == LabelFormat::Custom || key.format.1 == LabelFormat::Custom;
+        if custom || self.labels != Some(key) {
+            self.passes.retain(|(k, _)| !k.with_labels);
+            self.labels = Some(key);
+        }
+    }
+
+    pub(crate) fn pass(&self, key: &MeshPassKey) -> Option<&MeshPass> {
+        self.passes.iter().find(|(k, _)| k == key).map(|(_, p)| p)
+    }
+
+    pub(crate) fn insert_pass(&mut self, key: MeshPassKey, pass: MeshPass) {
+        self.passes.push((key, pass));
+    }
+
+    /// Get the measured width of a label, or measure it with `measure` if the label hasn't been
+    /// measured with the same font before
+    pub(crate) fn text_width<F: FnOnce() -> i32>(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        measure: F,
+    ) -> i32 {
+        let font = (
+            style.font.get_name().to_string(),
+            style.font.get_size(),
+            style.font.get_style().as_str().to_string(),
+        );
+        if self.font.as_ref() != Some(&font) {
+            self.font = Some(font);
+            self.text_widths.clear();
+        }
+        if let Some(width) = self.text_widths.get(text) {
+            return *width;
+        }
+        let width = measure();
+        self.text_widths.insert(text.to_string(), width);
+        width
+    }
 }
 
 impl<'a, 'b, X, Y, XT, YT, DB> MeshStyle<'a, 'b, X, Y, DB>
@@ -178,6 +479,7 @@ where
 {
     pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>) -> Self {
         let base_tick_size = (5u32).percent().max(5).in_pixels(chart.plotting_area());
+        let axis_arrow_size = (2u32).percent().max(8).in_pixels(chart.plotting_area());
 
         let mut x_tick_size = [base_tick_size, base_tick_size];
         let mut y_tick_size = [base_tick_size, base_tick_size];
@@ -202,6 +504,7 @@ where
             draw_y_axis: true,
             x_light_lines_limit: 10,
             y_light_lines_limit: 10,
+            minor_lines: (0, 0),
             n_x_labels: 11,
             n_y_labels: 11,
             bold_line_style: None,
@@ -210,6 +513,7 @@ where
             y_label_style: None,
             format_x: None,
             format_y: None,
+            label_format: (LabelFormat::Default, LabelFormat::Default),
             target: Some(chart),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -217,6 +521,17 @@ where
             axis_desc_style: None,
             x_tick_size,
             y_tick_size,
+            grid_on_top: false,
+            trim_trailing_zeros: false,
+            origin: (None, None),
+            axis_arrows: false,
+            axis_arrow_size,
+            plot_background: None,
+            grid_within_data: false,
+            cache: false,
+            max_label_width: (None, None),
+            zero_lines: [None, None],
+            draw_labels: true,
         }
     }
 }
@@ -297,6 +612,149 @@ where
         self
     }
 
+    /**
+    Draw the grid lines on top of the data series instead of behind them.
+
+    When this is enabled, [`MeshStyle::draw`] only draws the axes, tick marks and labels, and
+    the grid lines are drawn later by [`ChartContext::draw_deferred_grid`], which should be called
+    after all the data series are drawn. This is useful for drawing a faint reference grid over
+    a heat map, for example.
+
+    - `value`: If the grid lines should be drawn on top of the data series
+    */
+    pub fn grid_on_top(&mut self, value: bool) -> &mut Self {
+        self.grid_on_top = value;
+        self
+    }
+
+    /**
+    Draw the grid lines only within the bounding box of the data series, rather than across the
+    whole plotting area, which is sometimes used for "minimal ink" plots. The tick marks and
+    labels are not affected.
+
+    The bounding box is the one of the series drawn so far, see [`ChartContext::data_bounding_box`].
+    Thus the data series should be drawn before the mesh, or the grid should be drawn on top of
+    the series with [`MeshStyle::grid_on_top`]. If no series has been drawn, the grid lines span
+    the whole plotting area. This is off by default.
+
+    - `value`: If the grid lines should be clipped to the bounding box of the data series
+    */
+    pub fn grid_within_data(&mut self, value: bool) -> &mut Self {
+        self.grid_within_data = value;
+        self
+    }
+
+    /**
+    Cache the mesh lines, the tick labels and the measured label sizes in the chart, so that the
+    following draws of the mesh skip the key point computation and the text measurement.
+
+    This is useful for animations with static axes, where the chart is restored from a
+    [`crate::chart::ChartState`] and the mesh is redrawn for every frame, since the cache is kept
+    in the chart state. The cache is dropped when the plotting area is moved or resized, or
+    the range of the chart is changed by [`ChartContext::set_x_range`] or
+    [`ChartContext::set_y_range`]. The labels are reused only when they are formatted with the
+    same options, and the labels of an axis with a custom formatter set by
+    [`MeshStyle::x_label_formatter`] or [`MeshStyle::y_label_formatter`] are formatted again on
+    every draw, since the formatter may differ from the one of the previous draw. Their sizes
+    are still reused. This is off by default.
+
+    - `value`: If the mesh should be cached
+    */
+    pub fn cache(&mut self, value: bool) -> &mut Self {
+        self.cache = value;
+        self
+    }
+
+    /**
+    Draw the axes through the origin of the chart rather than on the borders of the plotting area,
+    which is the classic axis style of math text books.
+
+    The X axis is drawn at `y = 0` and the Y axis is drawn at `x = 0`, with the tick marks and
+    labels moved along with them. The tick marks and labels at the origin are omitted, so that
+    they don't overlap the other axis. If zero is out of the range of an axis, the other axis
+    falls back to the border of the plotting area.
+
+    - `value`: If the axes should cross at the origin
+    */
+    pub fn axes_at_origin(&mut self, value: bool) -> &mut Self
+    where
+        X::ValueType: Zero,
+        Y::ValueType: Zero,
+    {
+        self.origin = match (value, self.target.as_ref()) {
+            (true, Some(chart)) => chart.origin_in_pixels(),
+            _ => (None, None),
+        };
+        self
+    }
+
+    /**
+    Draw a prominent line at `y = 0` and another one at `x = 0`, which is distinct from the
+    regular grid lines. A line is drawn only if zero is within the range of the axis.
+
+    The lines are drawn after the mesh, thus they are drawn before the data series as long as the
+    mesh is drawn before the series. See [`MeshStyle::emphasize_x_zero_line`] and
+    [`MeshStyle::emphasize_y_zero_line`] for emphasizing just one of them.
+
+    - `style`: The style of the zero lines
+    */
+    pub fn emphasize_zero_line<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self
+    where
+        X::ValueType: Zero,
+        Y::ValueType: Zero,
+    {
+        let style = style.into();
+        self.emphasize_x_zero_line(style)
+            .emphasize_y_zero_line(style)
+    }
+
+    /// Draw a prominent vertical line at `x = 0` if zero is within the range of the X axis,
+    /// see [`MeshStyle::emphasize_zero_line`] for details
+    /// - `style`: The style of the zero line
+    pub fn emphasize_x_zero_line<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self
+    where
+        X::ValueType: Zero,
+    {
+        self.zero_lines[0] = self
+            .target
+            .as_ref()
+            .and_then(|chart| chart.x_zero_in_pixels())
+            .map(|x| (x, style.into()));
+        self
+    }
+
+    /// Draw a prominent horizontal line at `y = 0` if zero is within the range of the Y axis,
+    /// see [`MeshStyle::emphasize_zero_line`] for details
+    /// - `style`: The style of the zero line
+    pub fn emphasize_y_zero_line<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self
+    where
+        Y::ValueType: Zero,
+    {
+        self.zero_lines[1] = self
+            .target
+            .as_ref()
+            .and_then(|chart| chart.y_zero_in_pixels())
+            .map(|y| (y, style.into()));
+        self
+    }
+
+    /// Draw arrowheads at the right end of the X axis and the top end of the Y axis, this works
+    /// with both the border axes and the axes at origin, see [`MeshStyle::axes_at_origin`]
+    /// - `value`: If the arrowheads should be drawn
+    pub fn axis_arrows(&mut self, value: bool) -> &mut Self {
+        self.axis_arrows = value;
+        self
+    }
+
+    /// Set the size of the axis arrowheads, the default size is 2% of the plotting area.
+    /// This also enables the arrowheads, see [`MeshStyle::axis_arrows`]
+    /// - `size`: The length of the arrowheads
+    pub fn axis_arrow_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.axis_arrows = true;
+        self.axis_arrow_size = size.in_pixels(&self.parent_size);
+        self
+    }
+
     /// Disable drawing all meshes
     pub fn disable_mesh(&mut self) -> &mut Self {
         self.disable_x_mesh().disable_y_mesh()
@@ -336,6 +794,40 @@ where
         self
     }
 
+    /// Draw the minor grid lines of the X axis, which subdivide each interval between two
+    /// consecutive X labels evenly into the given number of segments. The minor lines replace
+    /// the fine grid of the X axis and are drawn with [`MeshStyle::light_line_style`], beneath
+    /// the coarse grid. A logarithmic axis is subdivided evenly in the values, thus the minor
+    /// lines of a decade are placed at 2, 3, ... 9 times the start of the decade with 9 segments.
+    /// - `value`: The number of segments between two consecutive X labels, 0 to disable the
+    ///   minor lines, which is the default
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// let drawing_area = SVGBackend::new("minor_lines.svg", (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .build_cartesian_2d((1.0..1000.0).log_scale(), 0.0..10.0)
+    ///     .unwrap();
+    /// chart
+    ///     .configure_mesh()
+    ///     .x_minor_lines(9)
+    ///     .y_minor_lines(4)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    pub fn x_minor_lines(&mut self, value: usize) -> &mut Self {
+        self.minor_lines.0 = value;
+        self
+    }
+
+    /// Draw the minor grid lines of the Y axis, see [`MeshStyle::x_minor_lines`] for details
+    /// - `value`: The number of segments between two consecutive Y labels, 0 to disable the
+    ///   minor lines, which is the default
+    pub fn y_minor_lines(&mut self, value: usize) -> &mut Self {
+        self.minor_lines.1 = value;
+        self
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -350,6 +842,39 @@ where
         self
     }
 
+    /// Fill the plotting area with the given color before drawing the grid lines, so that the
+    /// plotting area can have a different background than the rest of the chart. The label areas
+    /// are not filled.
+    /// - `color`: The background color of the plotting area
+    pub fn plot_background<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.plot_background = Some(color.to_rgba());
+        self
+    }
+
+    /// Set the style for both the coarse and the fine grind grids, which can carry a dash
+    /// pattern for the dotted or dashed grid lines. Use [`MeshStyle::bold_line_style`] and
+    /// [`MeshStyle::light_line_style`] afterwards to style the two grids differently.
+    /// - `style`: The grid style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// let drawing_area = SVGBackend::new("grid_style.svg", (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    ///     .unwrap();
+    /// chart
+    ///     .configure_mesh()
+    ///     .grid_style(ShapeStyle::from(&BLACK.mix(0.3)).dashed(&[1, 2]))
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    pub fn grid_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        let style = style.into();
+        self.bold_line_style = Some(style);
+        self.light_line_style = Some(style);
+        self
+    }
+
     /// Set the style for the coarse grind grid
     /// - `style`: This is the coarse grind grid style
     pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -375,6 +900,32 @@ where
 
     /// Set the style of the label X axis text
     /// - `style`: The text style that would be applied to the labels
+    ///
+    /// The labels can be slanted with [`FontTransform::RotateAngle`](crate::style::FontTransform),
+    /// then each label hangs from its tick mark by the corner which is the closest to the axis,
+    /// for example, the top right corner of a label below the axis rotated counterclockwise. The
+    /// height of the label area which is needed by the slanted labels can be found with
+    /// [`measure_tick_labels()`] and the rotated font.
+    ///
+    /// ```
+    /// use plotters::chart::measure_tick_labels;
+    /// use plotters::prelude::*;
+    /// let font = ("sans-serif", 12).into_font().transform(FontTransform::RotateAngle(-45.0));
+    /// let (_, height) = measure_tick_labels(0.0..1000.0, 20, font.clone()).unwrap();
+    /// let drawing_area = SVGBackend::new("x_label_style.svg", (400, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .x_label_area_size(height + 10)
+    ///     .y_label_area_size(40)
+    ///     .build_cartesian_2d(0.0..1000.0, 0.0..1.0)
+    ///     .unwrap();
+    /// chart
+    ///     .configure_mesh()
+    ///     .x_labels(20)
+    ///     .x_label_formatter(&|x| format!("{:.0} km", x))
+    ///     .x_label_style(font)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
     pub fn x_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
         self.x_label_style = Some(style.into_text_style(&self.parent_size));
         self
@@ -387,17 +938,73 @@ where
         self
     }
 
+    /// Shorten the X labels which are wider than the given width with an ellipsis, rather than
+    /// letting them overlap. Unlike reducing the number of labels, every label is kept.
+    /// The labels are cut on grapheme boundaries.
+    /// - `width`: The maximum width of a X label
+    pub fn x_max_label_width<S: SizeDesc>(&mut self, width: S) -> &mut Self {
+        self.max_label_width.0 = Some(width.in_pixels(&self.parent_size));
+        self
+    }
+
+    /// Shorten the Y labels which are wider than the given width with an ellipsis,
+    /// see [`MeshStyle::x_max_label_width`] for details
+    /// - `width`: The maximum width of a Y label
+    pub fn y_max_label_width<S: SizeDesc>(&mut self, width: S) -> &mut Self {
+        self.max_label_width.1 = Some(width.in_pixels(&self.parent_size));
+        self
+    }
+
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.format_x = Some(fmt);
+        self.format_x = Some(Box::new(fmt));
+        self.label_format.0 = LabelFormat::Custom;
         self
     }
 
     /// Set the formatter function for the Y label text
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.format_y = Some(fmt);
+        self.format_y = Some(Box::new(fmt));
+        self.label_format.1 = LabelFormat::Custom;
+        self
+    }
+
+    /// Format the X labels with a fixed number of decimal places, for example `1.50` and `2.00`
+    /// with `2` decimal places. This replaces the formatter function for the X label text.
+    /// - `decimals`: The number of decimal places
+    pub fn x_label_formatter_precision(&mut self, decimals: usize) -> &mut Self
+    where
+        X::ValueType: Into<f64> + Clone,
+    {
+        self.format_x = Some(Box::new(move |v: &X::ValueType| {
+            format!("{:.*}", decimals, v.clone().into())
+        }));
+        self.label_format.0 = LabelFormat::Precision(decimals);
+        self
+    }
+
+    /// Format the Y labels with a fixed number of decimal places, for example `1.50` and `2.00`
+    /// with `2` decimal places. This replaces the formatter function for the Y label text.
+    /// - `decimals`: The number of decimal places
+    pub fn y_label_formatter_precision(&mut self, decimals: usize) -> &mut Self
+    where
+        Y::ValueType: Into<f64> + Clone,
+    {
+        self.format_y = Some(Box::new(move |v: &Y::ValueType| {
+            format!("{:.*}", decimals, v.clone().into())
+        }));
+        self.label_format.1 = LabelFormat::Precision(decimals);
+        self
+    }
+
+    /// Remove the trailing zeros and the trailing decimal point of numeric labels, so that the
+    /// labels read `1.5` and `2` rather than `1.50` and `2.00`. Labels that are not numbers are
+    /// left untouched. This is off by default.
+    /// - `value`: If the trailing zeros should be trimmed
+    pub fn trim_trailing_zeros(&mut self, value: bool) -> &mut Self {
+        self.trim_trailing_zeros = value;
         self
     }
 
@@ -430,6 +1037,27 @@ where
     {
         let target = self.target.take().unwrap();
 
+        if !self.cache {
+            target.mesh_cache = None;
+        } else if target.mesh_cache.is_none() {
+            target.mesh_cache = Some(MeshCache::default());
+        }
+        if let Some(cache) = target.mesh_cache.as_mut() {
+            cache.validate_labels(MeshLabelKey {
+                format: self.label_format,
+                trim_trailing_zeros: self.trim_trailing_zeros,
+                draw_labels: self.draw_labels,
+                draw_axes: (self.draw_x_axis, self.draw_y_axis),
+                max_label_width: self.max_label_width,
+            });
+        }
+
+        target.mesh_labels = Some((self.n_x_labels, self.n_y_labels));
+
+        if let Some(color) = self.plot_background.as_ref() {
+            target.drawing_area.fill(color)?;
+        }
+
         let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
         let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
         let default_axis_color = RGBColor(0, 0, 0);
@@ -464,17 +1092,42 @@ where
             .clone()
             .unwrap_or_else(|| x_label_style.clone());
 
+        let grid_extent = if self.grid_within_data {
+            target.data_extent
+        } else {
+            None
+        };
+
+        let (draw_x_mesh, draw_y_mesh) = if self.grid_on_top {
+            target.deferred_grid = Some(DeferredGrid {
+                light_style,
+                bold_style,
+                n_x_labels: self.n_x_labels,
+                n_y_labels: self.n_y_labels,
+                x_light_lines_limit: self.x_light_lines_limit,
+                y_light_lines_limit: self.y_light_lines_limit,
+                minor_lines: self.minor_lines,
+                draw_x_mesh: self.draw_x_mesh,
+                draw_y_mesh: self.draw_y_mesh,
+                within_data: self.grid_within_data,
+            });
+            (false, false)
+        } else {
+            (self.draw_x_mesh, self.draw_y_mesh)
+        };
+
         target.draw_mesh(
             (
                 LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
                 LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_light_lines_limit),
             ),
+            self.minor_lines,
             &light_style,
             &x_label_style,
             &y_label_style,
             |_, _, _| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            draw_x_mesh,
+            draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             false,
@@ -485,39 +1138,54 @@ where
             self.y_desc.clone(),
             self.x_tick_size,
             self.y_tick_size,
+            (None, None),
+            None,
+            grid_extent,
+            (None, None),
         )?;
 
         target.draw_mesh(
             (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (0, 0),
             &bold_style,
             &x_label_style,
             &y_label_style,
-            |xr, yr, m| match m {
-                MeshLine::XMesh(_, _, v) => {
-                    if self.draw_x_axis {
-                        if let Some(fmt_func) = self.format_x {
-                            Some(fmt_func(v))
+            |xr, yr, m| {
+                if !self.draw_labels {
+                    return None;
+                }
+                let label = match m {
+                    MeshLine::XMesh(_, _, v) => {
+                        if self.draw_x_axis {
+                            if let Some(fmt_func) = self.format_x.as_ref() {
+                                Some(fmt_func(v))
+                            } else {
+                                Some(xr.format_ext(v))
+                            }
                         } else {
-                            Some(xr.format_ext(v))
+                            None
                         }
-                    } else {
-                        None
                     }
-                }
-                MeshLine::YMesh(_, _, v) => {
-                    if self.draw_y_axis {
-                        if let Some(fmt_func) = self.format_y {
-                            Some(fmt_func(v))
+                    MeshLine::YMesh(_, _, v) => {
+                        if self.draw_y_axis {
+                            if let Some(fmt_func) = self.format_y.as_ref() {
+                                Some(fmt_func(v))
+                            } else {
+                                Some(yr.format_ext(v))
+                            }
                         } else {
-                            Some(yr.format_ext(v))
+                            None
                         }
-                    } else {
-                        None
                     }
+                };
+                if self.trim_trailing_zeros {
+                    label.map(|text| trim_trailing_zeros(&text))
+                } else {
+                    label
                 }
             },
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            draw_x_mesh,
+            draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             self.draw_x_axis,
@@ -528,6 +1196,262 @@ where
             None,
             self.x_tick_size,
             self.y_tick_size,
-        )
+            self.origin,
+            Some(self.axis_arrow_size).filter(|_| self.axis_arrows),
+            grid_extent,
+            self.max_label_width,
+        )?;
+
+        target.draw_zero_lines(&self.zero_lines)
+    }
+}
+
+/// Clip a mesh line to the given bounding box, the X mesh lines outside of the box horizontally
+/// and the Y mesh lines outside of the box vertically are dropped
+pub(crate) fn clip_mesh_line(
+    (from, to): (BackendCoord, BackendCoord),
+    is_x_mesh: bool,
+    (min, max): (BackendCoord, BackendCoord),
+) -> Option<(BackendCoord, BackendCoord)> {
+    if is_x_mesh {
+        let x = from.0;
+        let top = from.1.min(to.1).max(min.1);
+        let bottom = from.1.max(to.1).min(max.1);
+        if min.0 <= x && x <= max.0 && top <= bottom {
+            Some(((x, top), (x, bottom)))
+        } else {
+            None
+        }
+    } else {
+        let y = from.1;
+        let left = from.0.min(to.0).max(min.0);
+        let right = from.0.max(to.0).min(max.0);
+        if min.1 <= y && y <= max.1 && left <= right {
+            Some(((left, y), (right, y)))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_measure_tick_labels() {
+        let font = ("sans-serif", 12).into_font();
+        let (w, h) = measure_tick_labels(0..1000, 11, font.clone()).unwrap();
+        assert_eq!((w, h), font.box_size("1000").unwrap());
+
+        let (w, _) =
+            measure_tick_labels_with(0..1000, 11, font.clone(), |_, v| format!("{} units", v))
+                .unwrap();
+        assert_eq!(w, font.box_size("1000 units").unwrap().0);
+
+        assert_eq!(
+            measure_tick_labels(0..1000, 0, font.clone()).unwrap(),
+            (0, 0)
+        );
+
+        let slanted = font.transform(FontTransform::RotateAngle(-45.0));
+        let (sw, sh) = measure_tick_labels(0..1000, 11, slanted).unwrap();
+        assert!(sh > h && sw > h && sw < w + h);
+    }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_slanted_x_labels() {
+        let draw = |angle: f32| {
+            let mut content = String::new();
+            {
+                let drawing_area =
+                    SVGBackend::with_string(&mut content, (200, 200)).into_drawing_area();
+                let mut chart = ChartBuilder::on(&drawing_area)
+                    .x_label_area_size(40)
+                    .y_label_area_size(40)
+                    .build_cartesian_2d(0..10, 0..10)
+                    .unwrap();
+                chart
+                    .configure_mesh()
+                    .x_label_style(
+                        ("sans-serif", 10)
+                            .into_font()
+                            .transform(FontTransform::RotateAngle(angle)),
+                    )
+                    .draw()
+                    .unwrap();
+            }
+            content
+        };
+
+        let content = draw(-45.0);
+        let slanted = content
+            .lines()
+            .filter(|line| line.contains("rotate(-45, "))
+            .collect::<Vec<_>>();
+        assert!(!slanted.is_empty());
+        assert!(slanted
+            .iter()
+            .all(|line| line.contains("text-anchor=\"end\"")));
+
+        let content = draw(45.0);
+        assert!(content
+            .lines()
+            .filter(|line| line.contains("rotate(45, "))
+            .all(|line| line.contains("text-anchor=\"start\"")));
+    }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_dashed_grid_style() {
+        let mut content = String::new();
+        {
+            let drawing_area =
+                SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            chart
+                .configure_mesh()
+                .grid_style(ShapeStyle::from(&BLACK).dashed(&[1, 2]))
+                .light_line_style(ShapeStyle::from(&BLACK).dashed(&[4, 4]))
+                .draw()
+                .unwrap();
+        }
+
+        assert!(content.contains("stroke-dasharray=\"1 2\""));
+        assert!(content.contains("stroke-dasharray=\"4 4\""));
+    }
+
+    #[test]
+    fn test_minor_lines() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let line_log = lines.clone();
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_line(move |c, _, from, to| {
+                if c == RED.to_rgba() {
+                    line_log.borrow_mut().push((from, to));
+                }
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((1.0..100.0).log_scale(), 0.0..10.0)
+            .unwrap();
+        chart
+            .configure_mesh()
+            .y_labels(6)
+            .x_minor_lines(9)
+            .y_minor_lines(2)
+            .light_line_style(RED)
+            .draw()
+            .unwrap();
+
+        let lines = lines.borrow();
+        let x_lines: Vec<_> = lines.iter().filter(|(a, b)| a.0 == b.0).collect();
+        let y_lines: Vec<_> = lines.iter().filter(|(a, b)| a.1 == b.1).collect();
+
+        // The minor lines of a decade are at 2, 3, ... 9 times the start of it
+        let expected: Vec<_> = (2..10)
+            .chain((2..10).map(|v| v * 10))
+            .map(|v| chart.backend_coord(&(v as f64, 0.0)).0)
+            .collect();
+        assert_eq!(x_lines.len(), expected.len());
+        for ((from, _), x) in x_lines.iter().zip(expected) {
+            assert!((from.0 - x).abs() <= 1);
+        }
+
+        let expected: Vec<_> = [1.0, 3.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|&v| chart.backend_coord(&(1.0, v)).1)
+            .collect();
+        assert_eq!(y_lines.len(), expected.len());
+        for ((from, _), y) in y_lines.iter().zip(expected) {
+            assert!((from.1 - y).abs() <= 1);
+        }
+    }
+
+    /// Draw a mesh with the X minor lines in red, returning the X minor lines and the pixels of
+    /// the given values
+    fn minor_x_lines<X: AsRangedCoord<Value = f64>>(
+        x_spec: X,
+        x_labels: usize,
+        x_minor: usize,
+        values: &[f64],
+    ) -> (Vec<i32>, Vec<i32>)
+    where
+        X::CoordDescType: ValueFormatter<f64>,
+    {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let line_log = lines.clone();
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_line(move |c, _, from, to| {
+                if c == RED.to_rgba() && from.0 == to.0 {
+                    line_log.borrow_mut().push(from.0);
+                }
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(x_spec, 0.0..10.0)
+            .unwrap();
+        chart
+            .configure_mesh()
+            .x_labels(x_labels)
+            .x_minor_lines(x_minor)
+            .light_line_style(RED)
+            .draw()
+            .unwrap();
+        let pixels = values
+            .iter()
+            .map(|&v| chart.backend_coord(&(v, 0.0)).0)
+            .collect();
+        let lines = lines.borrow().clone();
+        (lines, pixels)
+    }
+
+    fn assert_near(actual: &[i32], expected: &[i32]) {
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() <= 1, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_minor_lines_beyond_key_points() {
+        // The key points are 2, 4, 6 and 8, the minor lines continue up to the ends of the range
+        let (lines, expected) = minor_x_lines(0.5..9.5, 5, 2, &[1.0, 3.0, 5.0, 7.0, 9.0]);
+        assert_near(&lines, &expected);
+
+        // A single key point still has minor lines on both sides
+        let (lines, key) = minor_x_lines(0.95..1.04, 1, 2, &[1.0]);
+        assert!(lines.iter().any(|&x| x < key[0]));
+        assert!(lines.iter().any(|&x| x > key[0]));
+
+        // The wrapped logarithmic coordinate keeps its minor lines logarithmic
+        let values: Vec<_> = [0.5, 0.6, 0.7, 0.8, 0.9]
+            .iter()
+            .copied()
+            .chain((2..10).map(f64::from))
+            .chain((2..10).map(|v| f64::from(v) * 10.0))
+            .collect();
+        let x_spec = (0.5..150.0)
+            .log_scale()
+            .with_key_points(vec![1.0, 10.0, 100.0]);
+        let (lines, expected) = minor_x_lines(x_spec, 3, 9, &values);
+        assert_near(&lines, &expected);
     }
 }
@@ -18,13 +18,15 @@ mod context;
 mod dual_coord;
 mod mesh;
 mod series;
+mod small_multiples;
 mod state;
 
-pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use builder::{CaptionPosition, ChartBuilder, LabelAreaPosition};
 pub use context::ChartContext;
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
-pub use mesh::{MeshStyle, SecondaryMeshStyle};
-pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
+pub use mesh::{measure_tick_labels, measure_tick_labels_with, MeshStyle, SecondaryMeshStyle};
+pub use series::{Legend, SeriesAnno, SeriesLabelOrder, SeriesLabelPosition, SeriesLabelStyle};
+pub use small_multiples::{small_multiples, SmallMultiples};
 pub use state::ChartState;
 
 use context::Coord3D;
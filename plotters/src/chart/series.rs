@@ -1,8 +1,11 @@
 use super::ChartContext;
-use crate::coord::CoordTranslate;
-use crate::drawing::DrawingAreaErrorKind;
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
-use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
+use crate::style::{
+    with_default_stroke_width, IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle,
+    TRANSPARENT,
+};
 
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
@@ -14,6 +17,7 @@ type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, Backe
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    sort_key: Option<i32>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
@@ -31,6 +35,7 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         Self {
             label: None,
             draw_func: None,
+            sort_key: None,
         }
     }
 
@@ -65,6 +70,19 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
         self
     }
+
+    /**
+    Sets the sort key of the series label, which is used to order the series labels when
+    [`SeriesLabelOrder::SortKey`] is used. This doesn't change the order the series are drawn.
+
+    - `key`: The sort key, the labels with smaller keys are listed first
+
+    See [`SeriesLabelStyle::order()`] for more information.
+    */
+    pub fn sort_key(&mut self, key: i32) -> &mut Self {
+        self.sort_key = Some(key);
+        self
+    }
 }
 
 /**
@@ -93,53 +111,240 @@ pub enum SeriesLabelPosition {
     LowerRight,
     /// Places the series label at the specific location in backend coordinates
     Coordinate(i32, i32),
+    /// Places the series label in the strip on the right of the plotting area, which is reserved
+    /// by [`ChartBuilder::series_label_area()`](crate::chart::ChartBuilder::series_label_area).
+    /// Without the reserved strip, the series label is placed at the middle right
+    OutsideRight,
+    /// Places the series label in the strip below the plotting area, which is reserved by
+    /// [`ChartBuilder::series_label_area()`](crate::chart::ChartBuilder::series_label_area).
+    /// Without the reserved strip, the series label is placed at the lower middle
+    OutsideBottom,
+}
+
+/**
+Useful to specify the order of the series labels.
+
+See [`SeriesLabelStyle::order()`] for more information.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeriesLabelOrder {
+    /// Lists the series labels in the order the series are drawn, which is the default
+    DrawOrder,
+    /// Lists the series labels sorted alphabetically
+    Alphabetical,
+    /// Lists the series labels sorted by the keys set by [`SeriesAnno::sort_key()`], the labels
+    /// without a key are listed last, in the order the series are drawn
+    SortKey,
 }
 
 impl SeriesLabelPosition {
+    /// Check if the series label is placed out of the plotting area
+    fn is_outside(&self) -> bool {
+        matches!(
+            self,
+            SeriesLabelPosition::OutsideRight | SeriesLabelPosition::OutsideBottom
+        )
+    }
+
     fn layout_label_area(&self, label_dim: (i32, i32), area_dim: (u32, u32)) -> (i32, i32) {
         use SeriesLabelPosition::*;
         (
             match self {
                 UpperLeft | MiddleLeft | LowerLeft => 5,
-                UpperMiddle | MiddleMiddle | LowerMiddle => {
+                UpperMiddle | MiddleMiddle | LowerMiddle | OutsideBottom => {
                     (area_dim.0 as i32 - label_dim.0 as i32) / 2
                 }
-                UpperRight | MiddleRight | LowerRight => area_dim.0 as i32 - label_dim.0 as i32 - 5,
+                UpperRight | MiddleRight | LowerRight | OutsideRight => {
+                    area_dim.0 as i32 - label_dim.0 as i32 - 5
+                }
                 Coordinate(x, _) => *x,
             },
             match self {
                 UpperLeft | UpperMiddle | UpperRight => 5,
-                MiddleLeft | MiddleMiddle | MiddleRight => {
+                MiddleLeft | MiddleMiddle | MiddleRight | OutsideRight => {
                     (area_dim.1 as i32 - label_dim.1 as i32) / 2
                 }
-                LowerLeft | LowerMiddle | LowerRight => area_dim.1 as i32 - label_dim.1 as i32 - 5,
+                LowerLeft | LowerMiddle | LowerRight | OutsideBottom => {
+                    area_dim.1 as i32 - label_dim.1 as i32 - 5
+                }
                 Coordinate(_, y) => *y,
             },
         )
     }
 }
 
-/// The struct to specify the series label of a target chart context
-pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
-    target: &'b mut ChartContext<'a, DB, CT>,
+/// The options of a legend, which are shared by the series labels of a chart and the standalone
+/// legend
+pub(super) struct LegendStyle<'b> {
     position: SeriesLabelPosition,
+    order: SeriesLabelOrder,
     legend_area_size: u32,
     border_style: ShapeStyle,
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    background_opacity: Option<f64>,
+    backdrop_blur: u32,
 }
 
-impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
-    pub(super) fn new(target: &'b mut ChartContext<'a, DB, CT>) -> Self {
+impl<'b> LegendStyle<'b> {
+    pub(super) fn new() -> Self {
         Self {
-            target,
             position: SeriesLabelPosition::MiddleRight,
+            order: SeriesLabelOrder::DrawOrder,
             legend_area_size: 30,
             border_style: (&TRANSPARENT).into(),
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            background_opacity: None,
+            backdrop_blur: 0,
+        }
+    }
+
+    /// The font of the labels
+    fn font(&self) -> TextStyle<'b> {
+        // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
+        // resolved
+        match self.label_font.as_ref() {
+            Some(font) => font.clone(),
+            None => ("sans-serif", 12).into_font().into(),
+        }
+    }
+
+    /// Compute the size of the legend box which contains the given labels
+    fn measure_label_element<DB: DrawingBackend>(
+        &self,
+        label_element: &MultiLineText<BackendCoord, &str>,
+    ) -> Result<(i32, i32), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (w, h) = label_element.estimate_dimension().map_err(|e| {
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
+        })?;
+        let margin = self.margin as i32;
+        Ok((
+            w + self.legend_area_size as i32 + margin * 2,
+            h + margin * 2,
+        ))
+    }
+
+    /// Compute the size of the legend box of the given labels, or `None` if there's no label
+    pub(super) fn measure<DB: DrawingBackend, S: AsRef<str>>(
+        &self,
+        labels: &[S],
+    ) -> Result<Option<(u32, u32)>, DrawingAreaErrorKind<DB::ErrorType>> {
+        if labels.is_empty() {
+            return Ok(None);
+        }
+        let font = self.font();
+        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        for label in labels {
+            label_element.push_line(label.as_ref());
+        }
+        let (w, h) = self.measure_label_element::<DB>(&label_element)?;
+        Ok(Some((w.max(0) as u32, h.max(0) as u32)))
+    }
+
+    /// Draw the legend of the given entries onto the drawing area, the entries without a label
+    /// and a legend element are skipped
+    fn draw<'a, DB: DrawingBackend + 'a>(
+        &self,
+        drawing_area: &DrawingArea<DB, Shift>,
+        entries: &[SeriesAnno<'a, DB>],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let font = self.font();
+
+        let mut entries: Vec<_> = entries.iter().collect();
+        match self.order {
+            SeriesLabelOrder::DrawOrder => {}
+            SeriesLabelOrder::Alphabetical => {
+                entries.sort_by(|a, b| a.get_label().cmp(b.get_label()));
+            }
+            SeriesLabelOrder::SortKey => {
+                entries.sort_by_key(|anno| (anno.sort_key.is_none(), anno.sort_key));
+            }
+        }
+
+        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        let mut funcs = vec![];
+
+        for anno in entries {
+            let label_text = anno.get_label();
+            let draw_func = anno.get_draw_func();
+
+            if label_text.is_empty() && draw_func.is_none() {
+                continue;
+            }
+
+            funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
+            label_element.push_line(label_text);
+        }
+
+        let (w, h) = self.measure_label_element::<DB>(&label_element)?;
+        let margin = self.margin as i32;
+
+        let (area_w, area_h) = drawing_area.dim_in_pixel();
+
+        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
+
+        label_element.relocate((
+            label_x + self.legend_area_size as i32 + margin,
+            label_y + margin,
+        ));
+
+        if self.backdrop_blur > 0 {
+            let (x0, y0) = drawing_area.get_base_pixel();
+            let radius = self.backdrop_blur;
+            drawing_area.backend_ops(|b| {
+                b.blur_rect(
+                    (x0 + label_x, y0 + label_y),
+                    (x0 + label_x + w, y0 + label_y + h),
+                    radius,
+                )
+            })?;
+        }
+
+        let mut background = self.background;
+        if let Some(opacity) = self.background_opacity {
+            background.color.3 = opacity;
+        }
+
+        drawing_area.draw(&Rectangle::new(
+            [(label_x, label_y), (label_x + w, label_y + h)],
+            background.filled(),
+        ))?;
+        drawing_area.draw(&Rectangle::new(
+            [(label_x, label_y), (label_x + w, label_y + h)],
+            self.border_style,
+        ))?;
+        drawing_area.draw(&label_element)?;
+
+        for (((_, y0), (_, y1)), make_elem) in label_element
+            .compute_line_layout()
+            .map_err(|e| {
+                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
+            })?
+            .into_iter()
+            .zip(funcs.into_iter())
+        {
+            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
+            drawing_area.draw(&legend_element)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The struct to specify the series label of a target chart context
+pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
+    target: &'b mut ChartContext<'a, DB, CT>,
+    style: LegendStyle<'b>,
+}
+
+impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
+    pub(super) fn new(target: &'b mut ChartContext<'a, DB, CT>) -> Self {
+        Self {
+            target,
+            style: LegendStyle::new(),
         }
     }
 
@@ -151,7 +356,21 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn position(&mut self, pos: SeriesLabelPosition) -> &mut Self {
-        self.position = pos;
+        self.style.position = pos;
+        self
+    }
+
+    /**
+    Sets the order of the series labels, without changing the order the series are drawn.
+    This is useful when the series are drawn in the order of the layers, but the labels should
+    be listed in a logical order.
+
+    `order` - The order of the labels, [`SeriesLabelOrder::DrawOrder`] by default
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn order(&mut self, order: SeriesLabelOrder) -> &mut Self {
+        self.style.order = order;
         self
     }
 
@@ -163,7 +382,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn margin<S: SizeDesc>(&mut self, value: S) -> &mut Self {
-        self.margin = value
+        self.style.margin = value
             .in_pixels(&self.target.plotting_area().dim_in_pixel())
             .max(0) as u32;
         self
@@ -180,7 +399,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         let size = size
             .in_pixels(&self.target.plotting_area().dim_in_pixel())
             .max(0) as u32;
-        self.legend_area_size = size;
+        self.style.legend_area_size = size;
         self
     }
 
@@ -192,7 +411,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn border_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
-        self.border_style = style.into();
+        self.style.border_style = style.into();
         self
     }
 
@@ -204,7 +423,38 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn background_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
-        self.background = style.into();
+        self.style.background = style.into();
+        self
+    }
+
+    /**
+    Sets the opacity of the background of the label series area, which overrides the alpha
+    channel of the background style. A translucent background keeps the data behind the
+    labels visible.
+
+    `opacity` - The opacity between 0.0 (transparent) and 1.0 (opaque)
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn background_opacity(&mut self, opacity: f64) -> &mut Self {
+        self.style.background_opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+
+    /**
+    Blurs the figure behind the label series area before the background is drawn, which
+    makes the labels readable without fully hiding the data. Darkening or lightening the
+    figure is done by a translucent background together with this.
+
+    The blur is only done by the backends which are able to read back the pixels, such as the
+    bitmap backend, see [`DrawingBackend::blur_rect`]. Other backends ignore it.
+
+    `radius` - The radius of the blur in backend units (pixels), 0 disables the blur
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn backdrop_blur(&mut self, radius: u32) -> &mut Self {
+        self.style.backdrop_blur = radius;
         self
     }
 
@@ -216,7 +466,8 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn label_font<F: IntoTextStyle<'b>>(&mut self, font: F) -> &mut Self {
-        self.label_font = Some(font.into_text_style(&self.target.plotting_area().dim_in_pixel()));
+        self.style.label_font =
+            Some(font.into_text_style(&self.target.plotting_area().dim_in_pixel()));
         self
     }
 
@@ -226,74 +477,134 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
-        let drawing_area = self.target.plotting_area().strip_coord_spec();
-
-        // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
-        // resolved
-        let default_font = ("sans-serif", 12).into_font();
-        let default_style: TextStyle = default_font.into();
-
-        let font = {
-            let mut temp = None;
-            std::mem::swap(&mut self.label_font, &mut temp);
-            temp.unwrap_or(default_style)
+        let drawing_area = match self.target.series_label_area.as_ref() {
+            Some(area) if self.style.position.is_outside() => area.clone(),
+            _ => self.target.plotting_area().strip_coord_spec(),
         };
+        let style = &self.style;
+        let annos = &self.target.series_anno;
+        with_default_stroke_width(self.target.default_stroke_width, || {
+            style.draw(&drawing_area, annos)
+        })
+    }
+}
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+/**
+A legend which is not attached to any chart, it can be drawn onto any drawing area.
+
+This is useful for a grid of charts which share the same series, where a single legend is
+drawn for all the charts, for example, in a strip reserved by [`DrawingArea::split_vertically`].
+The entries are added with [`Legend::add_entry`], which mirrors [`SeriesAnno::label`] and
+[`SeriesAnno::legend`], and the options are the same as the ones of [`SeriesLabelStyle`].
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("shared_legend.svg", (400, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let (upper, lower) = drawing_area.split_vertically(260);
+for panel in upper.split_evenly((1, 2)).iter() {
+    let mut chart = ChartBuilder::on(panel).build_cartesian_2d(0..10, 0..10).unwrap();
+    chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), RED)).unwrap();
+    chart.draw_series(LineSeries::new((0..10).map(|x| (x, 9 - x)), BLUE)).unwrap();
+}
+Legend::on(&lower)
+    .add_entry("Rising", |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED))
+    .add_entry("Falling", |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE))
+    .position(SeriesLabelPosition::MiddleMiddle)
+    .draw()
+    .unwrap();
+```
+*/
+pub struct Legend<'a, DB: DrawingBackend> {
+    area: &'a DrawingArea<DB, Shift>,
+    entries: Vec<SeriesAnno<'a, DB>>,
+    style: LegendStyle<'a>,
+}
 
-        for anno in self.target.series_anno.iter() {
-            let label_text = anno.get_label();
-            let draw_func = anno.get_draw_func();
+impl<'a, DB: DrawingBackend + 'a> Legend<'a, DB> {
+    /// Create a legend without any entry on the drawing area
+    /// - `area`: The drawing area where the legend is drawn
+    pub fn on(area: &'a DrawingArea<DB, Shift>) -> Self {
+        Self {
+            area,
+            entries: vec![],
+            style: LegendStyle::new(),
+        }
+    }
 
-            if label_text.is_empty() && draw_func.is_none() {
-                continue;
-            }
+    /// Add an entry to the legend
+    /// - `label`: The label of the entry
+    /// - `func`: The function used to create the legend element, see [`SeriesAnno::legend`]
+    pub fn add_entry<L, E, T>(&mut self, label: L, func: T) -> &mut Self
+    where
+        L: Into<String>,
+        E: IntoDynElement<'a, DB, BackendCoord>,
+        T: Fn(BackendCoord) -> E + 'a,
+    {
+        let mut entry = SeriesAnno::new();
+        entry.label(label).legend(func);
+        self.entries.push(entry);
+        self
+    }
 
-            funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
-            label_element.push_line(label_text);
-        }
+    /// Sets the position of the legend in the drawing area, see [`SeriesLabelStyle::position`]
+    pub fn position(&mut self, pos: SeriesLabelPosition) -> &mut Self {
+        self.style.position = pos;
+        self
+    }
 
-        let (mut w, mut h) = label_element.estimate_dimension().map_err(|e| {
-            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-        })?;
+    /// Sets the order of the entries, see [`SeriesLabelStyle::order`]
+    pub fn order(&mut self, order: SeriesLabelOrder) -> &mut Self {
+        self.style.order = order;
+        self
+    }
 
-        let margin = self.margin as i32;
+    /// Sets the margin of the legend, see [`SeriesLabelStyle::margin`]
+    pub fn margin<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.margin = value.in_pixels(self.area).max(0) as u32;
+        self
+    }
 
-        w += self.legend_area_size as i32 + margin * 2;
-        h += margin * 2;
+    /// Sets the size of the legend element area, see [`SeriesLabelStyle::legend_area_size`]
+    pub fn legend_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.style.legend_area_size = size.in_pixels(self.area).max(0) as u32;
+        self
+    }
 
-        let (area_w, area_h) = drawing_area.dim_in_pixel();
+    /// Sets the border style of the legend, see [`SeriesLabelStyle::border_style`]
+    pub fn border_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.style.border_style = style.into();
+        self
+    }
 
-        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
+    /// Sets the background style of the legend, see [`SeriesLabelStyle::background_style`]
+    pub fn background_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.style.background = style.into();
+        self
+    }
 
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
+    /// Sets the opacity of the background, see [`SeriesLabelStyle::background_opacity`]
+    pub fn background_opacity(&mut self, opacity: f64) -> &mut Self {
+        self.style.background_opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
 
-        drawing_area.draw(&Rectangle::new(
-            [(label_x, label_y), (label_x + w, label_y + h)],
-            self.background.filled(),
-        ))?;
-        drawing_area.draw(&Rectangle::new(
-            [(label_x, label_y), (label_x + w, label_y + h)],
-            self.border_style,
-        ))?;
-        drawing_area.draw(&label_element)?;
+    /// Blurs the figure behind the legend, see [`SeriesLabelStyle::backdrop_blur`]
+    pub fn backdrop_blur(&mut self, radius: u32) -> &mut Self {
+        self.style.backdrop_blur = radius;
+        self
+    }
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
-            .compute_line_layout()
-            .map_err(|e| {
-                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-            })?
-            .into_iter()
-            .zip(funcs.into_iter())
-        {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
-        }
+    /// Sets the font of the labels, see [`SeriesLabelStyle::label_font`]
+    pub fn label_font<F: IntoTextStyle<'a>>(&mut self, font: F) -> &mut Self {
+        self.style.label_font = Some(font.into_text_style(self.area));
+        self
+    }
 
-        Ok(())
+    /// Draws the legend onto the drawing area
+    pub fn draw(&self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.style.draw(self.area, &self.entries)
     }
 }
@@ -0,0 +1,173 @@
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::style::{Color, RGBAColor, SizeDesc};
+
+use plotters_backend::DrawingBackend;
+
+/**
+Draws a grid of small multiples, which are the charts of the same kind laid out evenly, with
+a shared styling.
+
+The drawing area is split with [`DrawingArea::split_evenly`], the shared margin and background
+are applied to each cell, and then the drawing function is called for each cell with the cell
+area and its index. The cells are indexed row by row, from the upper left one.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("small_multiples.svg", (600, 400)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+SmallMultiples::on(&drawing_area, (2, 3))
+    .margin(5)
+    .background_color(&WHITE.mix(0.9))
+    .draw(|area, index| {
+        let mut chart = ChartBuilder::on(area)
+            .caption(format!("Series {}", index), ("sans-serif", 15))
+            .set_left_and_bottom_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)?;
+        chart.configure_mesh().draw()?;
+        let k = index as i32 + 1;
+        chart.draw_series(LineSeries::new((0..10).map(|x| (x, (x * k) % 10)), RED))?;
+        Ok(())
+    })
+    .unwrap();
+```
+*/
+pub struct SmallMultiples<'a, DB: DrawingBackend> {
+    root: &'a DrawingArea<DB, Shift>,
+    shape: (usize, usize),
+    margin: [i32; 4],
+    background: Option<RGBAColor>,
+}
+
+impl<'a, DB: DrawingBackend> SmallMultiples<'a, DB> {
+    /**
+    Creates a grid of small multiples on the drawing area.
+
+    - `root`: The drawing area to split
+    - `shape`: The number of rows and the number of columns of the grid
+    - Returns: The small multiples object
+
+    See [`SmallMultiples`] for more information and examples.
+    */
+    pub fn on(root: &'a DrawingArea<DB, Shift>, shape: (usize, usize)) -> Self {
+        Self {
+            root,
+            shape,
+            margin: [0; 4],
+            background: None,
+        }
+    }
+
+    /**
+    Sets the margin of each cell.
+
+    - `size`: The size of the four margins of each cell in backend units (pixels)
+
+    See [`SmallMultiples`] for more information and examples.
+    */
+    pub fn margin<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        let size = size.in_pixels(self.root).max(0);
+        self.margin = [size, size, size, size];
+        self
+    }
+
+    /**
+    Sets the background color of each cell, which is filled before the cell is drawn.
+
+    - `color`: The background color
+
+    See [`SmallMultiples`] for more information and examples.
+    */
+    pub fn background_color<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /**
+    Draws the small multiples.
+
+    - `draw`: The function that draws a cell, which is called with the area of the cell within the
+      margin and the index of the cell
+    - Returns: The first error returned by the drawing function, if any
+
+    See [`SmallMultiples`] for more information and examples.
+    */
+    pub fn draw<F>(&self, mut draw: F) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        F: FnMut(&DrawingArea<DB, Shift>, usize) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        let [top, bottom, left, right] = self.margin;
+        for (index, cell) in self.root.split_evenly(self.shape).iter().enumerate() {
+            let area = cell.margin(top, bottom, left, right);
+            if let Some(color) = self.background.as_ref() {
+                area.fill(color)?;
+            }
+            draw(&area, index)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+Draws a grid of small multiples without any shared styling, which is a shorthand of
+[`SmallMultiples::draw`].
+
+- `root`: The drawing area to split
+- `shape`: The number of rows and the number of columns of the grid
+- `draw`: The function that draws a cell, which is called with the area of the cell and its index
+- Returns: The first error returned by the drawing function, if any
+*/
+pub fn small_multiples<DB, F>(
+    root: &DrawingArea<DB, Shift>,
+    shape: (usize, usize),
+    draw: F,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+where
+    DB: DrawingBackend,
+    F: FnMut(&DrawingArea<DB, Shift>, usize) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+{
+    SmallMultiples::on(root, shape).draw(draw)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_small_multiples() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert!(filled);
+                assert_eq!(c, WHITE.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_circle_call, 2);
+            });
+        });
+
+        let mut cells = vec![];
+        SmallMultiples::on(&drawing_area, (1, 2))
+            .margin(10)
+            .background_color(&WHITE)
+            .draw(|area, index| {
+                cells.push((index, area.get_base_pixel(), area.dim_in_pixel()));
+                area.draw(&Circle::new((5, 5), 5, RED))
+            })
+            .expect("Drawing error");
+
+        assert_eq!(cells, [(0, (10, 10), (80, 80)), (1, (110, 10), (80, 80))]);
+
+        let result = small_multiples(&drawing_area, (2, 2), |_, index| {
+            if index == 1 {
+                Err(DrawingAreaErrorKind::LayoutError)
+            } else {
+                Ok(())
+            }
+        });
+        assert!(matches!(result, Err(DrawingAreaErrorKind::LayoutError)));
+    }
+}
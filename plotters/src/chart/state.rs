@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use super::mesh::MeshCache;
 use super::ChartContext;
 use crate::coord::{CoordTranslate, Shift};
 use crate::drawing::DrawingArea;
@@ -35,7 +36,9 @@ use plotters_backend::DrawingBackend;
 pub struct ChartState<CT: CoordTranslate> {
     drawing_area_pos: (i32, i32),
     drawing_area_size: (u32, u32),
+    default_stroke_width: u32,
     coord: CT,
+    mesh_cache: Option<MeshCache>,
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>> for ChartState<CT> {
@@ -43,7 +46,9 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>>
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            default_stroke_width: chart.default_stroke_width,
             coord: chart.drawing_area.into_coord_spec(),
+            mesh_cache: chart.mesh_cache,
         }
     }
 }
@@ -63,7 +68,9 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         ChartState {
             drawing_area_pos: self.drawing_area_pos,
             drawing_area_size: self.drawing_area.dim_in_pixel(),
+            default_stroke_width: self.default_stroke_width,
             coord: Arc::new(self.drawing_area.into_coord_spec()),
+            mesh_cache: self.mesh_cache,
         }
     }
 }
@@ -77,7 +84,9 @@ where
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            default_stroke_width: chart.default_stroke_width,
             coord: chart.drawing_area.as_coord_spec().clone(),
+            mesh_cache: chart.mesh_cache.clone(),
         }
     }
 }
@@ -107,6 +116,13 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            default_stroke_width: self.default_stroke_width,
+            deferred_grid: None,
+            held_grid: None,
+            data_extent: None,
+            mesh_cache: self.mesh_cache,
+            mesh_labels: None,
+            series_label_area: None,
         }
     }
 }
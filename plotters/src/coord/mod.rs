@@ -21,6 +21,7 @@ See documentation for module [combinators](combinators/index.html)  for details.
 Currently we support the following 2D coordinate system:
 
 - 2-dimensional Cartesian Coordinate: This is done by the combinator [Cartesian2d](cartesian/struct.Cartesian2d.html).
+- Polar Coordinate: This is done by the combinator [Polar](polar/struct.Polar.html).
 
 */
 
@@ -48,13 +49,35 @@ pub mod ranged3d;
 
 /// Groups Cartesian ranged coordinates in 2d and 3d.
 pub mod cartesian {
-    pub use super::ranged2d::cartesian::{Cartesian2d, MeshLine};
+    pub use super::ranged2d::cartesian::{Cartesian2d, MeshLine, PixelRounding};
     pub use super::ranged3d::Cartesian3d;
 }
 
+/// The polar coordinate system in 2d.
+pub mod polar {
+    pub use super::ranged2d::polar::Polar;
+}
+
 mod translate;
 pub use translate::{CoordTranslate, ReverseCoordTranslate};
 
+/// The backend coordinate that a non-finite value, such as NaN or infinity, is mapped to.
+/// If any component of a point is mapped to this value, the point is skipped when drawing an
+/// element, and a path element is broken into pieces at the point.
+pub const NON_FINITE_COORD: i32 = i32::MIN;
+
+/// The largest magnitude of a mapped backend coordinate. A value which is mapped far outside of
+/// the pixel range is clamped to this bound rather than overflowing, and there's still enough
+/// headroom for the element drawing code to offset the coordinate.
+pub(crate) const MAX_BACKEND_COORD: i32 = 1 << 30;
+
+/// Convert a pixel position computed in floating point to a backend coordinate, saturating at
+/// [`MAX_BACKEND_COORD`]
+pub(crate) fn saturating_backend_coord(value: f64) -> i32 {
+    let limit = f64::from(MAX_BACKEND_COORD);
+    value.max(-limit).min(limit) as i32
+}
+
 /// The coordinate translation that only impose shift
 #[derive(Debug, Clone)]
 pub struct Shift(pub BackendCoord);
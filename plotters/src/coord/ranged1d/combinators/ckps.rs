@@ -63,6 +63,20 @@ where
         self.inner.map(value, limit)
     }
 
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.inner.map_f64(value, limit)
+    }
+
+    fn map_between(
+        &self,
+        from: &Self::ValueType,
+        to: &Self::ValueType,
+        ratio: f64,
+        limit: (i32, i32),
+    ) -> f64 {
+        self.inner.map_between(from, to, ratio, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         if hint.weight().allow_light_points() {
             self.light_points.clone()
@@ -188,6 +202,20 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
         self.inner.map(value, limit)
     }
 
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.inner.map_f64(value, limit)
+    }
+
+    fn map_between(
+        &self,
+        from: &Self::ValueType,
+        to: &Self::ValueType,
+        ratio: f64,
+        limit: (i32, i32),
+    ) -> f64 {
+        self.inner.map_between(from, to, ratio, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         if hint.weight().allow_light_points() {
             (self.light_func)(hint.max_num_points())
@@ -223,7 +251,10 @@ mod test {
         assert_eq!(range.map(&3, (0, 1000)), 30);
         assert_eq!(range.range(), 0..100);
         assert_eq!(range.key_points(BoldPoints(100)), vec![1, 2, 3]);
-        assert_eq!(range.key_points(LightPoints::new(100, 100)), vec![]);
+        assert_eq!(
+            range.key_points(LightPoints::new(100, 100)),
+            Vec::<i32>::new()
+        );
         let range = range.with_light_points(5..10);
         assert_eq!(range.key_points(BoldPoints(10)), vec![1, 2, 3]);
         assert_eq!(
@@ -251,7 +282,10 @@ mod test {
         assert_eq!(range.map(&3, (0, 1000)), 30);
         assert_eq!(range.range(), 0..100);
         assert_eq!(range.key_points(BoldPoints(100)), vec![1, 2, 3]);
-        assert_eq!(range.key_points(LightPoints::new(100, 100)), vec![]);
+        assert_eq!(
+            range.key_points(LightPoints::new(100, 100)),
+            Vec::<i32>::new()
+        );
         let range = range.with_light_point_func(|_| (5..10).collect());
         assert_eq!(range.key_points(BoldPoints(10)), vec![1, 2, 3]);
         assert_eq!(
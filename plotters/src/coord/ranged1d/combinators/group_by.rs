@@ -67,6 +67,18 @@ impl<T: DiscreteRanged> Ranged for GroupBy<T> {
     fn map(&self, value: &T::ValueType, limit: (i32, i32)) -> i32 {
         self.0.map(value, limit)
     }
+    fn map_f64(&self, value: &T::ValueType, limit: (i32, i32)) -> f64 {
+        self.0.map_f64(value, limit)
+    }
+    fn map_between(
+        &self,
+        from: &T::ValueType,
+        to: &T::ValueType,
+        ratio: f64,
+        limit: (i32, i32),
+    ) -> f64 {
+        self.0.map_between(from, to, ratio, limit)
+    }
     fn range(&self) -> Range<T::ValueType> {
         self.0.range()
     }
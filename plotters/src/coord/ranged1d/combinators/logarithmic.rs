@@ -71,6 +71,7 @@ impl<T: LogScalable> IntoLogRange for Range<T> {
             range: self,
             zero: 0.0,
             base: 10.0,
+            decades: LogDecades::Auto,
         }
     }
 }
@@ -82,6 +83,18 @@ pub struct LogRangeExt<V: LogScalable> {
     range: Range<V>,
     zero: f64,
     base: f64,
+    decades: LogDecades,
+}
+
+/// Decides which powers of the base are used as the bold key points
+#[derive(Clone)]
+enum LogDecades {
+    /// Picked automatically based on the number of key points requested
+    Auto,
+    /// Every n-th power, starting from the 0-th power
+    Step(u32),
+    /// The powers with the given exponents
+    Exponents(Vec<i32>),
 }
 
 impl<V: LogScalable> LogRangeExt<V> {
@@ -107,6 +120,24 @@ impl<V: LogScalable> LogRangeExt<V> {
         }
         self
     }
+
+    /// Only put the bold key points, which are the labeled ones, on every `step`-th power of
+    /// the base, starting from the 0-th power. For example, with a step of 3, the labels are
+    /// placed at 1, 1000, 1000000 and so on. The light key points still fill in between.
+    pub fn decade_step(mut self, step: u32) -> Self {
+        self.decades = LogDecades::Step(step.max(1));
+        self
+    }
+
+    /// Only put the bold key points, which are the labeled ones, on the powers of the base
+    /// with the given exponents. The light key points still fill in between.
+    pub fn decade_exponents<I: IntoIterator<Item = i32>>(mut self, exponents: I) -> Self {
+        let mut exponents: Vec<_> = exponents.into_iter().collect();
+        exponents.sort_unstable();
+        exponents.dedup();
+        self.decades = LogDecades::Exponents(exponents);
+        self
+    }
 }
 
 impl<V: LogScalable> From<LogRangeExt<V>> for LogCoord<V> {
@@ -135,6 +166,7 @@ impl<V: LogScalable> From<LogRangeExt<V>> for LogCoord<V> {
             logic: spec.range,
             normalized: start..end,
             base: spec.base,
+            decades: spec.decades,
             zero_point,
             negative,
             marker: PhantomData,
@@ -153,6 +185,7 @@ pub struct LogCoord<V: LogScalable> {
     logic: Range<V>,
     normalized: Range<f64>,
     base: f64,
+    decades: LogDecades,
     zero_point: f64,
     negative: bool,
     marker: PhantomData<V>,
@@ -180,6 +213,34 @@ impl<V: LogScalable> LogCoord<V> {
 
         (V::as_f64(&a) - V::as_f64(&b)).abs() < std::f64::EPSILON
     }
+
+    /// The bold key points when the decades are specified explicitly
+    fn explicit_key_points(&self, start: f64, end: f64) -> Option<Vec<V>> {
+        let base_ln = self.base.ln();
+        let low = (start.ln() / base_ln - 1e-9).ceil() as i32;
+        let high = (end.ln() / base_ln + 1e-9).floor() as i32;
+
+        let exponents: Vec<i32> = match &self.decades {
+            LogDecades::Auto => return None,
+            LogDecades::Step(step) => (low..=high)
+                .filter(|e| e.rem_euclid(*step as i32) == 0)
+                .collect(),
+            LogDecades::Exponents(exponents) => exponents
+                .iter()
+                .copied()
+                .filter(|e| (low..=high).contains(e))
+                .collect(),
+        };
+
+        Some(
+            exponents
+                .into_iter()
+                .map(|e| self.base.powi(e))
+                .filter(|v| !self.is_inf(*v))
+                .map(|v| self.f64_to_value(v))
+                .collect(),
+        )
+    }
 }
 
 impl<V: LogScalable> Ranged for LogCoord<V> {
@@ -192,6 +253,18 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.linear.map(&value_ln, limit)
     }
 
+    fn map_f64(&self, value: &V, limit: (i32, i32)) -> f64 {
+        let fv = self.value_to_f64(value);
+        let value_ln = fv.ln();
+        self.linear.map_f64(&value_ln, limit)
+    }
+
+    fn map_between(&self, from: &V, to: &V, ratio: f64, limit: (i32, i32)) -> f64 {
+        let (from, to) = (self.value_to_f64(from), self.value_to_f64(to));
+        let value_ln = (from + (to - from) * ratio).ln();
+        self.linear.map_f64(&value_ln, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         let max_points = hint.max_num_points();
 
@@ -204,6 +277,12 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
             std::mem::swap(&mut start, &mut end);
         }
 
+        if !hint.weight().allow_light_points() {
+            if let Some(points) = self.explicit_key_points(start, end) {
+                return points;
+            }
+        }
+
         let bold_count = ((end / start).ln().abs() / base_ln).floor().max(1.0) as usize;
 
         let light_density = if max_points < bold_count {
@@ -281,4 +360,21 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_decades() {
+        use crate::coord::ranged1d::BoldPoints;
+
+        let range: LogCoord<f64> = (1.0..1e7).log_scale().decade_step(3).into();
+        assert_eq!(range.key_points(BoldPoints(100)), vec![1.0, 1e3, 1e6]);
+
+        let range: LogCoord<f64> = (0.01..1e7)
+            .log_scale()
+            .decade_exponents([6, 0, 9, -3, 3])
+            .into();
+        assert_eq!(range.key_points(BoldPoints(100)), vec![1.0, 1e3, 1e6]);
+
+        // The light points still fill in between
+        assert!(range.key_points(100).contains(&1e2));
+    }
 }
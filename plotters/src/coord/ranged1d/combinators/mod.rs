@@ -18,3 +18,6 @@ pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
 mod partial_axis;
 pub use partial_axis::{make_partial_axis, IntoPartialAxis};
+
+mod reversed;
+pub use reversed::{IntoReversedAxis, ReversedRange};
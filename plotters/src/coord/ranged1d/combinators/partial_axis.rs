@@ -36,6 +36,20 @@ where
         self.0.map(value, limit)
     }
 
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.0.map_f64(value, limit)
+    }
+
+    fn map_between(
+        &self,
+        from: &Self::ValueType,
+        to: &Self::ValueType,
+        ratio: f64,
+        limit: (i32, i32),
+    ) -> f64 {
+        self.0.map_between(from, to, ratio, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         self.0.key_points(hint)
     }
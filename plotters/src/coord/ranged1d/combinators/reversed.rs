@@ -0,0 +1,119 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use std::ops::Range;
+
+/// The axis decorator that reverses the direction of the axis, such as a Y axis increasing
+/// downward for a depth profile.
+///
+/// Only the pixel mapping is flipped, the range, the key points and the formatting of the values
+/// are the same as the inner coordinate spec.
+#[derive(Clone)]
+pub struct ReversedRange<R: Ranged>(R);
+
+impl<R: Ranged> ReversedRange<R> {
+    /// Get the inner coordinate spec, which maps the values in the original direction
+    pub fn inner(&self) -> &R {
+        &self.0
+    }
+}
+
+/// The trait for the types that can be converted into a reversed axis
+pub trait IntoReversedAxis: AsRangedCoord {
+    /// Make the reversed axis
+    ///
+    /// - **returns**: The converted range specification
+    fn reversed(self) -> ReversedRange<Self::CoordDescType> {
+        ReversedRange(self.into())
+    }
+}
+
+impl<R: AsRangedCoord> IntoReversedAxis for R {}
+
+impl<R: Ranged> Ranged for ReversedRange<R> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.0.map(value, (limit.1, limit.0))
+    }
+
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.0.map_f64(value, (limit.1, limit.0))
+    }
+
+    fn map_between(
+        &self,
+        from: &Self::ValueType,
+        to: &Self::ValueType,
+        ratio: f64,
+        limit: (i32, i32),
+    ) -> f64 {
+        self.0.map_between(from, to, ratio, (limit.1, limit.0))
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        self.0.key_points(hint)
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.0.axis_pixel_range((limit.1, limit.0))
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for ReversedRange<R> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.0.unmap(input, (limit.1, limit.0))
+    }
+}
+
+impl<R: Ranged> ValueFormatter<R::ValueType> for ReversedRange<R>
+where
+    R: ValueFormatter<R::ValueType>,
+{
+    fn format(value: &R::ValueType) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &R::ValueType) -> String {
+        self.0.format_ext(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::cartesian::Cartesian2d;
+    use crate::coord::types::RangedCoordi32;
+    use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+    #[test]
+    fn test_reversed_range() {
+        let r = (0i32..100).reversed();
+        assert_eq!(r.range(), 0..100);
+        assert_eq!(r.key_points(5), r.inner().key_points(5));
+        assert_eq!(r.map(&0, (0, 100)), 100);
+        assert_eq!(r.map(&25, (0, 100)), 75);
+        assert_eq!(r.unmap(75, (0, 100)), Some(25));
+        assert_eq!(r.axis_pixel_range((0, 100)), 0..100);
+        assert_eq!(r.format_ext(&42), "42");
+
+        let r = (0.0..10.0).reversed();
+        assert_eq!(r.map_f64(&2.5, (0, 100)), 75.0);
+        assert_eq!(r.unmap(100, (0, 100)), Some(0.0));
+    }
+
+    #[test]
+    fn test_reversed_cartesian() {
+        let (top, bottom) = (0, 100);
+        let coord: Cartesian2d<RangedCoordi32, ReversedRange<RangedCoordi32>> =
+            Cartesian2d::new(0..10, (0..100).reversed(), (0..10, bottom..top));
+        assert_eq!(coord.translate(&(0, 0)), (0, 0));
+        assert_eq!(coord.translate(&(10, 100)), (10, 100));
+        assert_eq!(coord.reverse_translate((5, 30)), Some((5, 30)));
+    }
+}
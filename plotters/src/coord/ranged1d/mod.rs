@@ -201,6 +201,33 @@ pub trait Ranged {
     /// This function maps the value to i32, which is the drawing coordinate
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32;
 
+    /// This function maps the value to the exact drawing coordinate, without rounding it to a
+    /// pixel. It's used by the coordinate systems which round the mapped coordinate to the
+    /// nearest pixel, see [`PixelRounding`](../cartesian/enum.PixelRounding.html).
+    /// A non-finite value is mapped to NaN. By default, this falls back to [`Ranged::map`].
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        match self.map(value, limit) {
+            crate::coord::NON_FINITE_COORD => f64::NAN,
+            coord => f64::from(coord),
+        }
+    }
+
+    /// This function maps the value which is `ratio` of the way from `from` to `to` to the exact
+    /// drawing coordinate. It's used to subdivide the interval between two key points, such as
+    /// the minor grid lines. By default, the interval is subdivided evenly in the drawing
+    /// coordinate, and the coordinates which aren't linear, such as the logarithmic coordinate,
+    /// subdivide the interval evenly in the value instead.
+    fn map_between(
+        &self,
+        from: &Self::ValueType,
+        to: &Self::ValueType,
+        ratio: f64,
+        limit: (i32, i32),
+    ) -> f64 {
+        let (from, to) = (self.map_f64(from, limit), self.map_f64(to, limit));
+        from + (to - from) * ratio
+    }
+
     /// This function gives the key points that we can draw a grid based on this
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType>;
 
@@ -0,0 +1,150 @@
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use std::fmt::Display;
+use std::ops::Range;
+
+/**
+A coordinate of named categories, such as the keys of a bar chart, which are placed on the axis
+in the given order.
+
+The axis is divided into evenly spaced bands, one for each category, and a category is mapped to
+the center of its band. All the categories are the key points, and they are labelled with their
+`Display` formatting. A value which isn't one of the categories is mapped to the start of the axis.
+An empty list of categories makes an empty axis, which has no key points.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("category.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .set_left_and_bottom_label_area_size(30)
+    .build_cartesian_2d(RangedCategory::new(vec!["apple", "banana", "cherry"]), 0..5)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(PointSeries::of_element(
+        vec![("apple", 3), ("banana", 1), ("cherry", 4)],
+        5,
+        RED.filled(),
+        &|coord, size, style| Circle::new(coord, size, style),
+    ))
+    .unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct RangedCategory<T> {
+    categories: Vec<T>,
+}
+
+impl<T> RangedCategory<T> {
+    /**
+    Creates the coordinate of the categories.
+
+    - `categories`: The categories in the order they are placed on the axis
+    - Returns: The newly created coordinate
+
+    See [`RangedCategory`] for more information and examples.
+    */
+    pub fn new<C: Into<Vec<T>>>(categories: C) -> Self {
+        Self {
+            categories: categories.into(),
+        }
+    }
+
+    /// Get the categories in the order they are placed on the axis
+    pub fn categories(&self) -> &[T] {
+        &self.categories
+    }
+}
+
+impl<T> From<Vec<T>> for RangedCategory<T> {
+    fn from(categories: Vec<T>) -> Self {
+        Self::new(categories)
+    }
+}
+
+impl<T: Clone + Eq + Default> Ranged for RangedCategory<T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        match (self.categories.first(), self.categories.last()) {
+            (Some(first), Some(last)) => first.clone()..last.clone(),
+            _ => T::default()..T::default(),
+        }
+    }
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        self.map_f64(value, limit).round() as i32
+    }
+
+    fn map_f64(&self, value: &T, limit: (i32, i32)) -> f64 {
+        match self.index_of(value) {
+            Some(idx) => {
+                let bands = self.categories.len() as f64;
+                f64::from(limit.0) + f64::from(limit.1 - limit.0) * ((idx as f64 + 0.5) / bands)
+            }
+            None => f64::from(limit.0),
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T> {
+        if hint.max_num_points() == 0 {
+            return vec![];
+        }
+        self.categories.clone()
+    }
+}
+
+impl<T: Clone + Eq + Default> DiscreteRanged for RangedCategory<T> {
+    fn size(&self) -> usize {
+        self.categories.len()
+    }
+
+    fn index_of(&self, value: &T) -> Option<usize> {
+        self.categories.iter().position(|c| c == value)
+    }
+
+    fn from_index(&self, index: usize) -> Option<T> {
+        self.categories.get(index).cloned()
+    }
+}
+
+impl<T: Display> ValueFormatter<T> for RangedCategory<T> {
+    fn format(value: &T) -> String {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::ReversibleRanged;
+
+    #[test]
+    fn test_ranged_category() {
+        let coord = RangedCategory::new(vec!["apple", "banana", "cherry", "durian"]);
+
+        assert_eq!(coord.range(), "apple".."durian");
+        assert_eq!(coord.map(&"apple", (0, 100)), 13);
+        assert_eq!(coord.map(&"durian", (0, 100)), 88);
+        assert_eq!(coord.map(&"elderberry", (0, 100)), 0);
+        assert_eq!(coord.unmap(30, (0, 100)), Some("banana"));
+        assert_eq!(
+            coord.key_points(2),
+            vec!["apple", "banana", "cherry", "durian"]
+        );
+        assert_eq!(coord.format_ext(&"cherry"), "cherry");
+        assert_eq!(coord.size(), 4);
+        assert_eq!(coord.from_index(4), None);
+
+        let empty: RangedCategory<String> = RangedCategory::new(vec![]);
+        assert_eq!(empty.range(), String::new()..String::new());
+        assert_eq!(empty.map(&"apple".to_string(), (0, 100)), 0);
+        assert!(empty.key_points(10).is_empty());
+        assert_eq!(empty.unmap(50, (0, 100)), None);
+    }
+}
@@ -5,11 +5,17 @@ pub use datetime::{
     IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
 };
 
+mod category;
+pub use category::RangedCategory;
+
 mod numeric;
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
     RangedCoordu128, RangedCoordu32, RangedCoordu64, RangedCoordusize,
 };
 
+mod sessions;
+pub use sessions::RangedSessions;
+
 mod slice;
 pub use slice::RangedSlice;
@@ -5,6 +5,7 @@ use crate::coord::ranged1d::{
     AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
     ReversibleRanged, ValueFormatter,
 };
+use crate::coord::{saturating_backend_coord, NON_FINITE_COORD};
 
 macro_rules! impl_discrete_trait {
     ($name:ident) => {
@@ -73,6 +74,11 @@ macro_rules! make_numeric_coord {
             type ValueType = $type;
             #[allow(clippy::float_cmp)]
             fn map(&self, v: &$type, limit: (i32, i32)) -> i32 {
+                // A non-finite value, like NaN or infinity, can't be mapped to any pixel
+                if !(*v as f64).is_finite() {
+                    return NON_FINITE_COORD;
+                }
+
                 // Corner case: If we have a range that have only one value,
                 // then we just assign everything to the only point
                 if self.1 == self.0 {
@@ -87,11 +93,28 @@ macro_rules! make_numeric_coord {
                     return limit.1;
                 }
 
-                if actual_length > 0 {
-                    return limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32;
+                // The offset is added in floating point and then saturated, so that a value far
+                // outside of the range doesn't overflow the pixel coordinate
+                let offset = if actual_length > 0 {
+                    (actual_length as f64 * logic_length + 1e-3).floor()
                 } else {
-                    return limit.0 + (actual_length as f64 * logic_length - 1e-3).ceil() as i32;
+                    (actual_length as f64 * logic_length - 1e-3).ceil()
+                };
+                saturating_backend_coord(limit.0 as f64 + offset)
+            }
+            #[allow(clippy::float_cmp)]
+            fn map_f64(&self, v: &$type, limit: (i32, i32)) -> f64 {
+                if !(*v as f64).is_finite() {
+                    return f64::NAN;
+                }
+
+                if self.1 == self.0 {
+                    return f64::from((limit.1 - limit.0) / 2);
                 }
+
+                let logic_length = (*v as f64 - self.0 as f64) / (self.1 as f64 - self.0 as f64);
+
+                limit.0 as f64 + f64::from(limit.1 - limit.0) * logic_length
             }
             fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<$type> {
                 $key_points((self.0, self.1), hint.max_num_points())
@@ -386,6 +409,22 @@ mod test {
             );
     }
 
+    #[test]
+    fn test_extreme_value_map() {
+        let coord: RangedCoordf64 = (0.0..1.0).into();
+        assert_eq!(coord.map(&1e15, (10, 200)), 1 << 30);
+        assert_eq!(coord.map(&-1e300, (10, 200)), -(1 << 30));
+        assert_eq!(coord.map(&1e15, (200, 10)), -(1 << 30));
+
+        let coord: RangedCoordi64 = (0..1).into();
+        assert_eq!(coord.map(&i64::MAX, (0, 100)), 1 << 30);
+
+        // A tiny value on a narrow log scale is mapped far below the pixel range
+        use crate::coord::combinators::{IntoLogRange, LogCoord};
+        let coord: LogCoord<f64> = (1.0..1.00001).log_scale().into();
+        assert_eq!(coord.map(&1e-300, (0, 300)), -(1 << 30));
+    }
+
     #[test]
     fn test_coord_unmap() {
         let coord: RangedCoordu32 = (0..20).into();
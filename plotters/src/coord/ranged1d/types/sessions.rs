@@ -0,0 +1,144 @@
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use std::fmt::Display;
+use std::ops::Range;
+
+/**
+A coordinate which maps a list of sessions, such as the trading days of a market, to evenly
+spaced slots, regardless of the calendar time between them.
+
+The value of the coordinate is the index of the session, and the labels are formatted as the
+session itself. This removes the gaps of the weekends and the holidays from a financial chart,
+which a continuous date axis would show. Each session takes one slot of the axis, and the index
+is mapped to the center of the slot.
+
+# Example
+
+```
+use chrono::NaiveDate;
+use plotters::prelude::*;
+let sessions: Vec<_> = [3, 4, 5, 8, 9]
+    .iter()
+    .map(|&d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap())
+    .collect();
+let prices = [(10.0, 12.0, 9.5, 11.0), (11.0, 11.5, 10.0, 10.5), (10.5, 13.0, 10.5, 12.5),
+    (12.5, 12.8, 11.0, 11.2), (11.2, 12.0, 11.0, 11.8)];
+let drawing_area = SVGBackend::new("sessions.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .set_left_and_bottom_label_area_size(30)
+    .build_cartesian_2d(RangedSessions::new(sessions), 9.0..14.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(prices.iter().enumerate().map(|(idx, &(open, high, low, close))| {
+        CandleStick::new(idx, open, high, low, close, GREEN.filled(), RED.filled(), 10)
+    }))
+    .unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct RangedSessions<T> {
+    sessions: Vec<T>,
+}
+
+impl<T> RangedSessions<T> {
+    /**
+    Creates the coordinate of the sessions.
+
+    - `sessions`: The sessions in the order they are placed on the axis
+    - Returns: The newly created coordinate
+
+    See [`RangedSessions`] for more information and examples.
+    */
+    pub fn new<S: Into<Vec<T>>>(sessions: S) -> Self {
+        Self {
+            sessions: sessions.into(),
+        }
+    }
+
+    /// Get the session at the index, if the index is in the range
+    pub fn session(&self, index: usize) -> Option<&T> {
+        self.sessions.get(index)
+    }
+
+    /// Find the index of the session, which is the value to draw the data of the session with
+    pub fn session_index(&self, session: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.sessions.iter().position(|s| s == session)
+    }
+}
+
+impl<T> Ranged for RangedSessions<T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = usize;
+
+    fn range(&self) -> Range<usize> {
+        0..self.sessions.len()
+    }
+
+    fn map(&self, value: &usize, limit: (i32, i32)) -> i32 {
+        self.map_f64(value, limit) as i32
+    }
+
+    fn map_f64(&self, value: &usize, limit: (i32, i32)) -> f64 {
+        let slots = self.sessions.len().max(1) as f64;
+        f64::from(limit.0) + f64::from(limit.1 - limit.0) * ((*value as f64 + 0.5) / slots)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<usize> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = (self.sessions.len() + max_points - 1) / max_points;
+        (0..self.sessions.len()).step_by(step.max(1)).collect()
+    }
+}
+
+impl<T> DiscreteRanged for RangedSessions<T> {
+    fn size(&self) -> usize {
+        self.sessions.len()
+    }
+
+    fn index_of(&self, value: &usize) -> Option<usize> {
+        Some(*value).filter(|idx| *idx < self.sessions.len())
+    }
+
+    fn from_index(&self, index: usize) -> Option<usize> {
+        Some(index).filter(|idx| *idx < self.sessions.len())
+    }
+}
+
+impl<T: Display> ValueFormatter<usize> for RangedSessions<T> {
+    fn format_ext(&self, value: &usize) -> String {
+        self.session(*value)
+            .map(|session| session.to_string())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::ReversibleRanged;
+
+    #[test]
+    fn test_ranged_sessions() {
+        let coord = RangedSessions::new(vec!["Mon", "Tue", "Wed", "Thu", "Fri"]);
+
+        assert_eq!(coord.range(), 0..5);
+        assert_eq!(coord.map(&0, (0, 100)), 10);
+        assert_eq!(coord.map(&4, (0, 100)), 90);
+        assert_eq!(coord.unmap(15, (0, 100)), Some(0));
+        assert_eq!(coord.unmap(95, (0, 100)), Some(4));
+        assert_eq!(coord.key_points(5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(coord.key_points(2), vec![0, 3]);
+        assert_eq!(coord.format_ext(&1), "Tue");
+        assert_eq!(coord.session_index(&"Thu"), Some(3));
+        assert_eq!(coord.from_index(5), None);
+    }
+}
@@ -7,14 +7,32 @@
  This types of coordinate system is used by the chart constructed with [ChartBuilder::build_cartesian_2d](../../chart/ChartBuilder.html#method.build_cartesian_2d).
 */
 
-use crate::coord::ranged1d::{KeyPointHint, Ranged, ReversibleRanged};
-use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+use crate::coord::ranged1d::{BoldPoints, KeyPointHint, Ranged, ReversibleRanged};
+use crate::coord::{
+    saturating_backend_coord, CoordTranslate, ReverseCoordTranslate, NON_FINITE_COORD,
+};
 
 use crate::style::ShapeStyle;
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use std::ops::Range;
 
+/// How a mapped coordinate is rounded to a pixel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelRounding {
+    /// Use the mapping of the 1D coordinate specs, which truncates the mapped coordinate
+    Truncate,
+    /// Round the mapped coordinate to the nearest pixel, which avoids the systematic half
+    /// pixel offset of the truncation
+    Round,
+}
+
+impl Default for PixelRounding {
+    fn default() -> Self {
+        PixelRounding::Truncate
+    }
+}
+
 /// A 2D Cartesian coordinate system described by two 1D ranged coordinate specs.
 #[derive(Clone)]
 pub struct Cartesian2d<X: Ranged, Y: Ranged> {
@@ -22,6 +40,7 @@ pub struct Cartesian2d<X: Ranged, Y: Ranged> {
     logic_y: Y,
     back_x: (i32, i32),
     back_y: (i32, i32),
+    rounding: PixelRounding,
 }
 
 impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
@@ -38,9 +57,21 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
             logic_y: logic_y.into(),
             back_x: (actual.0.start, actual.0.end),
             back_y: (actual.1.start, actual.1.end),
+            rounding: PixelRounding::default(),
         }
     }
 
+    /// Set how the mapped coordinates are rounded to pixels
+    /// - `rounding`: The rounding mode, [`PixelRounding::Truncate`] by default
+    pub fn set_pixel_rounding(&mut self, rounding: PixelRounding) {
+        self.rounding = rounding;
+    }
+
+    /// Get how the mapped coordinates are rounded to pixels
+    pub fn pixel_rounding(&self) -> PixelRounding {
+        self.rounding
+    }
+
     /// Draw the mesh for the coordinate system
     pub fn draw_mesh<
         E,
@@ -59,7 +90,7 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         );
 
         for logic_x in xkp {
-            let x = self.logic_x.map(&logic_x, self.back_x);
+            let x = self.map_x(&logic_x);
             draw_mesh(MeshLine::XMesh(
                 (x, self.back_y.0),
                 (x, self.back_y.1),
@@ -68,7 +99,7 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         }
 
         for logic_y in ykp {
-            let y = self.logic_y.map(&logic_y, self.back_y);
+            let y = self.map_y(&logic_y);
             draw_mesh(MeshLine::YMesh(
                 (self.back_x.0, y),
                 (self.back_x.1, y),
@@ -79,6 +110,35 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         Ok(())
     }
 
+    /// Find the minor mesh lines, which subdivide each interval between two consecutive bold
+    /// mesh lines evenly into the given number of segments. The lines outside of the axis are
+    /// skipped, and each line is tagged with whether it's an X mesh line.
+    /// - `bold`: The maximum number of the bold mesh lines in Y and X
+    /// - `minor`: The number of segments of each interval in X and Y, 0 for no minor lines
+    pub(crate) fn minor_mesh_lines(
+        &self,
+        (y_bold, x_bold): (usize, usize),
+        (x_minor, y_minor): (usize, usize),
+    ) -> Vec<(bool, BackendCoord, BackendCoord)> {
+        let mut lines = vec![];
+
+        let x_axis = self.get_x_axis_pixel_range();
+        for x in minor_points(&self.logic_x, x_bold, x_minor, self.back_x, self.rounding) {
+            if x_axis.start <= x && x <= x_axis.end {
+                lines.push((true, (x, self.back_y.0), (x, self.back_y.1)));
+            }
+        }
+
+        let y_axis = self.get_y_axis_pixel_range();
+        for y in minor_points(&self.logic_y, y_bold, y_minor, self.back_y, self.rounding) {
+            if y_axis.start <= y && y <= y_axis.end {
+                lines.push((false, (self.back_x.0, y), (self.back_x.1, y)));
+            }
+        }
+
+        lines
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -99,6 +159,32 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Map a X value to the horizontal backend coordinate
+    pub(crate) fn map_x(&self, x: &X::ValueType) -> i32 {
+        match self.rounding {
+            PixelRounding::Truncate => self.logic_x.map(x, self.back_x),
+            PixelRounding::Round => round_coord(self.logic_x.map_f64(x, self.back_x)),
+        }
+    }
+
+    /// Map a Y value to the vertical backend coordinate
+    pub(crate) fn map_y(&self, y: &Y::ValueType) -> i32 {
+        match self.rounding {
+            PixelRounding::Truncate => self.logic_y.map(y, self.back_y),
+            PixelRounding::Round => round_coord(self.logic_y.map_f64(y, self.back_y)),
+        }
+    }
+
+    /// Replace the 1D coordinate spec for X axis, the pixel range is kept unchanged
+    pub fn set_x_spec<IntoX: Into<X>>(&mut self, logic_x: IntoX) {
+        self.logic_x = logic_x.into();
+    }
+
+    /// Replace the 1D coordinate spec for Y axis, the pixel range is kept unchanged
+    pub fn set_y_spec<IntoY: Into<Y>>(&mut self, logic_y: IntoY) {
+        self.logic_y = logic_y.into();
+    }
+
     /// Get the 1D coordinate spec for X axis
     pub fn x_spec(&self) -> &X {
         &self.logic_x
@@ -114,10 +200,104 @@ impl<X: Ranged, Y: Ranged> CoordTranslate for Cartesian2d<X, Y> {
     type From = (X::ValueType, Y::ValueType);
 
     fn translate(&self, from: &Self::From) -> BackendCoord {
-        (
-            self.logic_x.map(&from.0, self.back_x),
-            self.logic_y.map(&from.1, self.back_y),
-        )
+        (self.map_x(&from.0), self.map_y(&from.1))
+    }
+}
+
+/// Find the pixels of the points which subdivide each interval between two consecutive bold key
+/// points of a 1D coordinate into `segments` segments.
+///
+/// Before the first and after the last key point, the subdivision of the first and the last
+/// interval is repeated up to the ends of the pixel range. This keeps the spacing of the
+/// logarithmic coordinates as well, since it's the same for every decade in pixels. When there's
+/// a single key point, the key points are looked up with more points until there are two.
+fn minor_points<R: Ranged>(
+    logic: &R,
+    bold: usize,
+    segments: usize,
+    limit: (i32, i32),
+    rounding: PixelRounding,
+) -> Vec<i32> {
+    if segments < 2 {
+        return vec![];
+    }
+    let span = f64::from(limit.0).max(f64::from(limit.1)) - f64::from(limit.0.min(limit.1));
+    let mut hint = bold.max(1);
+    let mut key_points = logic.key_points(BoldPoints(hint));
+    while key_points.len() < 2 && (hint as f64) < span {
+        hint *= 2;
+        key_points = logic.key_points(BoldPoints(hint));
+    }
+    if key_points.len() < 2 {
+        return vec![];
+    }
+
+    let subdivide = |from: &R::ValueType, to: &R::ValueType| -> Vec<f64> {
+        (1..segments)
+            .map(|idx| logic.map_between(from, to, idx as f64 / segments as f64, limit))
+            .collect()
+    };
+    let last = key_points.len() - 1;
+
+    // The first interval, which is repeated backward from the first key point
+    let head_from = logic.map_f64(&key_points[0], limit);
+    let head_period = logic.map_f64(&key_points[1], limit) - head_from;
+    let mut head = vec![head_from];
+    head.extend(subdivide(&key_points[0], &key_points[1]));
+    let head = repeat_pattern(&head, -head_period, span);
+
+    // The last interval, which is repeated forward from the last key point
+    let tail_to = logic.map_f64(&key_points[last], limit);
+    let tail_period = tail_to - logic.map_f64(&key_points[last - 1], limit);
+    let mut tail = subdivide(&key_points[last - 1], &key_points[last]);
+    tail.push(tail_to);
+    let tail = repeat_pattern(&tail, tail_period, span);
+
+    let mut exact = head;
+    for (from, to) in key_points.iter().zip(key_points.iter().skip(1)) {
+        exact.extend(subdivide(from, to));
+    }
+    exact.extend(tail);
+
+    let (lo, hi) = (limit.0.min(limit.1), limit.0.max(limit.1));
+    let mut ret = vec![];
+    for exact in exact {
+        let coord = match rounding {
+            PixelRounding::Truncate if exact.is_finite() => saturating_backend_coord(exact),
+            PixelRounding::Truncate => NON_FINITE_COORD,
+            PixelRounding::Round => round_coord(exact),
+        };
+        if coord != NON_FINITE_COORD && lo <= coord && coord <= hi {
+            ret.push(coord);
+        }
+    }
+    ret
+}
+
+/// Shift a pattern of exact coordinates by `period` repeatedly, until it has covered `span`
+/// pixels. The repeated patterns are ordered the same way as the key points.
+fn repeat_pattern(pattern: &[f64], period: f64, span: f64) -> Vec<f64> {
+    if !period.is_finite() || period.abs() < 1.0 {
+        return vec![];
+    }
+    let count = (span / period.abs()).ceil() as usize + 1;
+    let shifts: Vec<usize> = if period < 0.0 {
+        (1..=count).rev().collect()
+    } else {
+        (1..=count).collect()
+    };
+    shifts
+        .into_iter()
+        .flat_map(|n| pattern.iter().map(move |p| p + period * n as f64))
+        .collect()
+}
+
+/// Round an exact mapped coordinate to the nearest pixel
+fn round_coord(exact: f64) -> i32 {
+    if exact.is_finite() {
+        saturating_backend_coord(exact.round())
+    } else {
+        NON_FINITE_COORD
     }
 }
 
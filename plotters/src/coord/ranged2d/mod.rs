@@ -1 +1,2 @@
 pub mod cartesian;
+pub mod polar;
@@ -0,0 +1,196 @@
+/*!
+ The 2-dimensional polar coordinate system.
+
+ This module provides the polar coordinate system, which is composed by a ranged 1D coordinate
+ specification for the radius and another one for the angle. This is useful for radar charts and
+ the other polar plots.
+
+ This types of coordinate system is used by the chart constructed with [ChartBuilder::build_polar](../../chart/ChartBuilder.html#method.build_polar).
+*/
+
+use crate::coord::ranged1d::{Ranged, ReversibleRanged};
+use crate::coord::{
+    saturating_backend_coord, CoordTranslate, ReverseCoordTranslate, NON_FINITE_COORD,
+};
+
+use plotters_backend::BackendCoord;
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+/// The number of steps that a full turn is divided into when the angle spec is mapped
+const ANGLE_RESOLUTION: i32 = 1 << 24;
+
+/// A polar coordinate system described by two 1D ranged coordinate specs.
+///
+/// The radius range is mapped from the center pixel to the pixel radius, and the values below the
+/// range are mapped to the center. The angle range is mapped to a full turn clockwise, starting
+/// from the top by default, and the values out of the range wrap around, thus the start and the
+/// end of the angle range are mapped to the same direction.
+#[derive(Clone)]
+pub struct Polar<R: Ranged, A: Ranged> {
+    logic_r: R,
+    logic_a: A,
+    center: BackendCoord,
+    radius: u32,
+    start_angle: f64,
+}
+
+impl<R: Ranged, A: Ranged> Polar<R, A> {
+    /// Create a new polar coordinate system
+    /// - `logic_r` and `logic_a`: The description for the radius and the angle
+    /// - `center`: The pixel of the center on the screen
+    /// - `radius`: The pixel radius which the end of the radius range is mapped to
+    pub fn new<IntoR: Into<R>, IntoA: Into<A>>(
+        logic_r: IntoR,
+        logic_a: IntoA,
+        center: BackendCoord,
+        radius: u32,
+    ) -> Self {
+        Self {
+            logic_r: logic_r.into(),
+            logic_a: logic_a.into(),
+            center,
+            radius,
+            start_angle: -PI / 2.0,
+        }
+    }
+
+    /// Set the direction which the start of the angle range is mapped to
+    /// - `angle`: The angle in radians, measured clockwise from the positive X axis on the screen,
+    ///   `-PI / 2`, the top, by default
+    pub fn set_start_angle(&mut self, angle: f64) {
+        self.start_angle = angle;
+    }
+
+    /// Get the direction which the start of the angle range is mapped to, in radians
+    pub fn start_angle(&self) -> f64 {
+        self.start_angle
+    }
+
+    /// Get the pixel of the center
+    pub fn center(&self) -> BackendCoord {
+        self.center
+    }
+
+    /// Get the pixel radius which the end of the radius range is mapped to
+    pub fn pixel_radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Get the range of the radius
+    pub fn get_radius_range(&self) -> Range<R::ValueType> {
+        self.logic_r.range()
+    }
+
+    /// Get the range of the angle
+    pub fn get_angle_range(&self) -> Range<A::ValueType> {
+        self.logic_a.range()
+    }
+
+    /// Get the 1D coordinate spec for the radius
+    pub fn r_spec(&self) -> &R {
+        &self.logic_r
+    }
+
+    /// Get the 1D coordinate spec for the angle
+    pub fn a_spec(&self) -> &A {
+        &self.logic_a
+    }
+
+    /// Map an angle value to the direction on the screen, in radians
+    fn map_angle(&self, a: &A::ValueType) -> f64 {
+        let turn = self.logic_a.map_f64(a, (0, ANGLE_RESOLUTION)) / f64::from(ANGLE_RESOLUTION);
+        self.start_angle + turn.rem_euclid(1.0) * 2.0 * PI
+    }
+}
+
+impl<R: Ranged, A: Ranged> CoordTranslate for Polar<R, A> {
+    type From = (R::ValueType, A::ValueType);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let r = self.logic_r.map_f64(&from.0, (0, self.radius as i32));
+        let theta = self.map_angle(&from.1);
+        if !r.is_finite() || !theta.is_finite() {
+            return (NON_FINITE_COORD, NON_FINITE_COORD);
+        }
+        if r <= 0.0 {
+            return self.center;
+        }
+        (
+            saturating_backend_coord((f64::from(self.center.0) + r * theta.cos()).round()),
+            saturating_backend_coord((f64::from(self.center.1) + r * theta.sin()).round()),
+        )
+    }
+}
+
+impl<R: ReversibleRanged, A: ReversibleRanged> ReverseCoordTranslate for Polar<R, A> {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let (dx, dy) = (
+            f64::from(input.0 - self.center.0),
+            f64::from(input.1 - self.center.1),
+        );
+        let r = dx.hypot(dy).round() as i32;
+        let turn = ((dy.atan2(dx) - self.start_angle) / (2.0 * PI)).rem_euclid(1.0);
+        let a = ((turn * f64::from(ANGLE_RESOLUTION)).round() as i32) % ANGLE_RESOLUTION;
+        Some((
+            self.logic_r.unmap(r, (0, self.radius as i32))?,
+            self.logic_a.unmap(a, (0, ANGLE_RESOLUTION))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::types::RangedCoordf64;
+
+    #[test]
+    fn test_polar_translate() {
+        let coord: Polar<RangedCoordf64, RangedCoordf64> =
+            Polar::new(0.0..10.0, 0.0..360.0, (100, 100), 50);
+
+        assert_eq!(coord.translate(&(10.0, 0.0)), (100, 50));
+        assert_eq!(coord.translate(&(10.0, 90.0)), (150, 100));
+        assert_eq!(coord.translate(&(5.0, 180.0)), (100, 125));
+        assert_eq!(coord.translate(&(10.0, 270.0)), (50, 100));
+        // The angles wrap around at the end of the angle range
+        assert_eq!(coord.translate(&(10.0, 360.0)), (100, 50));
+        assert_eq!(coord.translate(&(10.0, 450.0)), (150, 100));
+        assert_eq!(coord.translate(&(10.0, -90.0)), (50, 100));
+        for angle in 0..36 {
+            assert_eq!(coord.translate(&(0.0, angle as f64 * 10.0)), (100, 100));
+        }
+        assert_eq!(coord.translate(&(-1.0, 45.0)), (100, 100));
+        assert_eq!(coord.translate(&(f64::NAN, 45.0)).0, NON_FINITE_COORD);
+    }
+
+    #[test]
+    fn test_polar_start_angle() {
+        let mut coord: Polar<RangedCoordf64, RangedCoordf64> =
+            Polar::new(0.0..1.0, 0.0..4.0, (10, 10), 10);
+        coord.set_start_angle(0.0);
+        assert_eq!(coord.translate(&(1.0, 0.0)), (20, 10));
+        assert_eq!(coord.translate(&(1.0, 1.0)), (10, 20));
+    }
+
+    #[test]
+    fn test_polar_reverse_translate() {
+        let coord: Polar<RangedCoordf64, RangedCoordf64> =
+            Polar::new(0.0..10.0, 0.0..360.0, (100, 100), 50);
+
+        let (r, a) = coord.reverse_translate((150, 100)).unwrap();
+        assert!((r - 10.0).abs() < 1e-6);
+        assert!((a - 90.0).abs() < 1e-3);
+
+        let (r, a) = coord.reverse_translate((100, 75)).unwrap();
+        assert!((r - 5.0).abs() < 1e-6);
+        assert!(a.abs() < 1e-3);
+
+        let (_, a) = coord.reverse_translate((99, 60)).unwrap();
+        assert!(a > 358.0 && a < 360.0);
+
+        assert!(coord.reverse_translate((100, 100)).is_some());
+        assert!(coord.reverse_translate((200, 100)).is_none());
+    }
+}
@@ -123,6 +123,30 @@ pub fn pretty_print_float(n: f64, allow_sn: bool) -> String {
     .print(n)
 }
 
+/// Remove the trailing zeros of the fractional part of a formatted number, and the decimal
+/// point as well when there's no fractional digit left. For example, `"1.50"` becomes `"1.5"`,
+/// `"2.00"` becomes `"2"` and `"1.50e3"` becomes `"1.5e3"`.
+///
+/// The text is returned unchanged when it's not a number, so that it's safe to apply to the
+/// output of any label formatter.
+///
+/// - `repr`: The formatted number
+/// - **returns**: The formatted number without trailing zeros
+pub fn trim_trailing_zeros(repr: &str) -> String {
+    if repr.parse::<f64>().is_err() {
+        return repr.to_string();
+    }
+    let (mantissa, exp) = match repr.find(|c| c == 'e' || c == 'E') {
+        Some(idx) => repr.split_at(idx),
+        None => (repr, ""),
+    };
+    if !mantissa.contains('.') {
+        return repr.to_string();
+    }
+    let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", mantissa, exp)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -142,4 +166,16 @@ mod test {
         assert_eq!(pretty_print_float(1234567890f64, true), "1234567890");
         assert_eq!(pretty_print_float(1000000001f64, true), "1e9");
     }
+
+    #[test]
+    fn test_trim_trailing_zeros() {
+        assert_eq!(trim_trailing_zeros("1.50"), "1.5");
+        assert_eq!(trim_trailing_zeros("2.00"), "2");
+        assert_eq!(trim_trailing_zeros("2."), "2");
+        assert_eq!(trim_trailing_zeros("-0.250"), "-0.25");
+        assert_eq!(trim_trailing_zeros("100"), "100");
+        assert_eq!(trim_trailing_zeros("1.50e-3"), "1.5e-3");
+        assert_eq!(trim_trailing_zeros("1.0e10"), "1e10");
+        assert_eq!(trim_trailing_zeros("10.00 kg"), "10.00 kg");
+    }
 }
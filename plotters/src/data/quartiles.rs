@@ -6,6 +6,7 @@ pub struct Quartiles {
     median: f64,
     upper: f64,
     upper_fence: f64,
+    mean: f64,
 }
 
 impl Quartiles {
@@ -53,12 +54,14 @@ impl Quartiles {
         let iqr = upper - lower;
         let lower_fence = lower - 1.5 * iqr;
         let upper_fence = upper + 1.5 * iqr;
+        let mean = s.iter().map(|&v| v.into()).sum::<f64>() / s.len() as f64;
         Self {
             lower_fence,
             lower,
             median,
             upper,
             upper_fence,
+            mean,
         }
     }
 
@@ -96,6 +99,20 @@ impl Quartiles {
     pub fn median(&self) -> f64 {
         self.median
     }
+
+    /// Get the mean of the original values.
+    ///
+    /// - **returns** The mean
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.mean(), 29.666666666666668);
+    /// ```
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
 }
 
 #[cfg(test)]
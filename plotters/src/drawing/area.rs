@@ -1,9 +1,12 @@
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
 use crate::coord::ranged1d::{KeyPointHint, Ranged};
 use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::DirtyTrackingBackend;
+#[cfg(feature = "bitmap_backend")]
+use crate::element::BitMapElement;
 use crate::element::{CoordMapper, Drawable, PointCollection};
 use crate::style::text_anchor::{HPos, Pos, VPos};
-use crate::style::{Color, SizeDesc, TextStyle};
+use crate::style::{with_stroke_width_parent, Color, FontError, SizeDesc, TextStyle};
 
 /// The abstraction of a drawing area
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
@@ -15,8 +18,12 @@ use std::iter::{once, repeat};
 use std::ops::Range;
 use std::rc::Rc;
 
+/// How far the points of an element may go out of a clipped drawing area before they are
+/// truncated, which bounds the work of rasterizing the shapes mostly out of sight
+const CLIP_MARGIN: i32 = 4096;
+
 /// The representation of the rectangle in backend canvas
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Rect {
     x0: i32,
     y0: i32,
@@ -105,10 +112,70 @@ impl Rect {
         })
     }
 
+    /// Check if the rectangle doesn't cover any pixel
+    fn is_empty(&self) -> bool {
+        self.x1 <= self.x0 || self.y1 <= self.y0
+    }
+
+    /// Grow the rectangle by the given number of pixels on each side, saturating at the bounds
+    /// of the coordinate
+    fn grow(&self, px: i32) -> Rect {
+        Rect {
+            x0: self.x0.saturating_sub(px),
+            y0: self.y0.saturating_sub(px),
+            x1: self.x1.saturating_add(px),
+            y1: self.y1.saturating_add(px),
+        }
+    }
+
     /// Make the coordinate in the range of the rectangle
     pub fn truncate(&self, p: (i32, i32)) -> (i32, i32) {
         (p.0.min(self.x1).max(self.x0), p.1.min(self.y1).max(self.y0))
     }
+
+    /// Get the upper left corner of the rectangle, which is inclusive
+    pub fn upper_left(&self) -> BackendCoord {
+        (self.x0, self.y0)
+    }
+
+    /// Get the bottom right corner of the rectangle, which is exclusive
+    pub fn bottom_right(&self) -> BackendCoord {
+        (self.x1, self.y1)
+    }
+
+    /// Get the width and the height of the rectangle in pixels
+    pub fn size(&self) -> (u32, u32) {
+        (
+            (self.x1 - self.x0).max(0) as u32,
+            (self.y1 - self.y0).max(0) as u32,
+        )
+    }
+
+    /// Create a rectangle from its inclusive upper left corner and exclusive bottom right corner
+    pub(crate) fn from_corners((x0, y0): BackendCoord, (x1, y1): BackendCoord) -> Rect {
+        Rect { x0, y0, x1, y1 }
+    }
+
+    /// The smallest rectangle containing both rectangles
+    pub(crate) fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            x0: self.x0.min(other.x0),
+            y0: self.y0.min(other.y0),
+            x1: self.x1.max(other.x1),
+            y1: self.y1.max(other.y1),
+        }
+    }
+
+    /// The overlapping part of both rectangles, or `None` if they don't overlap
+    pub(crate) fn intersect(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect {
+            x0: self.x0.max(other.x0),
+            y0: self.y0.max(other.y0),
+            x1: self.x1.min(other.x1),
+            y1: self.y1.min(other.y1),
+        };
+        Some(rect).filter(|rect| !rect.is_empty())
+    }
 }
 
 /// The abstraction of a drawing area. Plotters uses drawing area as the fundamental abstraction for the
@@ -119,6 +186,7 @@ impl Rect {
 pub struct DrawingArea<DB: DrawingBackend, CT: CoordTranslate> {
     backend: Rc<RefCell<DB>>,
     rect: Rect,
+    scale: f64,
     coord: CT,
 }
 
@@ -126,6 +194,7 @@ impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, C
     fn clone(&self) -> Self {
         Self {
             backend: self.backend.clone(),
+            scale: self.scale,
             rect: self.rect.clone(),
             coord: self.coord.clone(),
         }
@@ -159,6 +228,33 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
 
 impl<E: Error + Send + Sync> Error for DrawingAreaErrorKind<E> {}
 
+impl<E: Error + Send + Sync> DrawingAreaErrorKind<E> {
+    /// Get the font error if this error is caused by the font backend, for example, when the
+    /// font family isn't available on the system. Returns `None` for any other error.
+    pub fn font_error(&self) -> Option<&FontError> {
+        match self {
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Describes what to do when a text doesn't fit in the drawing area, see
+/// [`DrawingArea::draw_text_fit`] and [`DrawingArea::titled_fit`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextOverflow {
+    /// Draw the text as it is, the part outside of the area may be clipped
+    Ignore,
+    /// Don't draw the text and return [`DrawingAreaErrorKind::LayoutError`]
+    Error,
+    /// Shrink the font until the text fits, but never below `min_size`.
+    /// If the text still overflows at the minimum size, it's drawn at the minimum size.
+    ShrinkToFit {
+        /// The smallest font size the text can be shrunk to
+        min_size: f64,
+    },
+}
+
 #[allow(type_alias_bounds)]
 type DrawingAreaError<T: DrawingBackend> = DrawingAreaErrorKind<T::ErrorType>;
 
@@ -186,6 +282,15 @@ impl<T: DrawingBackend> IntoDrawingArea for T {
     }
 }
 
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DirtyTrackingBackend<DB>, CT> {
+    /// Take the bounding box of everything drawn on the backend since the region was last taken
+    /// or the backend was presented, and reset it. Returns `None` if nothing was drawn.
+    /// See [`DirtyTrackingBackend`] for details.
+    pub fn take_dirty_region(&self) -> Option<Rect> {
+        self.backend.borrow_mut().take_dirty_region()
+    }
+}
+
 impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>> {
     /// Draw the mesh on a area
     pub fn draw_mesh<DrawFunc, YH: KeyPointHint, XH: KeyPointHint>(
@@ -197,6 +302,9 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>
     where
         DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
     {
+        if self.is_empty() {
+            return Ok(());
+        }
         self.backend_ops(move |b| {
             self.coord
                 .draw_mesh(y_count_max, x_count_max, |line| draw_func(b, line))
@@ -235,6 +343,7 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: Shift((self.rect.x0, self.rect.y0)),
         }
     }
@@ -244,6 +353,7 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: Shift((0, 0)),
         }
     }
@@ -251,8 +361,8 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     /// Get the area dimension in pixel
     pub fn dim_in_pixel(&self) -> (u32, u32) {
         (
-            (self.rect.x1 - self.rect.x0) as u32,
-            (self.rect.y1 - self.rect.y0) as u32,
+            (self.rect.x1 - self.rect.x0).max(0) as u32,
+            (self.rect.y1 - self.rect.y0).max(0) as u32,
         )
     }
 
@@ -271,15 +381,42 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0..self.rect.x1, self.rect.y0..self.rect.y1)
     }
 
-    /// Perform operation on the drawing backend
-    fn backend_ops<R, O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>>(
+    /// Check if the drawing area has a zero dimension, nothing is drawn on such area
+    pub fn is_empty(&self) -> bool {
+        self.rect.is_empty()
+    }
+
+    /// Scale the absolute sizes used in this drawing area, such as the font size in
+    /// `("sans-serif", 20)` and the margins of a chart, by the given factor. The relative sizes,
+    /// such as `(5).percent_height()`, aren't affected. The sub-areas inherit the scale factor,
+    /// and scaling a scaled area multiplies the factors.
+    ///
+    /// This is useful to put a dense chart in a small area without shrinking every size of it.
+    pub fn scaled(mut self, factor: f64) -> Self {
+        self.scale *= factor;
+        self
+    }
+
+    /// Get the factor the absolute sizes are scaled by in this drawing area, see
+    /// [`DrawingArea::scaled`]
+    pub fn size_scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Perform operation on the drawing backend, the relative stroke widths drawn by the
+    /// operation are resolved against this drawing area
+    pub(crate) fn backend_ops<
+        R,
+        O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>,
+    >(
         &self,
         ops: O,
     ) -> Result<R, DrawingAreaError<DB>> {
         if let Ok(mut db) = self.backend.try_borrow_mut() {
             db.ensure_prepared()
                 .map_err(DrawingAreaErrorKind::BackendError)?;
-            ops(&mut db).map_err(DrawingAreaErrorKind::BackendError)
+            with_stroke_width_parent(self, || ops(&mut db))
+                .map_err(DrawingAreaErrorKind::BackendError)
         } else {
             Err(DrawingAreaErrorKind::SharingError)
         }
@@ -287,6 +424,9 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
 
     /// Fill the entire drawing area with a color
     pub fn fill<ColorType: Color>(&self, color: &ColorType) -> Result<(), DrawingAreaError<DB>> {
+        if self.is_empty() {
+            return Ok(());
+        }
         self.backend_ops(|backend| {
             backend.draw_rect(
                 (self.rect.x0, self.rect.y0),
@@ -297,12 +437,68 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         })
     }
 
+    /**
+    Fill the entire drawing area by repeating a bitmap as tiles.
+
+    The tiles start from the upper left corner of the drawing area, and the tiles at the right
+    and bottom edges are cropped to the drawing area. The position of the bitmap element is
+    ignored.
+
+    - `tile`: The bitmap to repeat
+
+    ```rust
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("fill_pattern.svg", (100, 100)).into_drawing_area();
+    // A 2x2 checkerboard tile in RGB pixels
+    let pixels = vec![255, 255, 255, 0, 0, 255, 0, 0, 255, 255, 255, 255];
+    let tile = BitMapElement::<_>::with_owned_buffer((0, 0), (2, 2), pixels).unwrap();
+    drawing_area.fill_pattern(&tile).unwrap();
+    ```
+    */
+    #[cfg(feature = "bitmap_backend")]
+    pub fn fill_pattern<Coord>(
+        &self,
+        tile: &BitMapElement<'_, Coord>,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let (tw, th) = tile.size();
+        if tw == 0 || th == 0 || self.is_empty() {
+            return Ok(());
+        }
+        let pixels = tile.pixels();
+        let format = tile.format();
+        let row_size = tw as usize * format.pixel_size();
+        self.backend_ops(|backend| {
+            for y in (self.rect.y0..self.rect.y1).step_by(th as usize) {
+                let h = (self.rect.y1 - y).min(th as i32) as u32;
+                for x in (self.rect.x0..self.rect.x1).step_by(tw as usize) {
+                    let w = (self.rect.x1 - x).min(tw as i32) as u32;
+                    if (w, h) == (tw, th) {
+                        backend.blit_bitmap_fmt((x, y), (tw, th), pixels, format)?;
+                    } else {
+                        // Crop the partial tile at the edge of the drawing area
+                        let cropped: Vec<_> = pixels
+                            .chunks(row_size)
+                            .take(h as usize)
+                            .flat_map(|row| &row[..w as usize * format.pixel_size()])
+                            .copied()
+                            .collect();
+                        backend.blit_bitmap_fmt((x, y), (w, h), &cropped, format)?;
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
     /// Draw a single pixel
     pub fn draw_pixel<ColorType: Color>(
         &self,
         pos: CT::From,
         color: &ColorType,
     ) -> Result<(), DrawingAreaError<DB>> {
+        if self.is_empty() {
+            return Ok(());
+        }
         let pos = self.coord.translate(&pos);
         self.backend_ops(|b| b.draw_pixel(pos, color.to_backend_color()))
     }
@@ -312,6 +508,29 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.present())
     }
 
+    /// Take the backend out of the drawing area, for example, to get the image rendered by an
+    /// in-memory backend.
+    ///
+    /// This only works for the last drawing area sharing the backend, thus the other areas, such
+    /// as the splits of this area and the charts built on it, must be dropped first. Otherwise,
+    /// the drawing area is given back as the error.
+    pub fn into_backend(self) -> Result<DB, Self> {
+        let DrawingArea {
+            backend,
+            rect,
+            scale,
+            coord,
+        } = self;
+        Rc::try_unwrap(backend)
+            .map(RefCell::into_inner)
+            .map_err(|backend| DrawingArea {
+                backend,
+                rect,
+                scale,
+                coord,
+            })
+    }
+
     /// Draw an high-level element
     pub fn draw<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
     where
@@ -319,11 +538,82 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         &'a E: PointCollection<'a, CT::From, B>,
         E: Drawable<DB, B>,
     {
-        let backend_coords = element.point_iter().into_iter().map(|p| {
-            let b = p.borrow();
-            B::map(&self.coord, b, &self.rect)
+        self.draw_with_overflow(element, 0)
+    }
+
+    /// Draw an high-level element, the points of the element may overflow the drawing area by
+    /// `overflow` pixels before they are truncated to the area.
+    ///
+    /// When the backend supports clipping, see [`DrawingBackend::supports_clipping`], the element
+    /// is clipped to the area grown by `overflow` pixels instead, so that a shape partially out of
+    /// the area keeps its geometry, rather than being squashed onto the edges of the area.
+    pub fn draw_with_overflow<'a, E, B>(
+        &self,
+        element: &'a E,
+        overflow: u32,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        self.draw_and_inspect(element, overflow, |_| {})
+    }
+
+    /// Draw an high-level element like [`DrawingArea::draw_with_overflow`], passing each of its
+    /// points to `inspect` once it's mapped to the backend
+    pub(crate) fn draw_and_inspect<'a, E, B, F>(
+        &self,
+        element: &'a E,
+        overflow: u32,
+        mut inspect: F,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+        F: FnMut(&B::Output),
+    {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let rect = self.rect.grow(overflow.min(i32::MAX as u32) as i32);
+        let clip = self.backend_ops(|b| Ok(b.supports_clipping()))?;
+        let point_rect = if clip {
+            rect.grow(CLIP_MARGIN)
+        } else {
+            rect.clone()
+        };
+        let backend_coords = element.point_iter().into_iter().map(move |p| {
+            let mapped = B::map(&self.coord, p.borrow(), &point_rect);
+            inspect(&mapped);
+            mapped
         });
-        self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
+        let draw = |b: &mut DB| {
+            // The points from non-finite values break the element into pieces, and each piece
+            // is drawn separately, so that a NaN in a line series leaves a gap in the line.
+            let mut backend_coords = backend_coords.peekable();
+            let mut first_piece = true;
+            loop {
+                while backend_coords.next_if(B::is_non_finite).is_some() {}
+                if !first_piece && backend_coords.peek().is_none() {
+                    return Ok(());
+                }
+                first_piece = false;
+                let mut piece = backend_coords.by_ref().take_while(|p| !B::is_non_finite(p));
+                element.draw(&mut piece, b, self.dim_in_pixel())?;
+                piece.for_each(drop);
+            }
+        };
+        self.backend_ops(move |b| {
+            if !clip {
+                return draw(b);
+            }
+            b.push_clip(((rect.x0, rect.y0), (rect.x1, rect.y1)));
+            let result = draw(b);
+            b.pop_clip();
+            result
+        })
     }
 
     /// Map coordinate to the backend coordinate
@@ -346,6 +636,14 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     ) -> Result<(u32, u32), DrawingAreaError<DB>> {
         self.backend_ops(move |b| b.estimate_text_size(text, style))
     }
+
+    /// Check if the backend of this drawing area is able to render text,
+    /// see [`DrawingBackend::supports_text_rendering`]
+    ///
+    /// - **return**: If the text can be rendered on this area
+    pub fn supports_text_rendering(&self) -> bool {
+        RefCell::borrow(&self.backend).supports_text_rendering()
+    }
 }
 
 impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
@@ -359,6 +657,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y1: y1 as i32,
             },
             backend,
+            scale: 1.0,
             coord: Shift((0, 0)),
         }
     }
@@ -387,6 +686,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: coord_spec,
         }
     }
@@ -407,10 +707,11 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             rect: Rect {
                 x0: self.rect.x0 + left,
                 y0: self.rect.y0 + top,
-                x1: self.rect.x1 - right,
-                y1: self.rect.y1 - bottom,
+                x1: (self.rect.x1 - right).max(self.rect.x0 + left),
+                y1: (self.rect.y1 - bottom).max(self.rect.y0 + top),
             },
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: Shift((self.rect.x0 + left, self.rect.y0 + top)),
         }
     }
@@ -422,6 +723,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let mut ret = self.rect.split(split_point.iter(), true).map(|rect| Self {
             rect: rect.clone(),
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: Shift((rect.x0, rect.y0)),
         });
 
@@ -435,6 +737,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let mut ret = self.rect.split(split_point.iter(), false).map(|rect| Self {
             rect: rect.clone(),
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: Shift((rect.x0, rect.y0)),
         });
 
@@ -448,6 +751,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .map(|rect| Self {
                 rect: rect.clone(),
                 backend: self.backend.clone(),
+                scale: self.scale,
                 coord: Shift((rect.x0, rect.y0)),
             })
             .collect()
@@ -472,6 +776,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .map(|rect| Self {
                 rect: rect.clone(),
                 backend: self.backend.clone(),
+                scale: self.scale,
                 coord: Shift((rect.x0, rect.y0)),
             })
             .collect()
@@ -483,22 +788,34 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         text: &str,
         style: S,
     ) -> Result<Self, DrawingAreaError<DB>> {
-        let style = style.into();
+        self.titled_fit(text, style, TextOverflow::Ignore)
+    }
 
+    /// Draw a title of the drawing area and return the remaining drawing area, the overflow
+    /// of the title is handled as `overflow` specifies
+    pub fn titled_fit<'a, S: Into<TextStyle<'a>>>(
+        &self,
+        text: &str,
+        style: S,
+        overflow: TextOverflow,
+    ) -> Result<Self, DrawingAreaError<DB>> {
         let x_padding = (self.rect.x1 - self.rect.x0) / 2;
 
+        let style = style.into().pos(Pos::new(HPos::Center, VPos::Top));
+        let style = self.fit_text_style(text, style, (x_padding, 0), overflow)?;
+
         let (_, text_h) = self.estimate_text_size(text, &style)?;
         let y_padding = (text_h / 2).min(5) as i32;
 
-        let style = &style.pos(Pos::new(HPos::Center, VPos::Top));
-
-        self.backend_ops(|b| {
-            b.draw_text(
-                text,
-                style,
-                (self.rect.x0 + x_padding, self.rect.y0 + y_padding),
-            )
-        })?;
+        if !self.is_empty() {
+            self.backend_ops(|b| {
+                b.draw_text(
+                    text,
+                    &style,
+                    (self.rect.x0 + x_padding, self.rect.y0 + y_padding),
+                )
+            })?;
+        }
 
         Ok(Self {
             rect: Rect {
@@ -508,6 +825,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y1: self.rect.y1,
             },
             backend: self.backend.clone(),
+            scale: self.scale,
             coord: Shift((self.rect.x0, self.rect.y0 + y_padding * 2 + text_h as i32)),
         })
     }
@@ -519,8 +837,75 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingAreaError<DB>> {
+        if self.is_empty() {
+            return Ok(());
+        }
         self.backend_ops(|b| b.draw_text(text, style, (pos.0 + self.rect.x0, pos.1 + self.rect.y0)))
     }
+
+    /// Draw text on the drawing area, the overflow of the text is handled as `overflow`
+    /// specifies. The text overflows when its estimated box isn't entirely in the area.
+    pub fn draw_text_fit(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+        overflow: TextOverflow,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let style = self.fit_text_style(text, style.clone(), pos, overflow)?;
+        self.draw_text(text, &style, pos)
+    }
+
+    /// Check if the text drawn at the given position fits in the drawing area
+    pub fn text_fits(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<bool, DrawingAreaError<DB>> {
+        let (w, h) = self.estimate_text_size(text, style)?;
+        let (w, h) = style.font.get_transform().transform_size(w, h);
+        let (w, h) = (w as i32, h as i32);
+        let x0 = match style.pos.h_pos {
+            HPos::Left => pos.0,
+            HPos::Center => pos.0 - w / 2,
+            HPos::Right => pos.0 - w,
+        };
+        let y0 = match style.pos.v_pos {
+            VPos::Top => pos.1,
+            VPos::Center => pos.1 - h / 2,
+            VPos::Bottom => pos.1 - h,
+        };
+        let (aw, ah) = self.dim_in_pixel();
+        Ok(x0 >= 0 && y0 >= 0 && x0 + w <= aw as i32 && y0 + h <= ah as i32)
+    }
+
+    pub(crate) fn fit_text_style<'a>(
+        &self,
+        text: &str,
+        mut style: TextStyle<'a>,
+        pos: BackendCoord,
+        overflow: TextOverflow,
+    ) -> Result<TextStyle<'a>, DrawingAreaError<DB>> {
+        match overflow {
+            TextOverflow::Ignore => {}
+            TextOverflow::Error => {
+                if !self.text_fits(text, &style, pos)? {
+                    return Err(DrawingAreaErrorKind::LayoutError);
+                }
+            }
+            TextOverflow::ShrinkToFit { min_size } => {
+                while !self.text_fits(text, &style, pos)? {
+                    let size = style.font.get_size();
+                    if size <= min_size {
+                        break;
+                    }
+                    style.font = style.font.resize((size * 0.9).max(min_size));
+                }
+            }
+        }
+        Ok(style)
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
@@ -543,6 +928,83 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
 #[cfg(test)]
 mod drawing_area_tests {
     use crate::{create_mocked_drawing_area, prelude::*};
+    #[test]
+    fn test_zero_size_area() {
+        let drawing_area = create_mocked_drawing_area(1, 1, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.draw_count, 0);
+            });
+        });
+
+        // Splitting a 1px area into 3 pieces leaves 2 of them empty
+        let empty_areas: Vec<_> = drawing_area
+            .split_evenly((1, 3))
+            .into_iter()
+            .chain(drawing_area.split_evenly((3, 1)))
+            .filter(|area| area.is_empty())
+            .collect();
+        assert_eq!(empty_areas.len(), 4);
+
+        for area in empty_areas {
+            assert!(matches!(area.dim_in_pixel(), (0, 1) | (1, 0)));
+            area.fill(&WHITE).expect("Fill");
+            area.draw(&Circle::new((0, 0), 5, RED.filled()))
+                .expect("Draw");
+            area.draw_text("x", &("sans-serif", 10).into_text_style(&area), (0, 0))
+                .expect("Text");
+            let area = area.titled("Title", ("sans-serif", 10)).expect("Title");
+            let mut chart = ChartBuilder::on(&area)
+                .caption("Caption", ("sans-serif", 10))
+                .margin(5)
+                .set_all_label_area_size(10)
+                .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+                .expect("Build");
+            chart.configure_mesh().draw().expect("Mesh");
+            chart
+                .draw_series(LineSeries::new(
+                    (0..10).map(|x| (x as f64 / 10.0, 0.5)),
+                    &RED,
+                ))
+                .expect("Series");
+            chart.configure_series_labels().draw().expect("Labels");
+        }
+    }
+
+    #[test]
+    fn test_text_overflow() {
+        let drawing_area = create_mocked_drawing_area(100, 30, |m| {
+            m.check_draw_text(|_, _, size, _, text| {
+                assert_eq!(text, "A very long text");
+                assert!(size < 40.0);
+                assert!(size >= 8.0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let style = ("sans-serif", 40).into_text_style(&drawing_area);
+        let text = "A very long text";
+
+        assert!(!drawing_area.text_fits(text, &style, (0, 0)).unwrap());
+        assert!(matches!(
+            drawing_area.draw_text_fit(text, &style, (0, 0), TextOverflow::Error),
+            Err(DrawingAreaErrorKind::LayoutError)
+        ));
+        drawing_area
+            .draw_text_fit(
+                text,
+                &style,
+                (0, 0),
+                TextOverflow::ShrinkToFit { min_size: 8.0 },
+            )
+            .expect("Drawing Failure");
+        let remaining = drawing_area
+            .titled_fit(text, style, TextOverflow::ShrinkToFit { min_size: 8.0 })
+            .expect("Drawing Failure");
+        assert!(remaining.dim_in_pixel().1 < 30);
+    }
+
     #[test]
     fn test_filling() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -562,6 +1024,28 @@ mod drawing_area_tests {
         drawing_area.fill(&WHITE).expect("Drawing Failure");
     }
 
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_fill_pattern() {
+        let drawing_area = create_mocked_drawing_area(20, 20, |m| {
+            m.check_draw_pixel(|c, (x, y)| {
+                assert!((3..10).contains(&x) && (2..7).contains(&y));
+                let (tx, ty) = ((x - 3) % 3, (y - 2) % 2);
+                assert_eq!(c, RGBAColor(tx as u8 * 10, ty as u8 * 10, 0, 1.0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 35);
+            });
+        });
+
+        let pixels = (0..2)
+            .flat_map(|y| (0..3).flat_map(move |x| [x * 10, y * 10, 0]))
+            .collect();
+        let tile = BitMapElement::<_>::with_owned_buffer((0, 0), (3, 2), pixels).unwrap();
+        let area = drawing_area.shrink((3, 2), (7, 5));
+        area.fill_pattern(&tile).expect("Drawing Failure");
+    }
+
     #[test]
     fn test_split_evenly() {
         let colors = vec![
@@ -856,4 +1340,31 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[test]
+    fn test_draw_clipped() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.with_clipping().with_pixel_buffer();
+            // The points out of the area are kept, rather than truncated onto its edges
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(-10, 30), (90, 30)]);
+            });
+            m.drop_check(|b| {
+                let log: Vec<_> = b.draw_log.iter().map(|x| x.as_str()).collect();
+                assert_eq!(log[0], "push_clip ((10, 10), (60, 60))");
+                assert!(log[1].starts_with("draw_path"));
+                assert_eq!(log[2], "pop_clip");
+                assert_eq!(b.get_pixel(7, 7), RGBAColor(0, 0, 0, 0.0));
+                assert_eq!(b.get_pixel(12, 12), RGBAColor(255, 0, 0, 1.0));
+            });
+        })
+        .shrink((10, 10), (50, 50));
+
+        drawing_area
+            .draw(&PathElement::new(vec![(-20, 20), (80, 20)], RED))
+            .unwrap();
+        drawing_area
+            .draw(&Rectangle::new([(-5, -5), (20, 20)], RED.filled()))
+            .unwrap();
+    }
 }
@@ -3,10 +3,13 @@ use crate::drawing::area::IntoDrawingArea;
 use crate::drawing::DrawingArea;
 use crate::style::RGBAColor;
 use plotters_backend::{
-    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+    rasterizer::ClipStack, BackendColor, BackendCoord, BackendStyle, BackendTextStyle,
+    DrawingBackend, DrawingErrorKind,
 };
 
+use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 pub fn check_color(left: BackendColor, right: RGBAColor) {
     assert_eq!(
@@ -27,6 +30,8 @@ pub struct MockedBackend {
     pub num_draw_text_call: u32,
     pub num_draw_path_call: u32,
     pub num_fill_polygon_call: u32,
+    /// The log of all the draw calls, in the order they are made
+    pub draw_log: Vec<String>,
     check_draw_pixel: VecDeque<Box<dyn FnMut(RGBAColor, BackendCoord)>>,
     check_draw_line: VecDeque<Box<dyn FnMut(RGBAColor, u32, BackendCoord, BackendCoord)>>,
     check_draw_rect: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, BackendCoord)>>,
@@ -34,6 +39,8 @@ pub struct MockedBackend {
     check_draw_circle: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, u32)>>,
     check_draw_text: VecDeque<Box<dyn FnMut(RGBAColor, &str, f64, BackendCoord, &str)>>,
     check_fill_polygon: VecDeque<Box<dyn FnMut(RGBAColor, Vec<BackendCoord>)>>,
+    pixel_buffer: Option<Vec<RGBAColor>>,
+    clip: Option<ClipStack>,
     drop_check: Option<Box<dyn FnMut(&Self)>>,
 }
 
@@ -66,6 +73,7 @@ impl MockedBackend {
             num_draw_text_call: 0,
             num_draw_path_call: 0,
             num_fill_polygon_call: 0,
+            draw_log: vec![],
             check_draw_pixel: vec![].into(),
             check_draw_line: vec![].into(),
             check_draw_rect: vec![].into(),
@@ -73,6 +81,8 @@ impl MockedBackend {
             check_draw_circle: vec![].into(),
             check_draw_text: vec![].into(),
             check_fill_polygon: vec![].into(),
+            pixel_buffer: None,
+            clip: None,
             drop_check: None,
         }
     }
@@ -93,6 +103,66 @@ impl MockedBackend {
     def_set_checker_func!(drop_check, &Self);
     def_set_checker_func!(check_fill_polygon, RGBAColor, Vec<BackendCoord>);
 
+    /// Composite the pixels and the filled rectangles into a pixel buffer, which starts fully
+    /// transparent, so that the resulting colors can be checked with
+    /// [`MockedBackend::get_pixel`]. The other shapes are not rasterized, and they are only
+    /// logged and checked as usual.
+    pub fn with_pixel_buffer(&mut self) -> &mut Self {
+        self.pixel_buffer = Some(vec![
+            RGBAColor(0, 0, 0, 0.0);
+            (self.width * self.height) as usize
+        ]);
+        self
+    }
+
+    /// Support the clipping boxes, so that the drawing areas clip the shapes rather than moving
+    /// their points onto the edges. The clipping boxes are logged, and the pixels out of them are
+    /// not composited into the pixel buffer.
+    pub fn with_clipping(&mut self) -> &mut Self {
+        self.clip = Some(ClipStack::new());
+        self
+    }
+
+    /// Get the composited color of a pixel, see [`MockedBackend::with_pixel_buffer`]
+    pub fn get_pixel(&self, x: u32, y: u32) -> RGBAColor {
+        assert!(x < self.width && y < self.height, "Pixel out of range");
+        let buffer = self
+            .pixel_buffer
+            .as_ref()
+            .expect("The pixel buffer is not enabled");
+        buffer[(y * self.width + x) as usize]
+    }
+
+    /// Blend the color over a pixel in the pixel buffer with the source-over compositing
+    fn blend_pixel(&mut self, (x, y): BackendCoord, color: RGBAColor) {
+        let (w, h) = (self.width as i32, self.height as i32);
+        if let Some(clip) = &self.clip {
+            if !clip.contains((x, y)) {
+                return;
+            }
+        }
+        let buffer = match self.pixel_buffer.as_mut() {
+            Some(buffer) if x >= 0 && y >= 0 && x < w && y < h => buffer,
+            _ => return,
+        };
+        let dst = &mut buffer[(y * w + x) as usize];
+        let (src_alpha, dst_alpha) = (color.3.clamp(0.0, 1.0), dst.3);
+        let alpha = src_alpha + dst_alpha * (1.0 - src_alpha);
+        if alpha <= 0.0 {
+            return;
+        }
+        let blend = |s: u8, d: u8| {
+            ((f64::from(s) * src_alpha + f64::from(d) * dst_alpha * (1.0 - src_alpha)) / alpha)
+                .round() as u8
+        };
+        *dst = RGBAColor(
+            blend(color.0, dst.0),
+            blend(color.1, dst.1),
+            blend(color.2, dst.2),
+            alpha,
+        );
+    }
+
     fn check_before_draw(&mut self) {
         self.draw_count += 1;
         //assert_eq!(self.init_count, self.draw_count);
@@ -122,6 +192,24 @@ impl DrawingBackend for MockedBackend {
         Ok(())
     }
 
+    fn push_clip(&mut self, rect: (BackendCoord, BackendCoord)) {
+        if let Some(clip) = self.clip.as_mut() {
+            self.draw_log.push(format!("push_clip {:?}", rect));
+            clip.push(rect);
+        }
+    }
+
+    fn pop_clip(&mut self) {
+        if let Some(clip) = self.clip.as_mut() {
+            self.draw_log.push("pop_clip".to_string());
+            clip.pop();
+        }
+    }
+
+    fn supports_clipping(&self) -> bool {
+        self.clip.is_some()
+    }
+
     fn present(&mut self) -> Result<(), DrawingErrorKind<MockedError>> {
         self.init_count = 0;
         self.draw_count = 0;
@@ -136,6 +224,9 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_pixel_call += 1;
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
+        self.draw_log
+            .push(format!("draw_pixel {:?} {:?}", color, point));
+        self.blend_pixel(point, color);
         if let Some(mut checker) = self.check_draw_pixel.pop_front() {
             checker(color, point);
 
@@ -156,6 +247,13 @@ impl DrawingBackend for MockedBackend {
         self.num_draw_line_call += 1;
         let color = style.color();
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
+        self.draw_log.push(format!(
+            "draw_line {:?} {} {:?} {:?}",
+            color,
+            style.stroke_width(),
+            from,
+            to
+        ));
         if let Some(mut checker) = self.check_draw_line.pop_front() {
             checker(color, style.stroke_width(), from, to);
 
@@ -177,6 +275,21 @@ impl DrawingBackend for MockedBackend {
         self.num_draw_rect_call += 1;
         let color = style.color();
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
+        self.draw_log.push(format!(
+            "draw_rect {:?} {} {} {:?} {:?}",
+            color,
+            style.stroke_width(),
+            fill,
+            upper_left,
+            bottom_right
+        ));
+        if fill && self.pixel_buffer.is_some() {
+            for y in upper_left.1.min(bottom_right.1)..upper_left.1.max(bottom_right.1) {
+                for x in upper_left.0.min(bottom_right.0)..upper_left.0.max(bottom_right.0) {
+                    self.blend_pixel((x, y), color);
+                }
+            }
+        }
         if let Some(mut checker) = self.check_draw_rect.pop_front() {
             checker(color, style.stroke_width(), fill, upper_left, bottom_right);
 
@@ -196,8 +309,15 @@ impl DrawingBackend for MockedBackend {
         self.num_draw_path_call += 1;
         let color = style.color();
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
+        let path: Vec<_> = path.into_iter().collect();
+        self.draw_log.push(format!(
+            "draw_path {:?} {} {:?}",
+            color,
+            style.stroke_width(),
+            path
+        ));
         if let Some(mut checker) = self.check_draw_path.pop_front() {
-            checker(color, style.stroke_width(), path.into_iter().collect());
+            checker(color, style.stroke_width(), path);
 
             if self.check_draw_path.is_empty() {
                 self.check_draw_path.push_back(checker);
@@ -217,6 +337,14 @@ impl DrawingBackend for MockedBackend {
         self.num_draw_circle_call += 1;
         let color = style.color();
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
+        self.draw_log.push(format!(
+            "draw_circle {:?} {} {} {:?} {}",
+            color,
+            style.stroke_width(),
+            fill,
+            center,
+            radius
+        ));
         if let Some(mut checker) = self.check_draw_circle.pop_front() {
             checker(color, style.stroke_width(), fill, center, radius);
 
@@ -236,8 +364,11 @@ impl DrawingBackend for MockedBackend {
         self.num_fill_polygon_call += 1;
         let color = style.color();
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
+        let path: Vec<_> = path.into_iter().collect();
+        self.draw_log
+            .push(format!("fill_polygon {:?} {:?}", color, path));
         if let Some(mut checker) = self.check_fill_polygon.pop_front() {
-            checker(color, path.into_iter().collect());
+            checker(color, path);
 
             if self.check_fill_polygon.is_empty() {
                 self.check_fill_polygon.push_back(checker);
@@ -246,6 +377,29 @@ impl DrawingBackend for MockedBackend {
         Ok(())
     }
 
+    fn begin_group(&mut self, id: &str) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_log.push(format!("begin_group {:?}", id));
+        Ok(())
+    }
+
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_log.push("end_group".to_string());
+        Ok(())
+    }
+
+    fn blur_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_log.push(format!(
+            "blur_rect {:?} {:?} {}",
+            upper_left, bottom_right, radius
+        ));
+        Ok(())
+    }
+
     fn draw_text<S: BackendTextStyle>(
         &mut self,
         text: &str,
@@ -256,6 +410,14 @@ impl DrawingBackend for MockedBackend {
         let color = RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha);
         self.check_before_draw();
         self.num_draw_text_call += 1;
+        self.draw_log.push(format!(
+            "draw_text {:?} {} {} {:?} {:?}",
+            color,
+            style.family().as_str(),
+            style.size(),
+            pos,
+            text
+        ));
         if let Some(mut checker) = self.check_draw_text.pop_front() {
             checker(color, style.family().as_str(), style.size(), pos, text);
 
@@ -294,3 +456,70 @@ pub fn create_mocked_drawing_area<F: FnOnce(&mut MockedBackend)>(
     setup(&mut backend);
     backend.into_drawing_area()
 }
+
+/// Run the drawing function on a mocked drawing area and return the log of all the draw calls
+/// made to the backend, which is used to check the output is deterministic
+pub fn record_draw_calls<F: FnOnce(&DrawingArea<MockedBackend, Shift>)>(
+    width: u32,
+    height: u32,
+    draw: F,
+) -> Vec<String> {
+    let log = Rc::new(RefCell::new(vec![]));
+    let drawing_area = {
+        let log = log.clone();
+        create_mocked_drawing_area(width, height, move |m| {
+            m.drop_check(move |b| {
+                *log.borrow_mut() = b.draw_log.clone();
+            });
+        })
+    };
+    draw(&drawing_area);
+    drop(drawing_area);
+    let log = log.borrow().clone();
+    log
+}
+
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pixel_buffer_compositing() {
+        let drawing_area = create_mocked_drawing_area(10, 10, |m| {
+            m.with_pixel_buffer();
+            m.drop_check(|b| {
+                assert_eq!(b.get_pixel(0, 0), RGBAColor(255, 255, 255, 1.0));
+                assert_eq!(b.get_pixel(3, 3), RGBAColor(255, 128, 128, 1.0));
+                assert_eq!(b.get_pixel(5, 5), RGBAColor(128, 64, 192, 1.0));
+                assert_eq!(b.get_pixel(8, 8), RGBAColor(255, 255, 255, 1.0));
+                assert_eq!(b.get_pixel(9, 9), RGBAColor(0, 0, 0, 1.0));
+            });
+        });
+
+        drawing_area.fill(&WHITE).unwrap();
+        drawing_area
+            .draw(&Rectangle::new([(2, 2), (8, 8)], RED.mix(0.5).filled()))
+            .unwrap();
+        drawing_area
+            .draw(&Rectangle::new([(5, 5), (6, 6)], BLUE.mix(0.5).filled()))
+            .unwrap();
+        drawing_area.draw_pixel((9, 9), &BLACK).unwrap();
+    }
+
+    #[test]
+    fn test_pixel_buffer_transparent_base() {
+        let drawing_area = create_mocked_drawing_area(4, 4, |m| {
+            m.with_pixel_buffer();
+            m.drop_check(|b| {
+                assert_eq!(b.get_pixel(0, 0), RGBAColor(0, 0, 0, 0.0));
+                assert_eq!(b.get_pixel(1, 1), RGBAColor(255, 0, 0, 0.75));
+            });
+        });
+
+        drawing_area
+            .draw(&Rectangle::new([(1, 1), (3, 3)], RED.mix(0.5).filled()))
+            .unwrap();
+        drawing_area
+            .draw(&Rectangle::new([(1, 1), (3, 3)], RED.mix(0.5).filled()))
+            .unwrap();
+    }
+}
@@ -0,0 +1,360 @@
+use crate::drawing::Rect;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, BitmapFormat, DrawingBackend,
+    DrawingErrorKind,
+};
+
+/**
+A drawing backend wrapper which tracks the region touched by the drawing operations, for
+incremental redraw.
+
+All the drawing operations are forwarded to the wrapped backend, and the union of their bounding
+boxes, clipped to the canvas, is accumulated. A backend rendering to a window can then copy only
+the dirty region to the screen, rather than the whole frame. The bounding boxes are conservative:
+they include the stroke width, and a text is assumed to be rotated in any direction around its
+anchor.
+
+The region is reset by [`DirtyTrackingBackend::take_dirty_region`], or by `present`, so the
+region should be taken before presenting the frame.
+
+```
+use plotters::prelude::*;
+use plotters::drawing::DirtyTrackingBackend;
+let backend = DirtyTrackingBackend::new(SVGBackend::new("dirty_region.svg", (100, 100)));
+let drawing_area = backend.into_drawing_area();
+drawing_area
+    .draw(&Rectangle::new([(10, 20), (30, 40)], RED.filled()))
+    .unwrap();
+let region = drawing_area.take_dirty_region().unwrap();
+assert_eq!(region.upper_left(), (10, 20));
+assert_eq!(region.bottom_right(), (31, 41));
+assert!(drawing_area.take_dirty_region().is_none());
+```
+*/
+pub struct DirtyTrackingBackend<DB: DrawingBackend> {
+    inner: DB,
+    dirty: Option<Rect>,
+}
+
+impl<DB: DrawingBackend> DirtyTrackingBackend<DB> {
+    /// Wrap a backend to track the region touched by the drawing operations
+    pub fn new(inner: DB) -> Self {
+        Self { inner, dirty: None }
+    }
+
+    /// Get a reference to the wrapped backend
+    pub fn inner(&self) -> &DB {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the wrapped backend. The drawing operations performed on it
+    /// directly are not tracked.
+    pub fn inner_mut(&mut self) -> &mut DB {
+        &mut self.inner
+    }
+
+    /// Unwrap the backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    /// Take the bounding box of all the drawing operations since the region was last taken or
+    /// presented, and reset it. Returns `None` if nothing was drawn.
+    pub fn take_dirty_region(&mut self) -> Option<Rect> {
+        self.dirty.take()
+    }
+
+    /// Add the bounding box of the points, grown by `margin` pixels, to the dirty region
+    fn touch<I: IntoIterator<Item = BackendCoord>>(&mut self, points: I, margin: i32) {
+        let mut points = points.into_iter();
+        let first = match points.next() {
+            Some(p) => p,
+            None => return,
+        };
+        let (min, max) = points.fold((first, first), |(min, max), p| {
+            (
+                (min.0.min(p.0), min.1.min(p.1)),
+                (max.0.max(p.0), max.1.max(p.1)),
+            )
+        });
+        let (w, h) = self.inner.get_size();
+        let touched = Rect::from_corners(
+            (min.0.saturating_sub(margin), min.1.saturating_sub(margin)),
+            (
+                max.0.saturating_add(margin + 1),
+                max.1.saturating_add(margin + 1),
+            ),
+        )
+        .intersect(&Rect::from_corners((0, 0), (w as i32, h as i32)));
+        if let Some(touched) = touched {
+            self.dirty = Some(match self.dirty.take() {
+                Some(dirty) => dirty.union(&touched),
+                None => touched,
+            });
+        }
+    }
+
+    /// The margin around the points of a shape drawn with the style
+    fn stroke_margin<S: BackendStyle>(style: &S, fill: bool) -> i32 {
+        if fill {
+            1
+        } else {
+            (style.stroke_width() as i32 + 1) / 2 + 1
+        }
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for DirtyTrackingBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()?;
+        self.dirty = None;
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch([point], 0);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_pixels(
+        &mut self,
+        points: &[(BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch(points.iter().map(|&(point, _)| point), 0);
+        self.inner.draw_pixels(points)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch([from, to], Self::stroke_margin(style, false));
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_segments<S: BackendStyle>(
+        &mut self,
+        segments: &[(BackendCoord, BackendCoord)],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch(
+            segments.iter().flat_map(|&(from, to)| [from, to]),
+            Self::stroke_margin(style, false),
+        );
+        self.inner.draw_segments(segments, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch(
+            [upper_left, bottom_right],
+            Self::stroke_margin(style, fill) - 1,
+        );
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn fill_rects(
+        &mut self,
+        rects: &[(BackendCoord, BackendCoord, BackendColor)],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch(rects.iter().flat_map(|&(from, to, _)| [from, to]), 0);
+        self.inner.fill_rects(rects)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.touch(path.iter().copied(), Self::stroke_margin(style, false));
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch([center], radius as i32 + Self::stroke_margin(style, fill));
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn draw_ellipse<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: (u32, u32),
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (rx, ry) = (radius.0 as i32, radius.1 as i32);
+        self.touch(
+            [
+                (center.0 - rx, center.1 - ry),
+                (center.0 + rx, center.1 + ry),
+            ],
+            Self::stroke_margin(style, fill),
+        );
+        self.inner.draw_ellipse(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.touch(vert.iter().copied(), 1);
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (w, h) = self.inner.estimate_text_size(text, style)?;
+        self.touch([pos], w.max(h) as i32 + 1);
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blur_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch([upper_left, bottom_right], 0);
+        self.inner.blur_rect(upper_left, bottom_right, radius)
+    }
+
+    fn begin_group(&mut self, id: &str) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.begin_group(id)
+    }
+
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.end_group()
+    }
+
+    fn push_clip(&mut self, rect: (BackendCoord, BackendCoord)) {
+        self.inner.push_clip(rect)
+    }
+
+    fn pop_clip(&mut self) {
+        self.inner.pop_clip()
+    }
+
+    fn supports_clipping(&self) -> bool {
+        self.inner.supports_clipping()
+    }
+
+    fn supports_text_rendering(&self) -> bool {
+        self.inner.supports_text_rendering()
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch_bitmap(pos, size);
+        self.inner.blit_bitmap(pos, size, src)
+    }
+
+    fn blit_bitmap_fmt(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        format: BitmapFormat,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.touch_bitmap(pos, size);
+        self.inner.blit_bitmap_fmt(pos, size, src, format)
+    }
+}
+
+impl<DB: DrawingBackend> DirtyTrackingBackend<DB> {
+    /// Add the region covered by a bitmap to the dirty region
+    fn touch_bitmap(&mut self, pos: BackendCoord, (w, h): (u32, u32)) {
+        if w > 0 && h > 0 {
+            self.touch([pos, (pos.0 + w as i32 - 1, pos.1 + h as i32 - 1)], 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::{IntoDrawingArea, MockedBackend};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dirty_region() {
+        let backend = DirtyTrackingBackend::new(MockedBackend::new(100, 100));
+        let area = backend.into_drawing_area();
+        assert!(area.take_dirty_region().is_none());
+
+        area.draw_pixel((10, 20), &RED).unwrap();
+        area.draw_pixel((30, 5), &RED).unwrap();
+        let region = area.take_dirty_region().unwrap();
+        assert_eq!(region.upper_left(), (10, 5));
+        assert_eq!(region.bottom_right(), (31, 21));
+        assert!(area.take_dirty_region().is_none());
+
+        // Clipped to the canvas and reset by present
+        area.draw(&Circle::new((95, 95), 20, RED.filled())).unwrap();
+        assert_eq!(area.take_dirty_region().unwrap().bottom_right(), (100, 100));
+        area.draw(&Rectangle::new([(0, 0), (10, 10)], RED.filled()))
+            .unwrap();
+        area.present().unwrap();
+        assert!(area.take_dirty_region().is_none());
+
+        // Drawing outside of the canvas leaves it clean
+        area.draw_pixel((-5, -5), &RED).unwrap();
+        assert!(area.take_dirty_region().is_none());
+    }
+
+    #[test]
+    fn test_dirty_region_stroke() {
+        let mut backend = DirtyTrackingBackend::new(MockedBackend::new(100, 100));
+        backend
+            .draw_line((20, 50), (80, 50), &Color::stroke_width(&RED, 5))
+            .unwrap();
+        let region = backend.take_dirty_region().unwrap();
+        assert!(region.upper_left().1 <= 48 && region.bottom_right().1 >= 53);
+        assert_eq!(region.upper_left().0, 20 - 4);
+    }
+}
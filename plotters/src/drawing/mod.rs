@@ -12,7 +12,13 @@ about the [coordinate abstraction](../coord/index.html) and [element system](../
 */
 mod area;
 mod backend_impl;
+mod dirty;
+mod record;
+mod scene;
 
-pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, Rect};
+pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, Rect, TextOverflow};
+pub use dirty::DirtyTrackingBackend;
+pub use record::{Minimap, Plot, RecordingBackend};
+pub use scene::{Scene, SceneError};
 
 pub use backend_impl::*;
@@ -0,0 +1,884 @@
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DummyBackendError};
+use crate::element::Rectangle;
+use crate::style::{
+    Color, DashPattern, FillPattern, FontDesc, FontFamily, FontStyle, FontTransform, PatternFill,
+    ShapeStyle, TextStyle, BLACK, RED,
+};
+use plotters_backend::{
+    text_anchor, BackendColor, BackendCoord, BackendStyle, BackendTextStyle, BitmapFormat,
+    DrawingBackend, DrawingErrorKind,
+};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The shape style captured by the recording backend
+#[derive(Clone, Copy)]
+struct RecordedStyle {
+    color: BackendColor,
+    stroke_width: u32,
+    dash: Option<DashPattern>,
+    pattern: Option<PatternFill>,
+}
+
+impl RecordedStyle {
+    fn capture<S: BackendStyle>(style: &S) -> Self {
+        Self {
+            color: style.color(),
+            stroke_width: style.stroke_width(),
+            dash: style
+                .dash_pattern()
+                .map(|pattern| DashPattern::new(pattern, style.dash_offset())),
+            pattern: Some(style.fill_pattern())
+                .filter(|&pattern| pattern != FillPattern::Solid)
+                .map(|pattern| {
+                    PatternFill::new(
+                        pattern,
+                        style.fill_pattern_spacing(),
+                        style.fill_pattern_line_width(),
+                    )
+                }),
+        }
+    }
+}
+
+impl BackendStyle for RecordedStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+
+    fn dash_pattern(&self) -> Option<&[u32]> {
+        self.dash.as_ref().map(|dash| dash.segments())
+    }
+
+    fn dash_offset(&self) -> u32 {
+        self.dash.map_or(0, |dash| dash.offset())
+    }
+
+    fn fill_pattern(&self) -> FillPattern {
+        self.pattern.map_or(FillPattern::Solid, |p| p.pattern())
+    }
+
+    fn fill_pattern_spacing(&self) -> u32 {
+        self.pattern
+            .map_or_else(|| self.color.fill_pattern_spacing(), |p| p.spacing())
+    }
+
+    fn fill_pattern_line_width(&self) -> u32 {
+        self.pattern
+            .map_or_else(|| self.color.fill_pattern_line_width(), |p| p.line_width())
+    }
+}
+
+/// The text style captured by the recording backend. The font family is owned, so that the
+/// recorded plot doesn't borrow anything from the code that produced it.
+#[derive(Clone)]
+struct RecordedTextStyle {
+    family: String,
+    size: f64,
+    style: FontStyle,
+    transform: FontTransform,
+    color: BackendColor,
+    anchor: text_anchor::Pos,
+}
+
+impl RecordedTextStyle {
+    fn capture<S: BackendTextStyle>(style: &S) -> Self {
+        Self {
+            family: style.family().as_str().to_string(),
+            size: style.size(),
+            style: style.style(),
+            transform: style.transform(),
+            color: style.color(),
+            anchor: style.anchor(),
+        }
+    }
+
+    fn to_text_style(&self, scale: f64) -> TextStyle<'_> {
+        let font = FontDesc::new(
+            FontFamily::from(self.family.as_str()),
+            self.size * scale,
+            self.style,
+        )
+        .transform(self.transform.clone());
+        TextStyle {
+            font,
+            color: self.color,
+            pos: self.anchor,
+        }
+    }
+}
+
+/// A single low-level drawing operation captured by the recording backend
+#[derive(Clone)]
+enum DrawOp {
+    Pixel(BackendCoord, BackendColor),
+    Line(BackendCoord, BackendCoord, RecordedStyle),
+    Rect(BackendCoord, BackendCoord, RecordedStyle, bool),
+    Path(Vec<BackendCoord>, RecordedStyle),
+    Circle(BackendCoord, u32, RecordedStyle, bool),
+    Polygon(Vec<BackendCoord>, RecordedStyle),
+    Text(String, RecordedTextStyle, BackendCoord),
+    Bitmap(BackendCoord, (u32, u32), Vec<u8>, BitmapFormat),
+    Blur(BackendCoord, BackendCoord, u32),
+    BeginGroup(String),
+    EndGroup,
+    PushClip(BackendCoord, BackendCoord),
+    PopClip,
+}
+
+/// The drawing backend that doesn't draw anything, but records all the drawing operations
+/// so that they can be replayed on other backends later. See [`Plot`] for details.
+pub struct RecordingBackend {
+    size: (u32, u32),
+    ops: Vec<DrawOp>,
+}
+
+impl RecordingBackend {
+    /// Create a new recording backend with the given size
+    pub fn new(size: (u32, u32)) -> Self {
+        Self { size, ops: vec![] }
+    }
+
+    /// Finish the recording and get the recorded plot
+    pub fn into_plot(self) -> Plot {
+        Plot {
+            size: self.size,
+            ops: self.ops,
+        }
+    }
+}
+
+impl DrawingBackend for RecordingBackend {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::Pixel(point, color));
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops
+            .push(DrawOp::Line(from, to, RecordedStyle::capture(style)));
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::Rect(
+            upper_left,
+            bottom_right,
+            RecordedStyle::capture(style),
+            fill,
+        ));
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::Path(
+            path.into_iter().collect(),
+            RecordedStyle::capture(style),
+        ));
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::Circle(
+            center,
+            radius,
+            RecordedStyle::capture(style),
+            fill,
+        ));
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::Polygon(
+            vert.into_iter().collect(),
+            RecordedStyle::capture(style),
+        ));
+        Ok(())
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::Text(
+            text.to_string(),
+            RecordedTextStyle::capture(style),
+            pos,
+        ));
+        Ok(())
+    }
+
+    fn blur_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops
+            .push(DrawOp::Blur(upper_left, bottom_right, radius));
+        Ok(())
+    }
+
+    fn begin_group(&mut self, id: &str) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::BeginGroup(id.to_string()));
+        Ok(())
+    }
+
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops.push(DrawOp::EndGroup);
+        Ok(())
+    }
+
+    fn push_clip(&mut self, (upper_left, bottom_right): (BackendCoord, BackendCoord)) {
+        self.ops.push(DrawOp::PushClip(upper_left, bottom_right));
+    }
+
+    fn pop_clip(&mut self) {
+        // Nothing has been drawn in the clipping box, thus it can be dropped
+        if let Some(DrawOp::PushClip(..)) = self.ops.last() {
+            self.ops.pop();
+        } else {
+            self.ops.push(DrawOp::PopClip);
+        }
+    }
+
+    fn supports_clipping(&self) -> bool {
+        true
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops
+            .push(DrawOp::Bitmap(pos, size, src.to_vec(), BitmapFormat::Rgb));
+        Ok(())
+    }
+
+    fn blit_bitmap_fmt(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        format: BitmapFormat,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.ops
+            .push(DrawOp::Bitmap(pos, size, src.to_vec(), format));
+        Ok(())
+    }
+}
+
+/// The mapping from the recorded canvas to the canvas the plot is replayed on. When the target
+/// backend doesn't support clipping, the recorded clipping boxes are kept in `clips`, and the
+/// points are moved onto the edges of the innermost box instead.
+struct Scale {
+    offset: BackendCoord,
+    sx: f64,
+    sy: f64,
+    clips: Vec<(BackendCoord, BackendCoord)>,
+}
+
+impl Scale {
+    fn corner(&self, (x, y): BackendCoord) -> BackendCoord {
+        (
+            self.offset.0 + (f64::from(x) * self.sx).round() as i32,
+            self.offset.1 + (f64::from(y) * self.sy).round() as i32,
+        )
+    }
+
+    fn point(&self, p: BackendCoord) -> BackendCoord {
+        let (x, y) = self.corner(p);
+        match self.clips.last() {
+            Some(&((x0, y0), (x1, y1))) => (x.max(x0).min(x1), y.max(y0).min(y1)),
+            None => (x, y),
+        }
+    }
+
+    fn push_clip(&mut self, upper_left: BackendCoord, bottom_right: BackendCoord) {
+        let (mut ul, mut br) = (self.corner(upper_left), self.corner(bottom_right));
+        if let Some(&(outer_ul, outer_br)) = self.clips.last() {
+            ul = (ul.0.max(outer_ul.0), ul.1.max(outer_ul.1));
+            br = (br.0.min(outer_br.0), br.1.min(outer_br.1));
+        }
+        self.clips.push((ul, br));
+    }
+
+    fn length(&self) -> f64 {
+        self.sx.min(self.sy)
+    }
+
+    fn size(&self, value: u32) -> u32 {
+        if value == 0 {
+            return 0;
+        }
+        ((f64::from(value) * self.length()).round() as u32).max(1)
+    }
+
+    fn style(&self, style: &RecordedStyle) -> RecordedStyle {
+        RecordedStyle {
+            color: style.color,
+            stroke_width: self.size(style.stroke_width),
+            dash: style.dash.map(|dash| {
+                let segments: Vec<_> = dash.segments().iter().map(|&l| self.size(l)).collect();
+                DashPattern::new(&segments, self.size(dash.offset()))
+            }),
+            pattern: style.pattern.map(|p| {
+                PatternFill::new(
+                    p.pattern(),
+                    self.size(p.spacing()),
+                    self.size(p.line_width()),
+                )
+            }),
+        }
+    }
+}
+
+/**
+A recorded plot, which is a sequence of low-level drawing operations that can be replayed on
+any drawing backend.
+
+Plotters draws in immediate mode, which means the chart code is bound to the backend it draws
+on. A plot allows the same chart to be rendered to multiple backends (for example, SVG for the
+web page and PNG for a thumbnail) without running the plotting code again: the plotting code
+draws onto a [`RecordingBackend`] once, and the recorded operations are replayed afterwards.
+When the target has a different size than the recorded canvas, the plot is scaled to fit.
+
+```rust
+use plotters::prelude::*;
+let plot = Plot::record((640, 480), |root| {
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(root)
+        .caption("Recorded", ("sans-serif", 20))
+        .set_all_label_area_size(30)
+        .build_cartesian_2d(0..10, 0..10)?;
+    chart.configure_mesh().draw()?;
+    chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))?;
+    Ok::<(), DrawingAreaErrorKind<_>>(())
+})
+.unwrap();
+
+let mut svg = String::new();
+plot.replay(&mut SVGBackend::with_string(&mut svg, (640, 480))).unwrap();
+let mut thumbnail = vec![0; 160 * 120 * 3];
+plot.replay(&mut BitMapBackend::with_buffer(&mut thumbnail, (160, 120))).unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct Plot {
+    size: (u32, u32),
+    ops: Vec<DrawOp>,
+}
+
+impl Plot {
+    /// Record a plot by running the plotting code on a recording backend
+    ///
+    /// - `size`: The size of the canvas the plotting code draws on
+    /// - `draw`: The plotting code, it takes the root drawing area of the recording backend
+    /// - **returns** The recorded plot, or the error returned by the plotting code
+    pub fn record<E, F>(size: (u32, u32), draw: F) -> Result<Plot, E>
+    where
+        F: FnOnce(&DrawingArea<RecordingBackend, Shift>) -> Result<(), E>,
+    {
+        let backend = Rc::new(RefCell::new(RecordingBackend::new(size)));
+        {
+            let root: DrawingArea<_, _> = (&backend).into();
+            draw(&root)?;
+        }
+        let ops = std::mem::take(&mut backend.borrow_mut().ops);
+        Ok(Plot { size, ops })
+    }
+
+    /// Get the size of the canvas this plot has been recorded on
+    pub fn dim_in_pixel(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Get the number of recorded drawing operations
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Check if nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Replay the plot on a drawing backend, the plot is scaled to the size of the backend.
+    ///
+    /// Note that this function doesn't call `present` on the backend.
+    ///
+    /// - `backend`: The target backend
+    pub fn replay<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let size = backend.get_size();
+        backend.ensure_prepared()?;
+        self.replay_scaled(backend, (0, 0), size)
+    }
+
+    /// Render the plot on a drawing area, the plot is scaled to fit the area.
+    ///
+    /// - `area`: The target drawing area
+    pub fn render<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let offset = area.get_base_pixel();
+        let size = area.dim_in_pixel();
+        area.backend_ops(|b| self.replay_scaled(b, offset, size))
+    }
+
+    fn replay_scaled<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        offset: BackendCoord,
+        (w, h): (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut scale = Scale {
+            offset,
+            sx: f64::from(w) / f64::from(self.size.0.max(1)),
+            sy: f64::from(h) / f64::from(self.size.1.max(1)),
+            clips: vec![],
+        };
+        let clipping = backend.supports_clipping();
+
+        for op in self.ops.iter() {
+            match op {
+                DrawOp::Pixel(p, color) => backend.draw_pixel(scale.point(*p), *color)?,
+                DrawOp::Line(from, to, style) => {
+                    backend.draw_line(scale.point(*from), scale.point(*to), &scale.style(style))?
+                }
+                DrawOp::Rect(ul, br, style, fill) => backend.draw_rect(
+                    scale.point(*ul),
+                    scale.point(*br),
+                    &scale.style(style),
+                    *fill,
+                )?,
+                DrawOp::Path(path, style) => {
+                    backend.draw_path(path.iter().map(|p| scale.point(*p)), &scale.style(style))?
+                }
+                DrawOp::Circle(center, radius, style, fill) => backend.draw_circle(
+                    scale.point(*center),
+                    scale.size(*radius),
+                    &scale.style(style),
+                    *fill,
+                )?,
+                DrawOp::Polygon(vert, style) => backend
+                    .fill_polygon(vert.iter().map(|p| scale.point(*p)), &scale.style(style))?,
+                DrawOp::Text(text, style, pos) => backend.draw_text(
+                    text,
+                    &style.to_text_style(scale.length()),
+                    scale.point(*pos),
+                )?,
+                DrawOp::Blur(ul, br, radius) => {
+                    backend.blur_rect(scale.point(*ul), scale.point(*br), scale.size(*radius))?
+                }
+                DrawOp::BeginGroup(id) => backend.begin_group(id)?,
+                DrawOp::EndGroup => backend.end_group()?,
+                DrawOp::PushClip(ul, br) if clipping => {
+                    backend.push_clip((scale.corner(*ul), scale.corner(*br)))
+                }
+                DrawOp::PushClip(ul, br) => scale.push_clip(*ul, *br),
+                DrawOp::PopClip if clipping => backend.pop_clip(),
+                DrawOp::PopClip => {
+                    scale.clips.pop();
+                }
+                DrawOp::Bitmap(pos, (iw, ih), src, format) => {
+                    let tw = ((f64::from(*iw) * scale.sx).round() as u32).max(1);
+                    let th = ((f64::from(*ih) * scale.sy).round() as u32).max(1);
+                    if (tw, th) == (*iw, *ih) {
+                        backend.blit_bitmap_fmt(scale.point(*pos), (*iw, *ih), src, *format)?;
+                    } else {
+                        // Nearest neighbor resampling of the pixel buffer
+                        let size = format.pixel_size();
+                        let mut buf = vec![0; (tw * th) as usize * size];
+                        for y in 0..th {
+                            let sy = (u64::from(y) * u64::from(*ih) / u64::from(th)) as u32;
+                            for x in 0..tw {
+                                let sx = (u64::from(x) * u64::from(*iw) / u64::from(tw)) as u32;
+                                let src_idx = (sy * iw + sx) as usize * size;
+                                let dst_idx = (y * tw + x) as usize * size;
+                                buf[dst_idx..dst_idx + size]
+                                    .copy_from_slice(&src[src_idx..src_idx + size]);
+                            }
+                        }
+                        backend.blit_bitmap_fmt(scale.point(*pos), (tw, th), &buf, *format)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**
+An overview inset of a plot, which draws a scaled-down copy of the plot in a corner of a
+drawing area, with a rectangle highlighting the visible region.
+
+This is usually used with the plot of the full data, while the drawing area shows a zoomed-in
+chart. The highlighted region is in the pixels of the recorded canvas, which can be found with
+[`crate::chart::ChartContext::backend_coord()`] in the recording code.
+
+```rust
+use plotters::prelude::*;
+let mut visible = ((0, 0), (0, 0));
+let overview = Plot::record((400, 300), |root| {
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(root).build_cartesian_2d(0..100, 0..100)?;
+    chart.draw_series(LineSeries::new((0..100).map(|x| (x, x)), &BLUE))?;
+    visible = (chart.backend_coord(&(20, 40)), chart.backend_coord(&(40, 20)));
+    Ok::<(), DrawingAreaErrorKind<_>>(())
+})
+.unwrap();
+
+let root = SVGBackend::new("minimap.svg", (800, 600)).into_drawing_area();
+root.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&root).build_cartesian_2d(20..40, 20..40).unwrap();
+chart.draw_series(LineSeries::new((20..40).map(|x| (x, x)), &BLUE)).unwrap();
+Minimap::new(&overview).size((200, 150)).highlight(visible.0, visible.1).draw(&root).unwrap();
+```
+*/
+pub struct Minimap<'a> {
+    plot: &'a Plot,
+    pos: Option<BackendCoord>,
+    size: Option<(u32, u32)>,
+    highlight: Option<(BackendCoord, BackendCoord)>,
+    highlight_style: ShapeStyle,
+    border_style: ShapeStyle,
+}
+
+impl<'a> Minimap<'a> {
+    /// Create a new inset of the plot, which is placed at the upper right corner and takes a
+    /// quarter of the width and height of the drawing area by default
+    ///
+    /// - `plot`: The plot shown in the inset
+    /// - **returns** The newly created inset
+    pub fn new(plot: &'a Plot) -> Self {
+        Self {
+            plot,
+            pos: None,
+            size: None,
+            highlight: None,
+            highlight_style: Color::stroke_width(&RED, 2),
+            border_style: BLACK.into(),
+        }
+    }
+
+    /// Set the position of the upper left corner of the inset
+    ///
+    /// - `pos`: The position in pixels, relative to the drawing area
+    /// - **returns** The up-to-dated inset
+    pub fn position(mut self, pos: BackendCoord) -> Self {
+        self.pos = Some(pos);
+        self
+    }
+
+    /// Set the size of the inset
+    ///
+    /// - `size`: The size in pixels
+    /// - **returns** The up-to-dated inset
+    pub fn size(mut self, size: (u32, u32)) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Highlight the visible region in the inset
+    ///
+    /// - `from`, `to`: The corners of the region, in the pixels of the recorded canvas
+    /// - **returns** The up-to-dated inset
+    pub fn highlight(mut self, from: BackendCoord, to: BackendCoord) -> Self {
+        self.highlight = Some((from, to));
+        self
+    }
+
+    /// Set the style of the rectangle highlighting the visible region
+    ///
+    /// - `style`: The style of the highlight
+    /// - **returns** The up-to-dated inset
+    pub fn highlight_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Set the style of the border around the inset
+    ///
+    /// - `style`: The style of the border
+    /// - **returns** The up-to-dated inset
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Draw the inset on the drawing area
+    ///
+    /// - `area`: The drawing area the inset is placed on
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (aw, ah) = area.dim_in_pixel();
+        let (w, h) = self.size.unwrap_or((aw / 4, ah / 4));
+        let pos = self.pos.unwrap_or((aw as i32 - w as i32 - 10, 10));
+        let inset = area.clone().shrink(pos, (w, h));
+        if inset.is_empty() {
+            return Ok(());
+        }
+        let (w, h) = inset.dim_in_pixel();
+
+        self.plot.render(&inset)?;
+
+        if let Some((from, to)) = self.highlight {
+            let (pw, ph) = self.plot.size;
+            let scale = |(x, y): BackendCoord| {
+                (
+                    (f64::from(x) * f64::from(w) / f64::from(pw.max(1))).round() as i32,
+                    (f64::from(y) * f64::from(h) / f64::from(ph.max(1))).round() as i32,
+                )
+            };
+            inset.draw(&Rectangle::new(
+                [scale(from), scale(to)],
+                self.highlight_style,
+            ))?;
+        }
+
+        inset.draw(&Rectangle::new(
+            [(0, 0), (w as i32 - 1, h as i32 - 1)],
+            self.border_style,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn num_draw_ops(plot: &Plot) -> usize {
+        plot.ops
+            .iter()
+            .filter(|op| !matches!(op, DrawOp::PushClip(..) | DrawOp::PopClip))
+            .count()
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let plot = Plot::record((200, 100), |root| {
+            root.fill(&WHITE)?;
+            root.draw(&Circle::new((50, 50), 10, RED))?;
+            root.draw(&PathElement::new(
+                vec![(0, 0), (100, 50)],
+                Color::stroke_width(&BLUE, 2),
+            ))?;
+            root.draw_text("Hello", &("sans-serif", 10).into_text_style(root), (20, 20))?;
+            Ok::<(), DrawingAreaErrorKind<_>>(())
+        })
+        .unwrap();
+
+        assert_eq!(num_draw_ops(&plot), 4);
+        assert_eq!(plot.dim_in_pixel(), (200, 100));
+
+        let area = crate::create_mocked_drawing_area(400, 200, |m| {
+            m.check_draw_rect(|c, _, f, u, d| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(f);
+                assert_eq!(u, (0, 0));
+                assert_eq!(d, (400, 200));
+            });
+            m.check_draw_circle(|c, _, _, center, r| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(center, (100, 100));
+                assert_eq!(r, 20);
+            });
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(s, 4);
+                assert_eq!(path, vec![(0, 0), (200, 100)]);
+            });
+            m.check_draw_text(|_, font, size, pos, text| {
+                assert_eq!(font, "sans-serif");
+                assert_eq!(size, 20.0);
+                assert_eq!(pos, (40, 40));
+                assert_eq!(text, "Hello");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.draw_count, 4);
+            });
+        });
+
+        plot.render(&area).unwrap();
+    }
+
+    #[test]
+    fn test_replay_chart() {
+        let plot = Plot::record((300, 200), |root| {
+            let mut chart = ChartBuilder::on(root)
+                .set_all_label_area_size(20)
+                .build_cartesian_2d(0..10, 0..10)?;
+            chart.configure_mesh().draw()?;
+            chart.draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))?;
+            Ok::<(), DrawingAreaErrorKind<_>>(())
+        })
+        .unwrap();
+
+        let count = num_draw_ops(&plot) as u32;
+        let area = crate::create_mocked_drawing_area(300, 200, |m| {
+            m.drop_check(move |b| {
+                assert_eq!(b.draw_count, count);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        plot.render(&area).unwrap();
+    }
+
+    #[test]
+    fn test_replay_groups() {
+        let plot = Plot::record((100, 100), |root| {
+            root.draw(&Tagged::new("point", Pixel::new((10, 10), RED)))?;
+            Ok::<(), DrawingAreaErrorKind<_>>(())
+        })
+        .unwrap();
+
+        let log = crate::drawing::record_draw_calls(100, 100, |area| {
+            plot.render(area).unwrap();
+        });
+        assert_eq!(log.len(), 3);
+        assert_eq!(log[0], "begin_group \"point\"");
+        assert!(log[1].starts_with("draw_pixel"));
+        assert_eq!(log[2], "end_group");
+    }
+
+    #[test]
+    fn test_replay_blur() {
+        let plot = Plot::record((100, 100), |root| {
+            root.backend_ops(|b| b.blur_rect((10, 10), (50, 30), 3))?;
+            Ok::<(), DrawingAreaErrorKind<_>>(())
+        })
+        .unwrap();
+
+        let log = crate::drawing::record_draw_calls(200, 200, |area| {
+            plot.render(area).unwrap();
+        });
+        assert_eq!(log, vec!["blur_rect (20, 20) (100, 60) 6"]);
+    }
+
+    #[test]
+    fn test_replay_clip() {
+        let plot = Plot::record((100, 100), |root| {
+            let area = root.clone().shrink((10, 10), (50, 50));
+            area.draw(&PathElement::new(vec![(-20, 20), (80, 20)], RED))?;
+            Ok::<(), DrawingAreaErrorKind<_>>(())
+        })
+        .unwrap();
+
+        let area = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.with_clipping();
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(-20, 60), (180, 60)]);
+            });
+            m.drop_check(|b| {
+                let log: Vec<_> = b.draw_log.iter().map(|x| x.as_str()).collect();
+                assert_eq!(log[0], "push_clip ((20, 20), (120, 120))");
+                assert!(log[1].starts_with("draw_path"));
+                assert_eq!(log[2], "pop_clip");
+            });
+        });
+        plot.render(&area).unwrap();
+
+        // Without clipping on the target, the points are moved into the clipping box
+        let area = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(20, 60), (120, 60)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        plot.render(&area).unwrap();
+    }
+
+    #[test]
+    fn test_minimap() {
+        let plot = Plot::record((400, 200), |root| {
+            root.fill(&WHITE)?;
+            Ok::<(), DrawingAreaErrorKind<_>>(())
+        })
+        .unwrap();
+
+        let area = crate::create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_rect(|c, _, f, u, d| {
+                if c == WHITE.to_rgba() {
+                    assert!(f);
+                    assert_eq!((u, d), ((290, 10), (390, 60)));
+                } else if c == RED.to_rgba() {
+                    assert!(!f);
+                    assert_eq!((u, d), ((300, 20), (340, 40)));
+                } else {
+                    assert_eq!(c, BLACK.to_rgba());
+                    assert_eq!((u, d), ((290, 10), (389, 59)));
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+
+        Minimap::new(&plot)
+            .size((100, 50))
+            .highlight((40, 40), (200, 120))
+            .draw(&area)
+            .unwrap();
+    }
+}
@@ -0,0 +1,208 @@
+use crate::coord::Shift;
+use crate::drawing::{
+    DrawingArea, DrawingAreaErrorKind, DummyBackendError, Plot, RecordingBackend,
+};
+use crate::element::{DynElement, IntoDynElement};
+use crate::style::{Color, RGBAColor};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The error type of the drawing code in a scene
+pub type SceneError = DrawingAreaErrorKind<DummyBackendError>;
+
+type SceneLayer<'a> = dyn Fn(&DrawingArea<RecordingBackend, Shift>) -> Result<(), SceneError> + 'a;
+
+/**
+A retained-mode scene, which keeps the definition of a figure and renders it to any backend,
+at any size, on demand.
+
+Different from [`Plot`], which records the drawing operations at a fixed canvas size, a scene
+keeps the plotting code itself. Each time the scene is rendered, the layers are drawn again on
+a canvas that matches the target, so label areas, font sizes and other relative sizes are laid
+out for the actual output size rather than being scaled afterwards.
+
+A scene consists of layers which are drawn in the order they are added:
+
+- [`Scene::background`] fills the whole canvas with a color before anything else is drawn.
+- [`Scene::element`] adds an element in backend (pixel) coordinates.
+- [`Scene::chart`] adds any plotting code, typically building a chart on the root area.
+
+```rust
+use plotters::prelude::*;
+let data = vec![(0, 1), (1, 3), (2, 2), (3, 5)];
+let scene = Scene::new()
+    .background(&WHITE)
+    .chart(move |root| {
+        let mut chart = ChartBuilder::on(root)
+            .set_all_label_area_size(20)
+            .build_cartesian_2d(0..3, 0..5)?;
+        chart.configure_mesh().draw()?;
+        chart.draw_series(LineSeries::new(data.clone(), &RED))?;
+        Ok(())
+    })
+    .element(Text::new("Scene", (5, 5), ("sans-serif", 15)));
+
+let mut large = String::new();
+scene.render(&mut SVGBackend::with_string(&mut large, (800, 600))).unwrap();
+let mut small = String::new();
+scene.render(&mut SVGBackend::with_string(&mut small, (200, 150))).unwrap();
+```
+*/
+#[derive(Default)]
+pub struct Scene<'a> {
+    background: Option<RGBAColor>,
+    layers: Vec<Box<SceneLayer<'a>>>,
+}
+
+impl<'a> Scene<'a> {
+    /// Create a new empty scene
+    pub fn new() -> Self {
+        Self {
+            background: None,
+            layers: vec![],
+        }
+    }
+
+    /// Set the background color of the scene
+    ///
+    /// - `color`: The color used to fill the canvas before drawing the layers
+    pub fn background<C: Color>(mut self, color: &C) -> Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /// Add an element to the scene. The element uses the backend (pixel) coordinate of the
+    /// canvas the scene is rendered on.
+    ///
+    /// - `element`: The element to add
+    pub fn element<E: IntoDynElement<'a, RecordingBackend, BackendCoord>>(
+        mut self,
+        element: E,
+    ) -> Self {
+        let element: DynElement<'a, RecordingBackend, BackendCoord> = element.into_dyn();
+        self.layers.push(Box::new(move |root| root.draw(&element)));
+        self
+    }
+
+    /// Add plotting code to the scene. The code is called with the root drawing area every
+    /// time the scene is rendered.
+    ///
+    /// - `draw`: The plotting code
+    pub fn chart<F>(mut self, draw: F) -> Self
+    where
+        F: Fn(&DrawingArea<RecordingBackend, Shift>) -> Result<(), SceneError> + 'a,
+    {
+        self.layers.push(Box::new(draw));
+        self
+    }
+
+    /// Get the number of the layers in this scene
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Check if the scene has no layers
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Record the scene at the given canvas size
+    ///
+    /// - `size`: The size of the canvas
+    /// - **returns** The recorded plot, which can be replayed on any backend
+    pub fn to_plot(&self, size: (u32, u32)) -> Result<Plot, SceneError> {
+        Plot::record(size, |root| {
+            if let Some(color) = self.background {
+                root.fill(&color)?;
+            }
+            for layer in self.layers.iter() {
+                layer(root)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Render the scene on a drawing backend. The scene is laid out for the size of the backend.
+    ///
+    /// Note that this function doesn't call `present` on the backend.
+    ///
+    /// - `backend`: The target backend
+    pub fn render<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let plot = self.to_plot(backend.get_size()).map_err(map_scene_error)?;
+        plot.replay(backend)
+            .map_err(DrawingAreaErrorKind::BackendError)
+    }
+
+    /// Render the scene on a drawing area. The scene is laid out for the size of the area.
+    ///
+    /// - `area`: The target drawing area
+    pub fn render_on<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let plot = self.to_plot(area.dim_in_pixel()).map_err(map_scene_error)?;
+        plot.render(area)
+    }
+}
+
+fn map_scene_error<E: std::error::Error + Send + Sync>(
+    error: SceneError,
+) -> DrawingAreaErrorKind<E> {
+    match error {
+        DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)) => {
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e))
+        }
+        DrawingAreaErrorKind::BackendError(DrawingErrorKind::DrawingError(_)) => {
+            unreachable!("The recording backend never fails")
+        }
+        DrawingAreaErrorKind::SharingError => DrawingAreaErrorKind::SharingError,
+        DrawingAreaErrorKind::LayoutError => DrawingAreaErrorKind::LayoutError,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_scene_render() {
+        let scene = Scene::new()
+            .background(&WHITE)
+            .element(Circle::new((10, 10), 5, RED))
+            .chart(|root| {
+                let (left, _) = root.split_horizontally(50);
+                left.fill(&BLUE)
+            });
+
+        assert_eq!(scene.len(), 2);
+
+        for (w, h) in [(100, 50), (400, 200)] {
+            let area = crate::create_mocked_drawing_area(w, h, |m| {
+                m.check_draw_rect(move |c, _, _, u, d| {
+                    assert_eq!(c, WHITE.to_rgba());
+                    assert_eq!(u, (0, 0));
+                    assert_eq!(d, (w as i32, h as i32));
+                });
+                m.check_draw_rect(move |c, _, _, u, d| {
+                    assert_eq!(c, BLUE.to_rgba());
+                    assert_eq!(u, (0, 0));
+                    // The layout is computed for each size, not scaled
+                    assert_eq!(d, (50, h as i32));
+                });
+                m.check_draw_circle(|c, _, _, center, r| {
+                    assert_eq!(c, RED.to_rgba());
+                    assert_eq!(center, (10, 10));
+                    assert_eq!(r, 5);
+                });
+                m.drop_check(|b| {
+                    assert_eq!(b.num_draw_rect_call, 2);
+                    assert_eq!(b.num_draw_circle_call, 1);
+                });
+            });
+            scene.render_on(&area).unwrap();
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::{Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A filled band between a lower and an upper curve, such as a confidence interval.
+
+The band is built from the `(x, low, high)` tuples, and it's filled as a single polygon going
+forward along the upper curve and back along the lower curve. The two bounds are ordered point
+by point once they are mapped to the backend coordinates, so a point with the low value above
+the high value still gives a band between the two curves rather than a twisted polygon.
+Optionally, the two boundaries are traced with a border style.
+
+# Example
+
+```
+use plotters::prelude::*;
+let band: Vec<_> = (0..=40)
+    .map(|i| i as f64 / 10.0)
+    .map(|x| (x, x.sin() - 0.3, x.sin() + 0.3))
+    .collect();
+let drawing_area = SVGBackend::new("area_between.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..4.0, -1.5..1.5)
+    .unwrap();
+chart
+    .draw_series(std::iter::once(
+        AreaBetween::new(band.iter().copied(), BLUE.mix(0.2)).border_style(BLUE),
+    ))
+    .unwrap();
+```
+*/
+pub struct AreaBetween<X, Y> {
+    points: Vec<(X, Y)>,
+    style: ShapeStyle,
+    border_style: Option<ShapeStyle>,
+}
+
+impl<X: Clone, Y> AreaBetween<X, Y> {
+    /**
+    Creates a band between two curves.
+
+    - `iter`: The iterator of the `(x, low, high)` tuples
+    - `style`: The fill style of the band
+    - Returns: The newly created element
+
+    See [`AreaBetween`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (X, Y, Y)>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            points: iter
+                .into_iter()
+                .flat_map(|(x, low, high)| [(x.clone(), low), (x, high)])
+                .collect(),
+            style: style.into(),
+            border_style: None,
+        }
+    }
+}
+
+impl<X, Y> AreaBetween<X, Y> {
+    /**
+    Sets the style to trace the lower and the upper boundaries with, which are not traced by
+    default.
+
+    See [`AreaBetween`] for more information and examples.
+    */
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = Some(style.into());
+        self
+    }
+}
+
+impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a AreaBetween<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for AreaBetween<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let (upper, lower): (Vec<_>, Vec<_>) = points
+            .chunks_exact(2)
+            .map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                // The smaller backend y is the upper bound on the screen
+                if a.1 <= b.1 {
+                    (a, b)
+                } else {
+                    (b, a)
+                }
+            })
+            .unzip();
+
+        if upper.len() < 2 {
+            return Ok(());
+        }
+
+        backend.fill_polygon(
+            upper.iter().chain(lower.iter().rev()).copied(),
+            &self.style.color.to_backend_color(),
+        )?;
+
+        if let Some(border_style) = &self.border_style {
+            backend.draw_path(upper, border_style)?;
+            backend.draw_path(lower, border_style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_area_between() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, p| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(
+                    p,
+                    vec![(10, 20), (50, 30), (90, 10), (90, 40), (50, 60), (10, 50)]
+                );
+            });
+            m.check_draw_path(|_, _, p| {
+                assert_eq!(p, vec![(10, 20), (50, 30), (90, 10)]);
+            });
+            m.check_draw_path(|_, _, p| {
+                assert_eq!(p, vec![(10, 50), (50, 60), (90, 40)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        // The low value is above the high value at x = 50, which still gives a valid band
+        let band = vec![(10, 50, 20), (50, 30, 60), (90, 40, 10)];
+        drawing_area
+            .draw(&AreaBetween::new(band, BLUE).border_style(BLACK))
+            .expect("Drawing Failure");
+        drawing_area
+            .draw(&AreaBetween::<i32, i32>::new(vec![], BLUE))
+            .expect("Drawing Failure");
+    }
+}
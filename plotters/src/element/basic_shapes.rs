@@ -1,5 +1,5 @@
 use super::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, SizeDesc};
+use crate::style::{Color, RGBAColor, ShapeStyle, SizeDesc};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /**
@@ -68,6 +68,86 @@ fn test_pixel_element() {
         .expect("Drawing Failure");
 }
 
+/**
+An element of many pixels in the same color, which are drawn at once with
+`DrawingBackend::draw_pixels`. This is much faster than drawing each point as a [`Pixel`] for
+the scatter plots of millions of points.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("point_cloud.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+    .unwrap();
+let points = (0..1000).map(|i| {
+    let t = i as f64 / 1000.0;
+    (t, (t * 20.0).sin() * 0.5 + 0.5)
+});
+chart.draw_series(std::iter::once(PointCloud::new(points, &BLUE))).unwrap();
+```
+*/
+pub struct PointCloud<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> PointCloud<Coord> {
+    /**
+    Creates a new point cloud.
+
+    - `points`: The positions of the pixels
+    - `style`: The style, only the color of which is used
+    - **returns**: The newly created point cloud
+    */
+    pub fn new<P: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a PointCloud<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for PointCloud<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = self.style.color.to_backend_color();
+        let pixels: Vec<_> = points.map(|point| (point, color)).collect();
+        backend.draw_pixels(&pixels)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_point_cloud_element() {
+    use crate::prelude::*;
+    let log = crate::drawing::record_draw_calls(100, 100, |da| {
+        da.draw(&PointCloud::new(vec![(10, 20), (30, 40), (5, 5)], &RED))
+            .expect("Drawing Failure");
+    });
+    // The default implementation draws each pixel in order
+    let red = RED.to_rgba();
+    assert_eq!(
+        log,
+        vec![
+            format!("draw_pixel {:?} (10, 20)", red),
+            format!("draw_pixel {:?} (30, 40)", red),
+            format!("draw_pixel {:?} (5, 5)", red),
+        ]
+    );
+}
+
 /// This is a deprecated type. Please use new name [`PathElement`] instead.
 #[deprecated(note = "Use new name PathElement instead")]
 pub type Path<Coord> = PathElement<Coord>;
@@ -131,6 +211,192 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+/// The default number of the line segments approximating each curve of a [`BezierPath`]
+const DEFAULT_BEZIER_SAMPLES: u32 = 16;
+
+/**
+An element of a smooth curve made of cubic Bezier curves.
+
+The control points are translated into the backend coordinate before the curves are evaluated,
+so the curve is smooth in pixels whatever the coordinate system is. There are two ways to
+interpret the control points:
+
+- [`BezierPath::new`]: The control points describe the cubic Bezier curves, where adjacent curves
+share the end points, that is, the points 0 to 3 make the first curve, the points 3 to 6 make
+the second curve, and so on. The remaining points which don't make a whole curve are ignored.
+- [`BezierPath::smooth`]: The curve passes through all the points, which are joined by a
+Catmull-Rom spline. This is useful to draw a data series smoothly.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("bezier_path.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(0.0..6.0, -1.2..1.2).unwrap();
+let data: Vec<_> = (0..=6).map(|x| (x as f64, (x as f64).sin())).collect();
+chart.draw_series(std::iter::once(BezierPath::smooth(data, RED))).unwrap();
+```
+*/
+pub struct BezierPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    samples: u32,
+    through_points: bool,
+}
+
+impl<Coord> BezierPath<Coord> {
+    /// Create a new path of cubic Bezier curves
+    /// - `points`: The control points, see [`BezierPath`] for how the curves are made
+    /// - `samples`: The number of the line segments approximating each curve
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<P: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(
+        points: P,
+        samples: u32,
+        style: S,
+    ) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+            samples: samples.max(1),
+            through_points: false,
+        }
+    }
+
+    /// Create a new smooth curve passing through the given points, which is a Catmull-Rom spline
+    /// - `points`: The points the curve passes through, such as the points of a data series
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn smooth<P: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+            samples: DEFAULT_BEZIER_SAMPLES,
+            through_points: true,
+        }
+    }
+
+    /// Set the number of the line segments approximating each curve, which is 16 by default
+    /// for a smooth curve
+    /// - `samples`: The number of the line segments
+    /// - returns the updated element
+    pub fn samples(mut self, samples: u32) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+}
+
+/// Compute the control points of the cubic Bezier curves which make a Catmull-Rom spline through
+/// the given points, the end points are repeated so the spline reaches them
+fn catmull_rom_controls(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut controls = vec![];
+    if let Some(first) = points.first() {
+        controls.push(*first);
+    }
+    for i in 1..points.len() {
+        let p0 = points[i.saturating_sub(2)];
+        let p1 = points[i - 1];
+        let p2 = points[i];
+        let p3 = points[(i + 1).min(points.len() - 1)];
+        controls.push((p1.0 + (p2.0 - p0.0) / 6.0, p1.1 + (p2.1 - p0.1) / 6.0));
+        controls.push((p2.0 - (p3.0 - p1.0) / 6.0, p2.1 - (p3.1 - p1.1) / 6.0));
+        controls.push(p2);
+    }
+    controls
+}
+
+/// Sample the cubic Bezier curves whose adjacent curves share the end points
+fn sample_bezier(controls: &[(f64, f64)], samples: u32) -> Vec<BackendCoord> {
+    let to_pixel = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+    let mut path: Vec<BackendCoord> = vec![];
+    if let Some(first) = controls.first() {
+        path.push(to_pixel(*first));
+    }
+    for curve in controls.windows(4).step_by(3) {
+        for i in 1..=samples {
+            let t = f64::from(i) / f64::from(samples);
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            let point = to_pixel((
+                a * curve[0].0 + b * curve[1].0 + c * curve[2].0 + d * curve[3].0,
+                a * curve[0].1 + b * curve[1].1 + c * curve[2].1 + d * curve[3].1,
+            ));
+            if path.last() != Some(&point) {
+                path.push(point);
+            }
+        }
+    }
+    path
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a BezierPath<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for BezierPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.map(|(x, y)| (f64::from(x), f64::from(y))).collect();
+        let path = if self.through_points {
+            sample_bezier(&catmull_rom_controls(&points), self.samples)
+        } else {
+            sample_bezier(&points, self.samples)
+        };
+        backend.draw_path(path, &self.style)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bezier_path_element() {
+    use crate::prelude::*;
+
+    // A straight curve stays straight, and the samples are evenly spread
+    assert_eq!(
+        sample_bezier(&[(0.0, 0.0), (10.0, 0.0), (20.0, 0.0), (30.0, 0.0)], 3),
+        vec![(0, 0), (10, 0), (20, 0), (30, 0)]
+    );
+    // The spline passes through every point
+    let controls = catmull_rom_controls(&[(0.0, 0.0), (10.0, 10.0), (20.0, 0.0)]);
+    assert_eq!(controls.len(), 7);
+    assert_eq!(
+        (controls[0], controls[3], controls[6]),
+        ((0.0, 0.0), (10.0, 10.0), (20.0, 0.0))
+    );
+    assert_eq!((controls[2].1, controls[4].1), (10.0, 10.0));
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path.first(), Some(&(100, 100)));
+            assert_eq!(path.last(), Some(&(200, 100)));
+            assert!(path.contains(&(150, 150)));
+            assert!(path.len() > 3);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 2);
+        });
+    });
+    da.draw(&BezierPath::smooth(
+        vec![(100, 100), (150, 150), (200, 100)],
+        BLUE,
+    ))
+    .expect("Drawing Failure");
+    da.draw(&BezierPath::new(
+        vec![(100, 100), (100, 167), (200, 167), (200, 100), (250, 50)],
+        8,
+        BLUE,
+    ))
+    .expect("Drawing Failure");
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
@@ -232,6 +498,332 @@ fn test_rect_element() {
     }
 }
 
+/// The default number of segments approximating each corner of a [`RoundedRectangle`]
+const DEFAULT_CORNER_SEGMENTS: u32 = 8;
+
+/// A rectangle element with rounded corners
+pub struct RoundedRectangle<Coord> {
+    points: [Coord; 2],
+    radius: u32,
+    style: ShapeStyle,
+    fill: bool,
+    segments: u32,
+}
+
+impl<Coord> RoundedRectangle<Coord> {
+    /// Create a new rounded rectangle
+    /// - `points`: The left upper and right lower corner of the rectangle
+    /// - `radius`: The radius of the corners in pixels, which is clamped to half of the shorter side
+    /// - `style`: The shape style
+    /// - `fill`: Whether the rectangle is filled, otherwise its outline is drawn
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 2], radius: u32, style: S, fill: bool) -> Self {
+        Self {
+            points,
+            radius,
+            style: style.into(),
+            fill,
+            segments: DEFAULT_CORNER_SEGMENTS,
+        }
+    }
+
+    /// Set the number of the segments approximating each corner, which is 8 by default
+    /// - `segments`: The number of the segments
+    /// - returns the updated element
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments.max(1);
+        self
+    }
+}
+
+/// Compute the outline of a rounded rectangle clockwise, starting from the left end of the top side
+fn rounded_rect_outline(
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    radius: u32,
+    segments: u32,
+) -> Vec<BackendCoord> {
+    let (x0, y0) = (f64::from(upper_left.0), f64::from(upper_left.1));
+    let (x1, y1) = (f64::from(bottom_right.0), f64::from(bottom_right.1));
+    let radius = f64::from(radius).min((x1 - x0) / 2.0).min((y1 - y0) / 2.0);
+
+    // The center of each corner and the angle its arc starts at, with the y axis pointing down
+    let corners = [
+        (x0 + radius, y0 + radius, std::f64::consts::PI),
+        (x1 - radius, y0 + radius, std::f64::consts::PI * 1.5),
+        (x1 - radius, y1 - radius, 0.0),
+        (x0 + radius, y1 - radius, std::f64::consts::PI * 0.5),
+    ];
+
+    let mut outline: Vec<BackendCoord> = vec![];
+    for &(cx, cy, start) in corners.iter() {
+        for i in 0..=segments {
+            let angle = start + std::f64::consts::FRAC_PI_2 * f64::from(i) / f64::from(segments);
+            let point = (
+                (cx + radius * angle.cos()).round() as i32,
+                (cy + radius * angle.sin()).round() as i32,
+            );
+            if outline.last() != Some(&point) {
+                outline.push(point);
+            }
+        }
+    }
+    if outline.len() > 1 && outline.first() == outline.last() {
+        outline.pop();
+    }
+    outline
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a RoundedRectangle<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for RoundedRectangle<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match (points.next(), points.next()) {
+            (Some(a), Some(b)) => {
+                let (a, b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+                let mut outline = rounded_rect_outline(a, b, self.radius, self.segments);
+                if self.fill {
+                    backend.fill_polygon(outline, &self.style.color.to_backend_color())
+                } else {
+                    outline.push(outline[0]);
+                    backend.draw_path(outline, &self.style)
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rounded_rect_element() {
+    use crate::prelude::*;
+
+    assert_eq!(
+        rounded_rect_outline((0, 0), (10, 10), 0, 4),
+        vec![(0, 0), (10, 0), (10, 10), (0, 10)]
+    );
+    // The radius is clamped to half of the shorter side, so the short sides are half circles
+    let outline = rounded_rect_outline((0, 0), (40, 10), 100, 2);
+    assert_eq!(
+        outline,
+        vec![
+            (0, 5),
+            (1, 1),
+            (5, 0),
+            (35, 0),
+            (39, 1),
+            (40, 5),
+            (39, 9),
+            (35, 10),
+            (5, 10),
+            (1, 9)
+        ]
+    );
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(p.len(), 4 * 9);
+        });
+        m.check_draw_path(|c, s, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(s, 2);
+            assert_eq!(p.first(), p.last());
+            assert_eq!(p.len(), 4 * 3 + 1);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    da.draw(&RoundedRectangle::new(
+        [(100, 100), (200, 150)],
+        10,
+        BLUE,
+        true,
+    ))
+    .expect("Drawing Failure");
+    da.draw(
+        &RoundedRectangle::new([(100, 100), (200, 150)], 10, BLUE.stroke_width(2), false)
+            .segments(2),
+    )
+    .expect("Drawing Failure");
+}
+
+/// The direction of the color change of a [`GradientRectangle`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// The color changes along the x axis
+    Horizontal,
+    /// The color changes along the y axis
+    Vertical,
+}
+
+/// The maximum number of the strips a [`GradientRectangle`] is drawn with, which is the number
+/// of the distinct values of a color channel
+const MAX_GRADIENT_STRIPS: i32 = 256;
+
+/// A rectangle element filled with a linear gradient between two colors
+pub struct GradientRectangle<Coord> {
+    points: [Coord; 2],
+    colors: (RGBAColor, RGBAColor),
+    direction: GradientDirection,
+}
+
+impl<Coord> GradientRectangle<Coord> {
+    /// Create a new gradient rectangle
+    /// - `points`: The two opposite corners of the rectangle
+    /// - `from`: The color at the side of the first corner
+    /// - `to`: The color at the side of the second corner
+    /// - `direction`: The direction the color changes in
+    /// - returns the created element
+    pub fn new(
+        points: [Coord; 2],
+        from: RGBAColor,
+        to: RGBAColor,
+        direction: GradientDirection,
+    ) -> Self {
+        Self {
+            points,
+            colors: (from, to),
+            direction,
+        }
+    }
+}
+
+/// Interpolate between two colors linearly in the RGBA space
+fn lerp_rgba(from: RGBAColor, to: RGBAColor, t: f64) -> RGBAColor {
+    let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+    RGBAColor(
+        lerp(from.0, to.0),
+        lerp(from.1, to.1),
+        lerp(from.2, to.2),
+        from.3 + (to.3 - from.3) * t,
+    )
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a GradientRectangle<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for GradientRectangle<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+
+        // The position along the gradient, from the first corner to the second one
+        let (start, end) = match self.direction {
+            GradientDirection::Horizontal => (a.0, b.0),
+            GradientDirection::Vertical => (a.1, b.1),
+        };
+        let span = (end - start).abs();
+        if span == 0 {
+            return Ok(());
+        }
+
+        // At most one strip per pixel, each strip ends right before the next one starts, so the
+        // seams aren't painted twice, and the last strip ends exactly at the far edge
+        let strips = span.min(MAX_GRADIENT_STRIPS);
+        let sign = (end - start).signum();
+        let edge = |i: i32| start + sign * (span * i / strips);
+        for i in 0..strips {
+            let t = if strips > 1 {
+                f64::from(i) / f64::from(strips - 1)
+            } else {
+                0.5
+            };
+            let color = lerp_rgba(self.colors.0, self.colors.1, t);
+            let (from, to) = if i + 1 < strips {
+                (edge(i), edge(i + 1) - sign)
+            } else {
+                (edge(i), end)
+            };
+            let (upper_left, bottom_right) = match self.direction {
+                GradientDirection::Horizontal => {
+                    ((from.min(to), a.1.min(b.1)), (from.max(to), a.1.max(b.1)))
+                }
+                GradientDirection::Vertical => {
+                    ((a.0.min(b.0), from.min(to)), (a.0.max(b.0), from.max(to)))
+                }
+            };
+            backend.draw_rect(upper_left, bottom_right, &color.to_backend_color(), true)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_gradient_rect_element() {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let rects = Rc::new(RefCell::new(vec![]));
+    let rects_log = rects.clone();
+    let da = crate::create_mocked_drawing_area(1000, 300, move |m| {
+        m.check_draw_rect(move |c, _, f, u, d| {
+            assert!(f);
+            rects_log.borrow_mut().push((c, u, d));
+        });
+    });
+
+    da.draw(&GradientRectangle::new(
+        [(10, 10), (20, 50)],
+        RED.to_rgba(),
+        BLUE.to_rgba(),
+        GradientDirection::Vertical,
+    ))
+    .expect("Drawing Failure");
+    {
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 40);
+        assert_eq!(rects[0], (RED.to_rgba(), (10, 10), (20, 10)));
+        assert_eq!(rects[1].1, (10, 11));
+        assert_eq!(rects[39], (BLUE.to_rgba(), (10, 49), (20, 50)));
+    }
+
+    // A wide rectangle is drawn with the limited number of strips from right to left, which
+    // still tile the whole rectangle without overlapping
+    rects.borrow_mut().clear();
+    da.draw(&GradientRectangle::new(
+        [(900, 0), (0, 10)],
+        RED.to_rgba(),
+        BLUE.to_rgba(),
+        GradientDirection::Horizontal,
+    ))
+    .expect("Drawing Failure");
+    let rects = rects.borrow();
+    assert_eq!(rects.len(), 256);
+    assert_eq!(rects[0].0, RED.to_rgba());
+    assert_eq!((rects[0].2).0, 900);
+    assert_eq!((rects[255].1).0, 0);
+    assert!(rects.windows(2).all(|w| (w[0].1).0 == (w[1].2).0 + 1));
+}
+
 /// A circle element
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
@@ -297,6 +889,84 @@ fn test_circle_element() {
         .expect("Drawing Failure");
 }
 
+/// An axis-aligned ellipse element
+pub struct Ellipse<Coord, Size: SizeDesc> {
+    center: Coord,
+    radius: (Size, Size),
+    style: ShapeStyle,
+    fill: bool,
+}
+
+impl<Coord, Size: SizeDesc> Ellipse<Coord, Size> {
+    /// Create a new ellipse element
+    /// - `center` The center of the ellipse
+    /// - `radius` The horizontal and vertical radii of the ellipse
+    /// - `style` The style of the ellipse
+    /// - `fill` Whether the ellipse is filled, otherwise its outline is drawn
+    /// - Return: The newly created ellipse element
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: (Size, Size),
+        style: S,
+        fill: bool,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            style: style.into(),
+            fill,
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Ellipse<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Ellipse<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let rx = self.radius.0.in_pixels(&ps).max(0) as u32;
+            let ry = self.radius.1.in_pixels(&ps).max(0) as u32;
+            return backend.draw_ellipse((x, y), (rx, ry), &self.style, self.fill);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert!(p.len() >= 8);
+            assert!(p.iter().all(|&(x, y)| {
+                let (dx, dy) = (f64::from(x - 150) / 40.0, f64::from(y - 151) / 20.0);
+                (dx * dx + dy * dy - 1.0).abs() < 0.1
+            }));
+            assert_eq!(p.iter().map(|p| p.0).max(), Some(190));
+            assert_eq!(p.iter().map(|p| p.1).min(), Some(131));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.draw_count, 1);
+        });
+    });
+    da.draw(&Ellipse::new((150, 151), (40, 20), BLUE, true))
+        .expect("Drawing Failure");
+}
+
 /// An element of a filled polygon
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
@@ -356,3 +1026,142 @@ fn test_polygon_element() {
     da.draw(&Polygon::new(points.clone(), &BLUE))
         .expect("Drawing Failure");
 }
+
+/// The head of an [`Arrow`], in pixels
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArrowHead {
+    /// The length of the head along the shaft
+    pub length: u32,
+    /// The width of the head across the shaft
+    pub width: u32,
+    /// Whether the head is a filled triangle, otherwise it's an open `V` drawn with the stroke
+    pub filled: bool,
+}
+
+impl Default for ArrowHead {
+    fn default() -> Self {
+        Self {
+            length: 10,
+            width: 8,
+            filled: true,
+        }
+    }
+}
+
+/// An arrow from the start point to the end point, with the head at the end point
+pub struct Arrow<Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    head: ArrowHead,
+}
+
+impl<Coord> Arrow<Coord> {
+    /// Create a new arrow
+    /// - `from`: The start point of the arrow
+    /// - `to`: The end point of the arrow, where the head points to
+    /// - `style`: The shape style
+    /// - `head`: The size and the kind of the head
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S, head: ArrowHead) -> Self {
+        Self {
+            points: [from, to],
+            style: style.into(),
+            head,
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arrow<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = match (points.next(), points.next()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+
+        let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 {
+            return Ok(());
+        }
+
+        // The head is computed along the shaft direction, with the offsets rotated from the
+        // (length, width) of the head pointing to the right
+        let (ux, uy) = (dx / length, dy / length);
+        let (head_length, half_width) = (
+            f64::from(self.head.length),
+            f64::from(self.head.width) / 2.0,
+        );
+        let head_point = |along: f64, across: f64| {
+            (
+                (f64::from(to.0) - ux * along - uy * across).round() as i32,
+                (f64::from(to.1) - uy * along + ux * across).round() as i32,
+            )
+        };
+        let left = head_point(head_length, half_width);
+        let right = head_point(head_length, -half_width);
+
+        if self.head.filled {
+            // The shaft stops at the base of the head, so a wide stroke doesn't stick out of the tip
+            backend.draw_line(from, head_point(head_length, 0.0), &self.style)?;
+            backend.fill_polygon([left, to, right], &self.style.color.to_backend_color())
+        } else {
+            backend.draw_line(from, to, &self.style)?;
+            backend.draw_path([left, to, right], &self.style)
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arrow_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!([from, to], [(100, 100), (190, 100)]);
+        });
+        m.check_draw_line(|_, _, from, to| {
+            assert_eq!([from, to], [(100, 100), (100, 200)]);
+        });
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(p, vec![(190, 104), (200, 100), (190, 96)]);
+        });
+        m.check_draw_path(|_, _, p| {
+            assert_eq!(p, vec![(96, 190), (100, 200), (104, 190)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 2);
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let head = ArrowHead::default();
+    da.draw(&Arrow::new((100, 100), (200, 100), BLUE, head))
+        .expect("Drawing Failure");
+    da.draw(&Arrow::new(
+        (100, 100),
+        (100, 200),
+        BLUE,
+        ArrowHead {
+            filled: false,
+            ..head
+        },
+    ))
+    .expect("Drawing Failure");
+    da.draw(&Arrow::new((100, 100), (100, 100), BLUE, head))
+        .expect("Drawing Failure");
+}
@@ -106,3 +106,326 @@ impl<X, Y, Z, DB: DrawingBackend> Drawable<DB, BackendCoordAndZ> for Cubiod<X, Y
         Ok(())
     }
 }
+
+/**
+Represents a surface, a grid of `(X, Y, Z)` points drawn as quadrilateral faces.
+
+The faces are drawn from back to front, so the faces near to the viewer occlude the faces far
+from it. The style of each face can be computed from its corners, which makes it possible to
+color the surface by its height.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("surface.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+let mut chart_context = chart_builder.margin(20).build_cartesian_3d(-2.0..2.0, 0.0..4.0, -2.0..2.0).unwrap();
+chart_context.configure_axes().draw().unwrap();
+let grid = (-10..=10).map(|x| {
+    (-10..=10).map(move |z| {
+        let (x, z) = (x as f64 / 5.0, z as f64 / 5.0);
+        (x, x * x + z * z, z)
+    })
+});
+let surface = Surface::new(grid, BLUE.filled())
+    .face_style_func(|[a, b, c, d]| {
+        let y = (a.1 + b.1 + c.1 + d.1) / 32.0;
+        HSLColor(0.7 * (1.0 - y), 0.7, 0.5).filled()
+    })
+    .edge_style(BLACK.mix(0.3));
+chart_context.draw_series(std::iter::once(surface)).unwrap();
+```
+*/
+pub struct Surface<'a, X, Y, Z> {
+    face_style: Box<dyn Fn([&(X, Y, Z); 4]) -> ShapeStyle + 'a>,
+    edge_style: Option<ShapeStyle>,
+    columns: usize,
+    vert: Vec<(X, Y, Z)>,
+}
+
+impl<'a, X, Y, Z> Surface<'a, X, Y, Z> {
+    /**
+    Creates a surface from the rows of a grid. Each row is truncated to the length of the
+    shortest row.
+
+    - `grid`: The rows of the grid
+    - `face_style`: The style of the faces
+    - **returns**: The newly created surface
+
+    See [`Surface`] for more information and examples.
+    */
+    pub fn new<G, R, S>(grid: G, face_style: S) -> Self
+    where
+        G: IntoIterator<Item = R>,
+        R: IntoIterator<Item = (X, Y, Z)>,
+        S: Into<ShapeStyle>,
+    {
+        let rows: Vec<Vec<_>> = grid
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+        let columns = rows.iter().map(Vec::len).min().unwrap_or(0);
+        let vert = rows
+            .into_iter()
+            .flat_map(|row| row.into_iter().take(columns))
+            .collect();
+        let face_style = face_style.into();
+        Self {
+            face_style: Box::new(move |_| face_style),
+            edge_style: None,
+            columns,
+            vert,
+        }
+    }
+
+    /**
+    Computes the style of each face from its four corners, for example to color the faces with
+    a colormap of their height.
+
+    - `func`: The function mapping the corners of a face to its style
+    - **returns**: The surface with the face style function set
+    */
+    pub fn face_style_func<F: Fn([&(X, Y, Z); 4]) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.face_style = Box::new(func);
+        self
+    }
+
+    /**
+    Strokes the edges of the faces with the given style.
+
+    - `style`: The style of the edges
+    - **returns**: The surface with the edge style set
+    */
+    pub fn edge_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.edge_style = Some(style.into());
+        self
+    }
+
+    fn faces(&self) -> impl Iterator<Item = [usize; 4]> {
+        let columns = self.columns;
+        let rows = if columns == 0 {
+            0
+        } else {
+            self.vert.len() / columns
+        };
+        (0..rows.saturating_sub(1)).flat_map(move |r| {
+            (0..columns.saturating_sub(1)).map(move |c| {
+                let i = r * columns + c;
+                [i, i + 1, i + columns + 1, i + columns]
+            })
+        })
+    }
+}
+
+impl<'a, 'b, X: 'a, Y: 'a, Z: 'a> PointCollection<'a, (X, Y, Z), BackendCoordAndZ>
+    for &'a Surface<'b, X, Y, Z>
+{
+    type Point = &'a (X, Y, Z);
+    type IntoIter = &'a [(X, Y, Z)];
+    fn point_iter(self) -> Self::IntoIter {
+        &self.vert
+    }
+}
+
+impl<'a, X, Y, Z, DB: DrawingBackend> Drawable<DB, BackendCoordAndZ> for Surface<'a, X, Y, Z> {
+    fn draw<I: Iterator<Item = (BackendCoord, i32)>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = points.collect();
+        if vert.len() != self.vert.len() {
+            return Ok(());
+        }
+        let mut faces: Vec<_> = self.faces().collect();
+        faces.sort_by_cached_key(|f| {
+            std::cmp::Reverse(f.iter().map(|&i| vert[i].1 as i64).sum::<i64>())
+        });
+
+        for f in faces {
+            let style = (self.face_style)(f.map(|i| &self.vert[i]));
+            backend.fill_polygon(f.iter().map(|&i| vert[i].0), &style)?;
+            if let Some(edge_style) = &self.edge_style {
+                backend.draw_path(
+                    f.iter()
+                        .map(|&i| vert[i].0)
+                        .chain(std::iter::once(vert[f[0]].0)),
+                    edge_style,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**
+Represents a sphere, a filled circle placed at a 3D point, whose radius shrinks as the point
+gets farther from the viewer. It is typically used as the marker of a 3D scatter plot.
+
+Spheres drawn as a series are not sorted by their depth, they are drawn in the order of the
+series.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("sphere.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+let mut chart_context = chart_builder.margin(20).build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0).unwrap();
+chart_context.configure_axes().draw().unwrap();
+chart_context.draw_series(
+    [(0.2, 0.3, 0.8), (0.5, 0.5, 0.5), (0.8, 0.7, 0.2)]
+        .iter()
+        .map(|&p| Sphere::new(p, 8, BLUE.filled()).depth_scale(0.002)),
+).unwrap();
+```
+*/
+pub struct Sphere<X, Y, Z> {
+    center: (X, Y, Z),
+    radius: u32,
+    depth_scale: f64,
+    style: ShapeStyle,
+}
+
+impl<X, Y, Z> Sphere<X, Y, Z> {
+    /// The default fraction of the radius lost per pixel of depth
+    pub const DEFAULT_DEPTH_SCALE: f64 = 0.001;
+
+    /**
+    Creates a sphere.
+
+    - `center`: The center of the sphere
+    - `radius`: The radius in pixels, at the depth of the screen
+    - `style`: The style of the sphere
+    - **returns**: The newly created sphere
+
+    See [`Sphere`] for more information and examples.
+    */
+    pub fn new<S: Into<ShapeStyle>>(center: (X, Y, Z), radius: u32, style: S) -> Self {
+        Self {
+            center,
+            radius,
+            depth_scale: Self::DEFAULT_DEPTH_SCALE,
+            style: style.into(),
+        }
+    }
+
+    /**
+    Sets how fast the radius shrinks with the depth. The radius in pixels is
+    `radius * (1 - factor * depth)`, where the depth is the distance from the screen in pixels,
+    and it is never less than 1 pixel.
+
+    - `factor`: The fraction of the radius lost per pixel of depth
+    - **returns**: The sphere with the depth scale set
+    */
+    pub fn depth_scale(mut self, factor: f64) -> Self {
+        self.depth_scale = factor;
+        self
+    }
+
+    fn projected_radius(&self, depth: i32) -> u32 {
+        let radius = self.radius as f64 * (1.0 - self.depth_scale * depth as f64);
+        radius.round().max(1.0) as u32
+    }
+}
+
+impl<'a, X: 'a, Y: 'a, Z: 'a> PointCollection<'a, (X, Y, Z), BackendCoordAndZ>
+    for &'a Sphere<X, Y, Z>
+{
+    type Point = &'a (X, Y, Z);
+    type IntoIter = std::iter::Once<&'a (X, Y, Z)>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<X, Y, Z, DB: DrawingBackend> Drawable<DB, BackendCoordAndZ> for Sphere<X, Y, Z> {
+    fn draw<I: Iterator<Item = (BackendCoord, i32)>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((coord, depth)) = points.next() {
+            backend.draw_circle(
+                coord,
+                self.projected_radius(depth),
+                &self.style,
+                self.style.filled,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_surface_depth_order() {
+        let mut backend = MockedBackend::new(100, 100);
+        let surface = Surface::new(
+            vec![
+                vec![(0, 0, 0), (1, 0, 0), (2, 0, 0)],
+                vec![(0, 0, 1), (1, 0, 1), (2, 0, 1)],
+            ],
+            RED,
+        )
+        .face_style_func(|[a, _, _, _]| if a.0 == 0 { RED } else { BLUE }.filled())
+        .edge_style(BLACK);
+        // The right face is farther from the viewer, so it must be drawn first
+        let points = [
+            ((0, 0), 0),
+            ((10, 0), 5),
+            ((20, 0), 10),
+            ((0, 10), 0),
+            ((10, 10), 5),
+            ((20, 10), 10),
+        ];
+        backend.check_fill_polygon(|c, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path, vec![(10, 0), (20, 0), (20, 10), (10, 10)]);
+        });
+        backend.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLACK.to_rgba());
+            assert_eq!(path.len(), 5);
+        });
+        backend.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, vec![(0, 0), (10, 0), (10, 10), (0, 10)]);
+        });
+        surface
+            .draw(points.iter().cloned(), &mut backend, (100, 100))
+            .unwrap();
+        assert_eq!(backend.num_fill_polygon_call, 2);
+        assert_eq!(backend.num_draw_path_call, 2);
+    }
+
+    #[test]
+    fn test_sphere_radius() {
+        let mut backend = MockedBackend::new(100, 100);
+        backend.check_draw_circle(|c, _, filled, center, radius| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(filled);
+            assert_eq!(center, (50, 50));
+            assert_eq!(radius, 10);
+        });
+        backend.check_draw_circle(|_, _, _, _, radius| assert_eq!(radius, 5));
+        backend.check_draw_circle(|_, _, _, _, radius| assert_eq!(radius, 1));
+        let sphere = Sphere::new((0, 0, 0), 10, RED.filled()).depth_scale(0.01);
+        for depth in [0, 50, 200] {
+            sphere
+                .draw(std::iter::once(((50, 50), depth)), &mut backend, (100, 100))
+                .unwrap();
+        }
+        assert_eq!(backend.num_draw_circle_call, 3);
+    }
+}
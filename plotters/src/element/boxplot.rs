@@ -48,14 +48,28 @@ impl<K, V> BoxplotOrient<K, V> for BoxplotOrientH<K, V> {
 
 const DEFAULT_WIDTH: u32 = 10;
 
+/// The marker of the mean of a boxplot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoxplotMean {
+    /// A diamond centered at the mean, with the given half diagonal in pixels
+    Diamond(u32),
+    /// A line across the bar at the mean
+    Line,
+}
+
 /// The boxplot element
 pub struct Boxplot<K, O: BoxplotOrient<K, f32>> {
     style: ShapeStyle,
+    median_style: Option<ShapeStyle>,
+    mean_marker: Option<BoxplotMean>,
+    mean_style: Option<ShapeStyle>,
     width: u32,
     whisker_width: f64,
+    caps: bool,
     offset: f64,
     key: K,
     values: [f32; 5],
+    mean: f32,
     _p: PhantomData<O>,
 }
 
@@ -75,11 +89,16 @@ impl<K: Clone> Boxplot<K, BoxplotOrientV<K, f32>> {
     pub fn new_vertical(key: K, quartiles: &Quartiles) -> Self {
         Self {
             style: Into::<ShapeStyle>::into(&BLACK),
+            median_style: None,
+            mean_marker: None,
+            mean_style: None,
             width: DEFAULT_WIDTH,
             whisker_width: 1.0,
+            caps: true,
             offset: 0.0,
             key,
             values: quartiles.values(),
+            mean: quartiles.mean() as f32,
             _p: PhantomData,
         }
     }
@@ -101,11 +120,16 @@ impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f32>> {
     pub fn new_horizontal(key: K, quartiles: &Quartiles) -> Self {
         Self {
             style: Into::<ShapeStyle>::into(&BLACK),
+            median_style: None,
+            mean_marker: None,
+            mean_style: None,
             width: DEFAULT_WIDTH,
             whisker_width: 1.0,
+            caps: true,
             offset: 0.0,
             key,
             values: quartiles.values(),
+            mean: quartiles.mean() as f32,
             _p: PhantomData,
         }
     }
@@ -128,6 +152,58 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self
     }
 
+    /// Set the style of the median line, which is the style of the boxplot by default.
+    ///
+    /// - `S`: The required style
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).median_style(RED.stroke_width(2));
+    /// ```
+    pub fn median_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.median_style = Some(style.into());
+        self
+    }
+
+    /// Draw the mean of the values with the marker, in addition to the median. The mean isn't
+    /// drawn by default.
+    ///
+    /// - `marker`: The marker of the mean
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).mean(BoxplotMean::Diamond(3));
+    /// ```
+    pub fn mean(mut self, marker: BoxplotMean) -> Self {
+        self.mean_marker = Some(marker);
+        self
+    }
+
+    /// Set the style of the mean marker, which is the style of the boxplot by default. A filled
+    /// style fills the diamond marker, and a dashed style makes a dashed mean line.
+    ///
+    /// - `S`: The required style
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles)
+    ///     .mean(BoxplotMean::Line)
+    ///     .mean_style(ShapeStyle::from(&BLUE).dashed(&[2, 2]));
+    /// ```
+    pub fn mean_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.mean_style = Some(style.into());
+        self
+    }
+
     /// Set the bar width.
     ///
     /// - `width`: The required width
@@ -144,7 +220,7 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self
     }
 
-    /// Set the width of the whiskers as a fraction of the bar width.
+    /// Set the width of the whisker caps as a fraction of the bar width.
     ///
     /// - `whisker_width`: The required fraction
     /// - **returns** The up-to-dated boxplot element
@@ -160,6 +236,22 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self
     }
 
+    /// Set whether to draw the caps at the ends of the whiskers, which are drawn by default.
+    ///
+    /// - `caps`: Whether to draw the caps
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).caps(false);
+    /// ```
+    pub fn caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+
     /// Set the element offset on the key axis.
     ///
     /// - `offset`: The required offset (on the X axis for vertical, on the Y axis for horizontal)
@@ -183,8 +275,10 @@ impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::Y
     type Point = (O::XType, O::YType);
     type IntoIter = Vec<Self::Point>;
     fn point_iter(self) -> Self::IntoIter {
+        let mean = self.mean_marker.map(|_| self.mean);
         self.values
             .iter()
+            .chain(mean.iter())
             .map(|v| O::make_coord(self.key.clone(), *v))
             .collect()
     }
@@ -197,8 +291,8 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        let points: Vec<_> = points.take(5).collect();
-        if points.len() == 5 {
+        let points: Vec<_> = points.take(6).collect();
+        if points.len() >= 5 {
             let width = f64::from(self.width);
             let moved = |coord| O::with_offset(coord, self.offset);
             let start_bar = |coord| O::with_offset(moved(coord), -width / 2.0);
@@ -210,11 +304,13 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
 
             // |---[   |  ]----|
             // ^________________
-            backend.draw_line(
-                start_whisker(points[0]),
-                end_whisker(points[0]),
-                &self.style,
-            )?;
+            if self.caps {
+                backend.draw_line(
+                    start_whisker(points[0]),
+                    end_whisker(points[0]),
+                    &self.style,
+                )?;
+            }
 
             // |---[   |  ]----|
             // _^^^_____________
@@ -235,7 +331,11 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
 
             // |---[   |  ]----|
             // ________^________
-            backend.draw_line(start_bar(points[2]), end_bar(points[2]), &self.style)?;
+            backend.draw_line(
+                start_bar(points[2]),
+                end_bar(points[2]),
+                self.median_style.as_ref().unwrap_or(&self.style),
+            )?;
 
             // |---[   |  ]----|
             // ____________^^^^_
@@ -243,11 +343,35 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
 
             // |---[   |  ]----|
             // ________________^
-            backend.draw_line(
-                start_whisker(points[4]),
-                end_whisker(points[4]),
-                &self.style,
-            )?;
+            if self.caps {
+                backend.draw_line(
+                    start_whisker(points[4]),
+                    end_whisker(points[4]),
+                    &self.style,
+                )?;
+            }
+
+            // |---[ + |  ]----|
+            // ______^__________
+            if let (Some(marker), Some(&mean)) = (self.mean_marker, points.get(5)) {
+                let style = self.mean_style.as_ref().unwrap_or(&self.style);
+                match marker {
+                    BoxplotMean::Diamond(size) => {
+                        let size = size as i32;
+                        let (x, y) = moved(mean);
+                        let diamond = [(x, y - size), (x + size, y), (x, y + size), (x - size, y)];
+                        if style.filled {
+                            backend.fill_polygon(diamond, style)?;
+                        } else {
+                            backend
+                                .draw_path(diamond.iter().chain(&diamond[..1]).copied(), style)?;
+                        }
+                    }
+                    BoxplotMean::Line => {
+                        backend.draw_line(start_bar(mean), end_bar(mean), style)?;
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -285,4 +409,69 @@ mod test {
             .draw(&Boxplot::new_horizontal(1, &values))
             .is_ok());
     }
+
+    #[test]
+    fn test_caps_and_median_style() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|c, s, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(s, 1);
+            });
+            m.check_draw_line(|c, s, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 3);
+                assert_eq!(from.1, to.1);
+            });
+            m.check_draw_line(|c, s, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(s, 1);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_rect_call, 1);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..2, 0f32..100f32)
+            .unwrap();
+        let values = Quartiles::new(&[10, 20, 30, 40, 50]);
+        chart
+            .plotting_area()
+            .draw(
+                &Boxplot::new_vertical(1, &values)
+                    .caps(false)
+                    .median_style(RED.stroke_width(3)),
+            )
+            .expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_mean_marker() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path.len(), 4);
+                assert_eq!(path[0].0 + 4, path[1].0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_line_call, 5);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..2, 0f32..100f32)
+            .unwrap();
+        let values = Quartiles::new(&[10, 20, 30, 40, 100]);
+        assert_eq!(values.mean(), 40.0);
+        chart
+            .plotting_area()
+            .draw(
+                &Boxplot::new_vertical(1, &values)
+                    .mean(BoxplotMean::Diamond(4))
+                    .mean_style(BLUE.filled()),
+            )
+            .expect("Drawing Failure");
+    }
 }
@@ -1,5 +1,5 @@
 /*!
-  The candlestick element, which showing the high/low/open/close price
+  The candlestick and the OHLC bar elements, which showing the high/low/open/close price
 */
 
 use std::cmp::Ordering;
@@ -98,3 +98,128 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         Ok(())
     }
 }
+
+/// The OHLC bar data point element, which is a vertical line from the low value to the high
+/// value, with a tick on the left for the open value and a tick on the right for the close value
+pub struct OhlcBar<X, Y: PartialOrd> {
+    style: ShapeStyle,
+    width: u32,
+    points: [(X, Y); 4],
+}
+
+impl<X: Clone, Y: PartialOrd> OhlcBar<X, Y> {
+    /// Create a new OHLC bar element, which requires the Y coordinate can be compared
+    ///
+    /// - `x`: The x coordinate
+    /// - `open`: The open value
+    /// - `high`: The high value
+    /// - `low`: The low value
+    /// - `close`: The close value
+    /// - `gain_style`: The style for gain
+    /// - `loss_style`: The style for loss
+    /// - `width`: The width, which is the distance between the ends of the open and close ticks
+    /// - **returns** The newly created OHLC bar element
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use plotters::prelude::*;
+    ///
+    /// let bar = OhlcBar::new(Local::now(), 130.0600, 131.3700, 128.8300, 129.1500, &GREEN, &RED, 15);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        x: X,
+        open: Y,
+        high: Y,
+        low: Y,
+        close: Y,
+        gain_style: GS,
+        loss_style: LS,
+        width: u32,
+    ) -> Self {
+        Self {
+            style: match open.partial_cmp(&close) {
+                Some(Ordering::Less) => gain_style.into(),
+                _ => loss_style.into(),
+            },
+            width,
+            points: [
+                (x.clone(), open),
+                (x.clone(), high),
+                (x.clone(), low),
+                (x, close),
+            ],
+        }
+    }
+}
+
+impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a OhlcBar<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for OhlcBar<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(4).collect();
+        if points.len() == 4 {
+            let (open, high, low, close) = (points[0], points[1], points[2], points[3]);
+            let (l, r) = (
+                self.width as i32 / 2,
+                self.width as i32 - self.width as i32 / 2,
+            );
+
+            backend.draw_line(high, low, &self.style)?;
+            backend.draw_line((open.0 - l, open.1), open, &self.style)?;
+            backend.draw_line(close, (close.0 + r, close.1), &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ohlc_bar() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let lines_log = lines.clone();
+        let drawing_area = create_mocked_drawing_area(100, 100, move |m| {
+            m.check_draw_line(move |c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                lines_log.borrow_mut().push((from, to));
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0..100)
+            .unwrap();
+        chart
+            .plotting_area()
+            .draw(&OhlcBar::new(50, 60, 80, 20, 40, GREEN, RED, 10))
+            .unwrap();
+
+        let x = chart.backend_coord(&(50, 0)).0;
+        let y = |v| chart.backend_coord(&(50, v)).1;
+        assert_eq!(
+            *lines.borrow(),
+            vec![
+                ((x, y(80)), (x, y(20))),
+                ((x - 5, y(60)), (x, y(60))),
+                ((x, y(40)), (x + 5, y(40))),
+            ]
+        );
+    }
+}
@@ -168,6 +168,56 @@ where
     phantom: PhantomData<DB>,
 }
 
+impl<'e, Coord, DB: DrawingBackend + 'e>
+    ComposedElement<Coord, DB, DynElement<'e, DB, BackendCoord>, DynElement<'e, DB, BackendCoord>>
+{
+    /**
+    Composes a dynamic number of elements, which is useful when the number of the parts isn't
+    known at compile time, for example, a connector with a variable number of segments.
+
+    - `anchor`: The guest coordinate the elements are positioned relative to
+    - `elements`: The elements in backend coordinates relative to the anchor, they are drawn in
+      the iteration order
+    - Returns: The composed element
+
+    ```
+    use plotters::element::ComposedElement;
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("composed_from_iter.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..5.0, 0.0..5.0)
+        .unwrap();
+    chart
+        .draw_series(std::iter::once(ComposedElement::from_iter(
+            (2.5, 2.5),
+            (0..5).map(|i| {
+                PathElement::new(vec![(i * 10, 0), (i * 10 + 10, (i % 2) * 10)], BLUE).into_dyn()
+            }),
+        )))
+        .unwrap();
+    ```
+    */
+    pub fn from_iter<I>(anchor: Coord, elements: I) -> Self
+    where
+        I: IntoIterator<Item = DynElement<'e, DB, BackendCoord>>,
+    {
+        let mut elements = elements.into_iter();
+        let first = elements
+            .next()
+            .unwrap_or_else(|| EmptyElement::at((0, 0)).into_dyn());
+        let second = elements.fold(EmptyElement::at((0, 0)).into_dyn(), |rest, elem| {
+            (EmptyElement::at((0, 0)) + rest + elem).into_dyn()
+        });
+        ComposedElement {
+            offset: anchor,
+            first,
+            second,
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl<'b, Coord, DB: DrawingBackend, A, B> PointCollection<'b, Coord>
     for &'b ComposedElement<Coord, DB, A, B>
 where
@@ -240,3 +290,43 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_composed_element_from_iter() {
+        let colors = [RED, GREEN, BLUE];
+        let log = record_draw_calls(100, 100, |root| {
+            let element = ComposedElement::from_iter(
+                (10, 20),
+                colors.iter().enumerate().map(|(i, color)| {
+                    let i = i as i32;
+                    PathElement::new(vec![(i, 0), (i, 5)], *color).into_dyn()
+                }),
+            );
+            root.draw(&element).unwrap();
+        });
+
+        let paths: Vec<_> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| {
+                format!(
+                    "draw_path {:?} 1 {:?}",
+                    color.to_rgba(),
+                    vec![(10 + i as i32, 20), (10 + i as i32, 25)]
+                )
+            })
+            .collect();
+        assert_eq!(log, paths);
+
+        let log = record_draw_calls(100, 100, |root| {
+            let element = ComposedElement::from_iter((10, 20), std::iter::empty());
+            root.draw(&element).unwrap();
+        });
+        assert!(log.is_empty());
+    }
+}
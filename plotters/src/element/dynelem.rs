@@ -33,7 +33,7 @@ where
     drawable: Box<dyn DynDrawable<DB> + 'a>,
 }
 
-impl<'a, 'b: 'a, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
+impl<'a, 'b, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
     for &'a DynElement<'b, DB, Coord>
 {
     type Point = &'a Coord;
@@ -5,7 +5,7 @@
 use image::{DynamicImage, GenericImageView};
 
 use super::{Drawable, PointCollection};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, BitmapFormat, DrawingBackend, DrawingErrorKind};
 
 use plotters_bitmap::bitmap_pixel::{PixelFormat, RGBPixel};
 
@@ -59,11 +59,30 @@ impl<'a> Buffer<'a> {
     }
 }
 
+/// The filter used for resampling a bitmap element which is drawn at a different size than its
+/// source, see [`BitMapElement::scale_to`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Use the nearest source pixel, which is fast and keeps the hard edges of the pixels
+    Nearest,
+    /// Blend the four nearest source pixels, which looks smoother for photos
+    Bilinear,
+}
+
+impl Default for Interpolation {
+    fn default() -> Self {
+        Interpolation::Nearest
+    }
+}
+
 /// The element that contains a bitmap on it
 pub struct BitMapElement<'a, Coord, P: PixelFormat = RGBPixel> {
     image: Buffer<'a>,
     size: (u32, u32),
     pos: Coord,
+    draw_size: Option<(u32, u32)>,
+    interpolation: Interpolation,
+    format: BitmapFormat,
     phantom: PhantomData<P>,
 }
 
@@ -78,6 +97,9 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(vec![0; (size.0 * size.1) as usize * P::PIXEL_SIZE]),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format: BitmapFormat::Rgb,
             phantom: PhantomData,
         }
     }
@@ -99,6 +121,9 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(buf),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format: BitmapFormat::Rgb,
             phantom: PhantomData,
         })
     }
@@ -119,6 +144,9 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::BorrowedMut(buf),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format: BitmapFormat::Rgb,
             phantom: PhantomData,
         })
     }
@@ -140,6 +168,9 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(buf),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format: BitmapFormat::Rgb,
             phantom: PhantomData,
         })
     }
@@ -152,6 +183,9 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(self.image.borrow()),
             size: self.size,
             pos,
+            draw_size: self.draw_size,
+            interpolation: self.interpolation,
+            format: self.format,
             phantom: PhantomData,
         }
     }
@@ -163,11 +197,82 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
         self.pos = pos;
     }
 
+    /// Draw the bitmap at the given size rather than the size of the source bitmap, the bitmap is
+    /// resampled with the filter set by [`BitMapElement::interpolation`] when it's drawn
+    ///
+    /// - `size`: The size of the bitmap on the drawing backend
+    pub fn scale_to(mut self, size: (u32, u32)) -> Self {
+        self.draw_size = Some(size);
+        self
+    }
+
+    /// Set the filter used for resampling the bitmap when it's drawn at a different size,
+    /// by default the nearest pixel is used
+    ///
+    /// - `interpolation`: The resampling filter
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
     /// Make the bitmap element as a bitmap backend, so that we can use
-    /// plotters drawing functionality on the bitmap element
+    /// plotters drawing functionality on the bitmap element.
+    /// A bitmap with an alpha channel is converted into RGB first, which drops the alpha channel.
     pub fn as_bitmap_backend(&mut self) -> BitMapBackend<P> {
+        if self.format != BitmapFormat::Rgb {
+            self.image = Buffer::Owned(self.format.to_rgb(self.image.as_ref()));
+            self.format = BitmapFormat::Rgb;
+        }
         BitMapBackend::with_buffer_and_format(self.image.to_mut(), self.size).unwrap()
     }
+
+    /// Get the pixel format of the bitmap, which is RGB unless the bitmap is created with
+    /// [`BitMapElement::with_owned_buffer_and_format`] or from an image with transparency
+    pub fn format(&self) -> BitmapFormat {
+        self.format
+    }
+
+    /// Get the size of the bitmap
+    pub(crate) fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Get the raw pixel data of the bitmap
+    pub(crate) fn pixels(&self) -> &[u8] {
+        self.image.as_ref()
+    }
+}
+
+impl<'a, Coord> BitMapElement<'a, Coord, RGBPixel> {
+    /// Create a new bitmap element with an pre-allocated owned buffer in the given pixel format.
+    /// The bitmaps with an alpha channel are composited onto the existing content when drawn.
+    ///
+    /// - `pos`: The left upper coordinate of the elelent
+    /// - `size`: The size of the bitmap
+    /// - `buf`: The buffer to use
+    /// - `format`: The pixel format of the buffer
+    /// - **returns**: The newly created image element, if the buffer isn't fit the image
+    ///   dimension, this will returns an `None`.
+    pub fn with_owned_buffer_and_format(
+        pos: Coord,
+        size: (u32, u32),
+        buf: Vec<u8>,
+        format: BitmapFormat,
+    ) -> Option<Self> {
+        if buf.len() < (size.0 * size.1) as usize * format.pixel_size() {
+            return None;
+        }
+
+        Some(Self {
+            image: Buffer::Owned(buf),
+            size,
+            pos,
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format,
+            phantom: PhantomData,
+        })
+    }
 }
 
 #[cfg(all(
@@ -177,11 +282,19 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
 impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixel> {
     fn from((pos, image): (Coord, DynamicImage)) -> Self {
         let (w, h) = image.dimensions();
-        let rgb_image = image.to_rgb8().into_raw();
+        // Keep the alpha channel, so that a transparent image is composited when drawn
+        let (buf, format) = if image.color().has_alpha() {
+            (image.to_rgba8().into_raw(), BitmapFormat::Rgba)
+        } else {
+            (image.to_rgb8().into_raw(), BitmapFormat::Rgb)
+        };
         Self {
             pos,
-            image: Buffer::Owned(rgb_image),
+            image: Buffer::Owned(buf),
             size: (w, h),
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format,
             phantom: PhantomData,
         }
     }
@@ -199,6 +312,9 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            draw_size: None,
+            interpolation: Interpolation::Nearest,
+            format: BitmapFormat::Rgb,
             phantom: PhantomData,
         }
     }
@@ -220,9 +336,154 @@ impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
-            // TODO: convert the pixel format when needed
-            return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
+            return match self.draw_size {
+                Some(size) if size != self.size => {
+                    let image = resample(
+                        self.image.as_ref(),
+                        self.size,
+                        size,
+                        self.interpolation,
+                        self.format.pixel_size(),
+                    );
+                    backend.blit_bitmap_fmt((x, y), size, &image, self.format)
+                }
+                _ => backend.blit_bitmap_fmt((x, y), self.size, self.image.as_ref(), self.format),
+            };
         }
         Ok(())
     }
 }
+
+/// Resample a bitmap of size `(sw, sh)` into a new bitmap of size `(dw, dh)`, each pixel of
+/// which is `pixel_size` bytes
+fn resample(
+    src: &[u8],
+    (sw, sh): (u32, u32),
+    (dw, dh): (u32, u32),
+    interpolation: Interpolation,
+    pixel_size: usize,
+) -> Vec<u8> {
+    let mut dst = vec![0; (dw * dh) as usize * pixel_size];
+    if sw == 0 || sh == 0 {
+        return dst;
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let offset = (y * sw as usize + x) * pixel_size;
+        &src[offset..offset + pixel_size]
+    };
+    // Map the center of a destination pixel to the source bitmap
+    let map =
+        |d: u32, dsize: u32, ssize: u32| (f64::from(d) + 0.5) * f64::from(ssize) / f64::from(dsize);
+
+    for y in 0..dh {
+        let sy = map(y, dh, sh);
+        for x in 0..dw {
+            let sx = map(x, dw, sw);
+            let offset = (y * dw + x) as usize * pixel_size;
+            let target = &mut dst[offset..offset + pixel_size];
+            match interpolation {
+                Interpolation::Nearest => {
+                    let nx = (sx as usize).min(sw as usize - 1);
+                    let ny = (sy as usize).min(sh as usize - 1);
+                    target.copy_from_slice(pixel(nx, ny));
+                }
+                Interpolation::Bilinear => {
+                    let fx = (sx - 0.5).max(0.0).min(f64::from(sw - 1));
+                    let fy = (sy - 0.5).max(0.0).min(f64::from(sh - 1));
+                    let (x0, y0) = (fx as usize, fy as usize);
+                    let (x1, y1) = ((x0 + 1).min(sw as usize - 1), (y0 + 1).min(sh as usize - 1));
+                    let (tx, ty) = (fx - x0 as f64, fy - y0 as f64);
+                    for (c, value) in target.iter_mut().enumerate() {
+                        let top = f64::from(pixel(x0, y0)[c]) * (1.0 - tx)
+                            + f64::from(pixel(x1, y0)[c]) * tx;
+                        let bottom = f64::from(pixel(x0, y1)[c]) * (1.0 - tx)
+                            + f64::from(pixel(x1, y1)[c]) * tx;
+                        *value = (top * (1.0 - ty) + bottom * ty).round() as u8;
+                    }
+                }
+            }
+        }
+    }
+    dst
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resample() {
+        // A 2x1 bitmap with a black and a white pixel
+        let src = [0, 0, 0, 255, 255, 255];
+
+        let nearest = resample(&src, (2, 1), (4, 1), Interpolation::Nearest, 3);
+        assert_eq!(
+            &nearest[..],
+            &[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+
+        let bilinear = resample(&src, (2, 1), (4, 1), Interpolation::Bilinear, 3);
+        let red: Vec<_> = bilinear.chunks(3).map(|p| p[0]).collect();
+        assert_eq!(red, [0, 64, 191, 255]);
+
+        assert_eq!(
+            resample(&src, (2, 1), (2, 1), Interpolation::Bilinear, 3),
+            src
+        );
+    }
+
+    #[test]
+    fn test_scaled_bitmap_element() {
+        use crate::prelude::*;
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|c, (x, y)| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!((10..30).contains(&x) && (10..30).contains(&y));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 400);
+            });
+        });
+        let element = BitMapElement::<_>::with_owned_buffer((10, 10), (2, 2), vec![255; 12])
+            .unwrap()
+            .scale_to((20, 20))
+            .interpolation(Interpolation::Bilinear);
+        drawing_area.draw(&element).unwrap();
+    }
+
+    #[test]
+    fn test_rgba_bitmap_element() {
+        use crate::prelude::*;
+
+        let drawing_area = create_mocked_drawing_area(10, 10, |m| {
+            m.with_pixel_buffer();
+            m.drop_check(|b| {
+                assert_eq!(b.get_pixel(1, 1), RGBAColor(255, 0, 0, 1.0));
+                // The fully transparent pixel keeps the content under it
+                assert_eq!(b.get_pixel(2, 1), RGBAColor(255, 255, 255, 1.0));
+                // The half transparent black pixel is blended into a gray
+                let RGBAColor(r, g, b, a) = b.get_pixel(3, 1);
+                assert_eq!((r, a), (g, 1.0));
+                assert_eq!(r, b);
+                assert!((127..=128).contains(&r));
+            });
+        });
+        drawing_area.fill(&WHITE).unwrap();
+
+        let pixels = vec![255, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 128];
+        let element =
+            BitMapElement::with_owned_buffer_and_format((1, 1), (3, 1), pixels, BitmapFormat::Rgba)
+                .unwrap();
+        assert_eq!(element.format(), BitmapFormat::Rgba);
+        drawing_area.draw(&element).unwrap();
+        assert!(BitMapElement::<_>::with_owned_buffer_and_format(
+            (0, 0),
+            (3, 1),
+            vec![0; 9],
+            BitmapFormat::Rgba
+        )
+        .is_none());
+    }
+}
@@ -175,7 +175,7 @@ pub use composable::{ComposedElement, EmptyElement};
 #[cfg(feature = "candlestick")]
 mod candlestick;
 #[cfg(feature = "candlestick")]
-pub use candlestick::CandleStick;
+pub use candlestick::{CandleStick, OhlcBar};
 
 #[cfg(feature = "errorbar")]
 mod errorbar;
@@ -185,20 +185,37 @@ pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
 #[cfg(feature = "boxplot")]
 mod boxplot;
 #[cfg(feature = "boxplot")]
-pub use boxplot::Boxplot;
+pub use boxplot::{Boxplot, BoxplotMean};
+
+#[cfg(feature = "violin")]
+mod violin;
+#[cfg(feature = "violin")]
+pub use violin::{Violin, ViolinOrient, ViolinOrientH, ViolinOrientV};
 
 #[cfg(feature = "bitmap_backend")]
 mod image;
 #[cfg(feature = "bitmap_backend")]
-pub use self::image::BitMapElement;
+pub use self::image::{BitMapElement, Interpolation};
 
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
+mod tagged;
+pub use tagged::Tagged;
+
+mod area_between;
+pub use area_between::AreaBetween;
+
+mod path_markers;
+pub use path_markers::PathMarkers;
+
 mod pie;
 pub use pie::Pie;
 
-use crate::coord::CoordTranslate;
+mod polar_grid;
+pub use polar_grid::PolarGrid;
+
+use crate::coord::{CoordTranslate, NON_FINITE_COORD};
 use crate::drawing::Rect;
 
 /// A type which is logically a collection of points, under any given coordinate system.
@@ -257,6 +274,20 @@ pub trait CoordMapper {
     type Output;
     /// Performs the translation from guest coordinates to backend coordinates
     fn map<CT: CoordTranslate>(coord_trans: &CT, from: &CT::From, rect: &Rect) -> Self::Output;
+    /// Check if the translated output comes from a non-finite value, see [`NON_FINITE_COORD`],
+    /// such output is skipped by the drawing code. By default, nothing is skipped.
+    fn is_non_finite(_output: &Self::Output) -> bool {
+        false
+    }
+    /// Get the backend coordinate of the translated output, which is used to track the
+    /// bounding box of the data drawn on a chart. By default, the output has none.
+    fn backend_coord(_output: &Self::Output) -> Option<BackendCoord> {
+        None
+    }
+}
+
+fn is_non_finite_coord(coord: &BackendCoord) -> bool {
+    coord.0 == NON_FINITE_COORD || coord.1 == NON_FINITE_COORD
 }
 
 /// Used for 2d coordinate transformations.
@@ -265,7 +296,17 @@ pub struct BackendCoordOnly;
 impl CoordMapper for BackendCoordOnly {
     type Output = BackendCoord;
     fn map<CT: CoordTranslate>(coord_trans: &CT, from: &CT::From, rect: &Rect) -> BackendCoord {
-        rect.truncate(coord_trans.translate(from))
+        let coord = coord_trans.translate(from);
+        if is_non_finite_coord(&coord) {
+            return (NON_FINITE_COORD, NON_FINITE_COORD);
+        }
+        rect.truncate(coord)
+    }
+    fn is_non_finite(output: &BackendCoord) -> bool {
+        is_non_finite_coord(output)
+    }
+    fn backend_coord(output: &BackendCoord) -> Option<BackendCoord> {
+        Some(*output)
     }
 }
 
@@ -283,8 +324,17 @@ impl CoordMapper for BackendCoordAndZ {
         from: &CT::From,
         rect: &Rect,
     ) -> (BackendCoord, i32) {
-        let coord = rect.truncate(coord_trans.translate(from));
+        let coord = coord_trans.translate(from);
+        if is_non_finite_coord(&coord) {
+            return ((NON_FINITE_COORD, NON_FINITE_COORD), 0);
+        }
         let z = coord_trans.depth(from);
-        (coord, z)
+        (rect.truncate(coord), z)
+    }
+    fn is_non_finite(output: &(BackendCoord, i32)) -> bool {
+        is_non_finite_coord(&output.0)
+    }
+    fn backend_coord(output: &(BackendCoord, i32)) -> Option<BackendCoord> {
+        Some(output.0)
     }
 }
@@ -0,0 +1,159 @@
+use super::{Drawable, PointCollection};
+use crate::style::SizeDesc;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::borrow::Borrow;
+
+/**
+A marker element drawn repeatedly along a path, at regular intervals of the path length.
+
+The path is given in the guest coordinate system, while the marker uses the backend
+coordinates relative to each interval point, like the elements of a composed element. The
+interval is measured in pixels along the drawn path, and the first marker is placed at the
+origin of the path.
+
+# Example
+
+```
+use plotters::prelude::*;
+let route = [(0.0, 0.5), (2.0, 2.5), (4.0, 1.0), (6.0, 3.5)];
+let drawing_area = SVGBackend::new("path_markers.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..6.0, 0.0..4.0)
+    .unwrap();
+chart.draw_series(LineSeries::new(route, &BLUE)).unwrap();
+chart
+    .draw_series(std::iter::once(PathMarkers::new(
+        route,
+        30,
+        Circle::new((0, 0), 3, BLUE.filled()),
+    )))
+    .unwrap();
+```
+*/
+pub struct PathMarkers<Coord, Size: SizeDesc, E> {
+    points: Vec<Coord>,
+    spacing: Size,
+    marker: E,
+}
+
+impl<Coord, Size: SizeDesc, E> PathMarkers<Coord, Size, E> {
+    /**
+    Creates a series of markers along a path.
+
+    - `points`: The points of the path
+    - `spacing`: The distance between two adjacent markers along the path, in pixels
+    - `marker`: The marker to draw, in backend coordinates relative to each interval point
+    - Returns: The element
+
+    See [`PathMarkers`] for more information and examples.
+    */
+    pub fn new<P: Into<Vec<Coord>>>(points: P, spacing: Size, marker: E) -> Self {
+        Self {
+            points: points.into(),
+            spacing,
+            marker,
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc, E> PointCollection<'a, Coord> for &'a PathMarkers<Coord, Size, E> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+/// Find the points at every `spacing` pixels along the path, starting from the first point
+fn interval_points(path: &[BackendCoord], spacing: f64) -> Vec<BackendCoord> {
+    let mut ret: Vec<_> = path.first().into_iter().copied().collect();
+    if spacing <= 0.0 {
+        return ret;
+    }
+
+    let mut walked = 0.0;
+    let mut next = spacing;
+    for (&(x0, y0), &(x1, y1)) in path.iter().zip(path.iter().skip(1)) {
+        let (dx, dy) = (f64::from(x1 - x0), f64::from(y1 - y0));
+        let length = (dx * dx + dy * dy).sqrt();
+        while next <= walked + length {
+            let t = (next - walked) / length;
+            ret.push((
+                (f64::from(x0) + dx * t).round() as i32,
+                (f64::from(y0) + dy * t).round() as i32,
+            ));
+            next += spacing;
+        }
+        walked += length;
+    }
+
+    ret
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc, E> Drawable<DB> for PathMarkers<Coord, Size, E>
+where
+    E: Drawable<DB>,
+    for<'b> &'b E: PointCollection<'b, BackendCoord>,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = points.collect();
+        let spacing = f64::from(self.spacing.in_pixels(&parent_dim));
+
+        for (x0, y0) in interval_points(&path, spacing) {
+            self.marker.draw(
+                self.marker.point_iter().into_iter().map(|p| {
+                    let p = p.borrow();
+                    (p.0 + x0, p.1 + y0)
+                }),
+                backend,
+                parent_dim,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interval_points() {
+        let path = [(0, 0), (10, 0), (10, 15)];
+        assert_eq!(
+            interval_points(&path, 4.0),
+            vec![(0, 0), (4, 0), (8, 0), (10, 2), (10, 6), (10, 10), (10, 14)]
+        );
+        assert_eq!(interval_points(&path, 0.0), vec![(0, 0)]);
+        assert_eq!(interval_points(&[], 4.0), vec![]);
+    }
+
+    #[test]
+    fn test_path_markers() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_circle(|_, _, _, center, r| {
+                assert_eq!(r, 2);
+                assert_eq!(center.1, 50);
+                assert_eq!(center.0 % 20, 10);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 5);
+            });
+        });
+
+        drawing_area
+            .draw(&PathMarkers::new(
+                vec![(10, 50), (90, 50)],
+                20,
+                Circle::new((0, 0), 2, RED),
+            ))
+            .expect("Drawing Failure");
+    }
+}
@@ -0,0 +1,259 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A polar grid, which is made of concentric circles and radial spokes around a center point.
+
+This element doesn't require a polar coordinate system, the center point can be in any
+coordinate and the radius is in pixels. It's typically used as the backdrop of a radar chart.
+
+The angles of the spokes are in degrees, 0 degree points to the right and the angle increases
+clockwise, which is the same as [`crate::element::Pie`].
+
+```rust
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("polar_grid.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area.draw(
+    &PolarGrid::new((150, 150), 120)
+        .rings(4)
+        .spokes(6)
+        .start_angle(-90.0)
+        .ring_labels(|value| format!("{:.0}%", value * 100.0))
+        .spoke_labels(["A", "B", "C", "D", "E", "F"]),
+).unwrap();
+```
+*/
+pub struct PolarGrid<'a, Coord> {
+    center: Coord,
+    radius: u32,
+    rings: usize,
+    spoke_angles: Vec<f64>,
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    ring_label_fmt: Option<Box<dyn Fn(f64) -> String + 'a>>,
+    spoke_labels: Vec<String>,
+    label_offset: i32,
+}
+
+impl<'a, Coord> PolarGrid<'a, Coord> {
+    /// Create a new polar grid with 5 rings and 8 spokes, without any label
+    ///
+    /// - `center`: The center of the grid
+    /// - `radius`: The radius of the outermost ring in pixels
+    /// - **returns** The newly created polar grid
+    pub fn new(center: Coord, radius: u32) -> Self {
+        Self {
+            center,
+            radius,
+            rings: 5,
+            spoke_angles: evenly_spaced_angles(8, 0.0),
+            style: BLACK.mix(0.3).into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            ring_label_fmt: None,
+            spoke_labels: vec![],
+            label_offset: 5,
+        }
+    }
+
+    /// Set the number of the concentric rings, the rings are evenly spaced
+    ///
+    /// - `rings`: The number of the rings
+    /// - **returns** The up-to-dated polar grid
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings;
+        self
+    }
+
+    /// Set the number of the evenly spaced spokes, the first spoke is at the current start
+    /// angle, which is 0 degree by default
+    ///
+    /// - `spokes`: The number of the spokes
+    /// - **returns** The up-to-dated polar grid
+    pub fn spokes(mut self, spokes: usize) -> Self {
+        let start = self.spoke_angles.first().copied().unwrap_or(0.0);
+        self.spoke_angles = evenly_spaced_angles(spokes, start);
+        self
+    }
+
+    /// Rotate all the spokes so that the first spoke is at the given angle
+    ///
+    /// - `angle`: The angle of the first spoke in degrees
+    /// - **returns** The up-to-dated polar grid
+    pub fn start_angle(mut self, angle: f64) -> Self {
+        let delta = angle - self.spoke_angles.first().copied().unwrap_or(0.0);
+        self.spoke_angles.iter_mut().for_each(|a| *a += delta);
+        self
+    }
+
+    /// Set the angles of the spokes explicitly
+    ///
+    /// - `angles`: The angles of the spokes in degrees
+    /// - **returns** The up-to-dated polar grid
+    pub fn spoke_angles<I: IntoIterator<Item = f64>>(mut self, angles: I) -> Self {
+        self.spoke_angles = angles.into_iter().collect();
+        self
+    }
+
+    /// Set the style of the rings and spokes
+    ///
+    /// - `style`: The line style
+    /// - **returns** The up-to-dated polar grid
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the ring labels and spoke labels
+    ///
+    /// - `style`: The text style
+    /// - **returns** The up-to-dated polar grid
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Draw a label on each ring. The formatter is called with the relative position of the ring,
+    /// which is in `(0, 1]` and the outermost ring is 1.
+    ///
+    /// - `fmt`: The label formatter
+    /// - **returns** The up-to-dated polar grid
+    pub fn ring_labels<F: Fn(f64) -> String + 'a>(mut self, fmt: F) -> Self {
+        self.ring_label_fmt = Some(Box::new(fmt));
+        self
+    }
+
+    /// Draw a label at the outer end of each spoke, the labels are assigned to the spokes in order
+    ///
+    /// - `labels`: The spoke labels
+    /// - **returns** The up-to-dated polar grid
+    pub fn spoke_labels<S: ToString, I: IntoIterator<Item = S>>(mut self, labels: I) -> Self {
+        self.spoke_labels = labels.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Set the distance between the outermost ring and the spoke labels
+    ///
+    /// - `offset`: The distance in pixels
+    /// - **returns** The up-to-dated polar grid
+    pub fn label_offset(mut self, offset: i32) -> Self {
+        self.label_offset = offset;
+        self
+    }
+}
+
+fn evenly_spaced_angles(n: usize, start: f64) -> Vec<f64> {
+    (0..n)
+        .map(|i| start + 360.0 * i as f64 / n as f64)
+        .collect()
+}
+
+fn polar_to_backend(center: BackendCoord, radius: f64, angle: f64) -> BackendCoord {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    (
+        center.0 + (radius * cos).round() as i32,
+        center.1 + (radius * sin).round() as i32,
+    )
+}
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a PolarGrid<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for PolarGrid<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+
+        let ring_radius = |idx: usize| self.radius as f64 * idx as f64 / self.rings as f64;
+
+        for idx in 1..=self.rings {
+            let r = ring_radius(idx).round() as u32;
+            backend.draw_circle(center, r, &self.style, false)?;
+        }
+
+        for &angle in self.spoke_angles.iter() {
+            let end = polar_to_backend(center, self.radius as f64, angle);
+            backend.draw_line(center, end, &self.style)?;
+        }
+
+        if let Some(fmt) = self.ring_label_fmt.as_ref() {
+            let style = self.label_style.pos(Pos::new(HPos::Left, VPos::Bottom));
+            for idx in 1..=self.rings {
+                let r = ring_radius(idx).round() as i32;
+                let text = fmt(idx as f64 / self.rings as f64);
+                backend.draw_text(&text, &style, (center.0 + 2, center.1 - r))?;
+            }
+        }
+
+        let style = self.label_style.pos(Pos::new(HPos::Center, VPos::Center));
+        for (label, &angle) in self.spoke_labels.iter().zip(self.spoke_angles.iter()) {
+            let (w, h) = backend.estimate_text_size(label, &style)?;
+            // Push the label out so that its box doesn't overlap the outermost ring
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let extra = (w as f64 * cos.abs()).max(h as f64 * sin.abs()) / 2.0;
+            let r = self.radius as f64 + self.label_offset as f64 + extra;
+            backend.draw_text(label, &style, polar_to_backend(center, r, angle))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_polar_grid() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_circle(|_, _, filled, center, r| {
+                assert!(!filled);
+                assert_eq!(center, (150, 150));
+                assert_eq!(r % 25, 0);
+                assert!(r <= 100);
+            });
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!(from, (150, 150));
+                let (dx, dy) = (to.0 - 150, to.1 - 150);
+                assert!((dx * dx + dy * dy - 100 * 100).abs() <= 200);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_text_call, 4 + 3);
+            });
+        });
+
+        da.draw(
+            &PolarGrid::new((150, 150), 100)
+                .rings(4)
+                .spokes(3)
+                .start_angle(-90.0)
+                .ring_labels(|v| format!("{}", v))
+                .spoke_labels(["a", "b", "c"]),
+        )
+        .expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_spoke_angles() {
+        let grid = PolarGrid::new((0, 0), 10).spokes(4).start_angle(-90.0);
+        assert_eq!(grid.spoke_angles, vec![-90.0, 0.0, 90.0, 180.0]);
+        assert_eq!(polar_to_backend((0, 0), 10.0, -90.0), (0, -10));
+        assert_eq!(polar_to_backend((0, 0), 10.0, 0.0), (10, 0));
+    }
+}
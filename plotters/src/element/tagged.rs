@@ -0,0 +1,112 @@
+use super::{Drawable, PointCollection};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::borrow::Borrow;
+
+/**
+An element tagged with an id, which makes it pickable in an interactive figure.
+
+The element is drawn in a group tagged with the id, see [`DrawingBackend::begin_group`]. The SVG
+backend emits the group as a `<g>` tag with the `id` attribute, so that the data points of an
+exported SVG can be handled by a click handler. Other backends draw the element as usual.
+
+# Example
+
+```
+use plotters::prelude::*;
+let data = [(1.0, 3.3), (2., 2.1), (3., 1.5), (4., 1.9), (5., 1.0)];
+let drawing_area = SVGBackend::new("tagged.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..6.0, 0.0..4.0)
+    .unwrap();
+chart
+    .draw_series(data.iter().enumerate().map(|(i, &point)| {
+        Tagged::new(format!("point-{}", i), Circle::new(point, 5, BLUE.filled()))
+    }))
+    .unwrap();
+```
+*/
+pub struct Tagged<Coord, E> {
+    id: String,
+    points: Vec<Coord>,
+    inner: E,
+}
+
+impl<Coord: Clone, E> Tagged<Coord, E> {
+    /**
+    Creates a tagged element.
+
+    - `id`: The id of the element, which is emitted by the interactive backends
+    - `element`: The element to tag
+    - Returns: The tagged element
+
+    See [`Tagged`] for more information and examples.
+    */
+    pub fn new<S: Into<String>>(id: S, element: E) -> Self
+    where
+        for<'a> &'a E: PointCollection<'a, Coord>,
+    {
+        Self {
+            id: id.into(),
+            points: element
+                .point_iter()
+                .into_iter()
+                .map(|p| p.borrow().clone())
+                .collect(),
+            inner: element,
+        }
+    }
+
+    /// Get the id of the element
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord> for &'a Tagged<Coord, E> {
+    type Point = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend, Coord, E: Drawable<DB>> Drawable<DB> for Tagged<Coord, E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.begin_group(&self.id)?;
+        self.inner.draw(pos, backend, parent_dim)?;
+        backend.end_group()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_tagged_element() {
+        let log = record_draw_calls(100, 100, |root| {
+            let mut chart = ChartBuilder::on(root)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            chart
+                .draw_series(
+                    (0..2).map(|i| Tagged::new(format!("point-{}", i), Pixel::new((i, i), RED))),
+                )
+                .unwrap();
+        });
+
+        assert_eq!(log.len(), 6);
+        assert_eq!(log[0], "begin_group \"point-0\"");
+        assert!(log[1].starts_with("draw_pixel"));
+        assert_eq!(log[2], "end_group");
+        assert_eq!(log[3], "begin_group \"point-1\"");
+        assert_eq!(log[5], "end_group");
+    }
+}
@@ -1,8 +1,9 @@
 use std::borrow::Borrow;
 use std::i32;
 
-use super::{Drawable, PointCollection};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+use super::{Drawable, PointCollection, RoundedRectangle};
+use crate::style::text_anchor::{HPos, VPos};
+use crate::style::{FontDesc, FontResult, LayoutBox, ShapeStyle, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// A single line text element. This can be owned or borrowed string, dependents on
@@ -50,6 +51,119 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
     }
 }
 
+/// A single line text element drawn over a filled box, which keeps the text readable over the
+/// grid lines and the data. The box surrounds the text with the padding, and it follows the
+/// anchor of the text style, so a centered text gets a centered box.
+///
+/// ```
+/// use plotters::prelude::*;
+/// use plotters::style::text_anchor::{HPos, Pos, VPos};
+/// let drawing_area = SVGBackend::new("background_text.svg", (300, 200)).into_drawing_area();
+/// drawing_area.fill(&WHITE).unwrap();
+/// let style = ("sans-serif", 15).into_text_style(&drawing_area).pos(Pos::new(HPos::Center, VPos::Center));
+/// drawing_area
+///     .draw(&BackgroundText::new("Peak", (150, 100), style, WHITE.mix(0.8).filled(), 4).corner_radius(3))
+///     .unwrap();
+/// ```
+pub struct BackgroundText<'a, Coord, T: Borrow<str>> {
+    text: Text<'a, Coord, T>,
+    background: ShapeStyle,
+    padding: u32,
+    corner_radius: u32,
+}
+
+impl<'a, Coord, T: Borrow<str>> BackgroundText<'a, Coord, T> {
+    /// Create a new text element with a background box
+    /// - `text`: The text for the element
+    /// - `points`: The anchor point of the text
+    /// - `style`: The text style
+    /// - `background`: The style of the box, which is always filled
+    /// - `padding`: The space between the text and the edges of the box in pixels
+    /// - Return the newly created text element
+    pub fn new<S: Into<TextStyle<'a>>, B: Into<ShapeStyle>>(
+        text: T,
+        points: Coord,
+        style: S,
+        background: B,
+        padding: u32,
+    ) -> Self {
+        Self {
+            text: Text::new(text, points, style),
+            background: background.into(),
+            padding,
+            corner_radius: 0,
+        }
+    }
+
+    /// Round the corners of the box, which are square by default
+    /// - `radius`: The radius of the corners in pixels
+    /// - Return the updated element
+    pub fn corner_radius(mut self, radius: u32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
+    for &'a BackgroundText<'b, Coord, T>
+{
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.text.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
+    for BackgroundText<'a, Coord, T>
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(a) = points.next() {
+            let text = self.text.text.borrow();
+            let style = &self.text.style;
+            let (w, h) = backend.estimate_text_size(text, style)?;
+            let (w, h) = (w as i32, h as i32);
+            let x0 = match style.pos.h_pos {
+                HPos::Left => a.0,
+                HPos::Center => a.0 - w / 2,
+                HPos::Right => a.0 - w,
+            };
+            let y0 = match style.pos.v_pos {
+                VPos::Top => a.1,
+                VPos::Center => a.1 - h / 2,
+                VPos::Bottom => a.1 - h,
+            };
+            let padding = self.padding as i32;
+            let upper_left = (x0 - padding, y0 - padding);
+            let bottom_right = (x0 + w + padding, y0 + h + padding);
+
+            if self.corner_radius > 0 {
+                RoundedRectangle::new(
+                    [upper_left, bottom_right],
+                    self.corner_radius,
+                    self.background,
+                    true,
+                )
+                .draw(
+                    vec![upper_left, bottom_right].into_iter(),
+                    backend,
+                    parent_dim,
+                )?;
+            } else {
+                backend.draw_rect(upper_left, bottom_right, &self.background, true)?;
+            }
+
+            return backend.draw_text(text, style, a);
+        }
+        Ok(())
+    }
+}
+
 /// An multi-line text element. The `Text` element allows only single line text
 /// and the `MultiLineText` supports drawing multiple lines
 pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
@@ -57,6 +171,7 @@ pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
     coord: Coord,
     style: TextStyle<'a>,
     line_height: f64,
+    alignment: HPos,
 }
 
 impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
@@ -71,6 +186,7 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
             coord: pos,
             style: style.into(),
             line_height: 1.25,
+            alignment: HPos::Left,
         }
     }
 
@@ -80,6 +196,14 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         self
     }
 
+    /// Set the horizontal alignment of the lines, which are left aligned by default.
+    /// The lines narrower than the widest one are moved to the right by the difference of
+    /// the widths for `HPos::Right`, or by the half of it for `HPos::Center`
+    pub fn set_alignment(&mut self, alignment: HPos) -> &mut Self {
+        self.alignment = alignment;
+        self
+    }
+
     /// Push a new line into the given multi-line text
     /// `line`: The line to be pushed
     pub fn push_line<L: Into<T>>(&mut self, line: L) {
@@ -90,8 +214,7 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
     pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
         let (mut mx, mut my) = (0, 0);
 
-        for ((x, y), t) in self.layout_lines((0, 0)).zip(self.lines.iter()) {
-            let (dx, dy) = self.style.font.box_size(t.borrow())?;
+        for ((x, y), (dx, dy)) in self.layout_lines((0, 0))? {
             mx = mx.max(x + dx as i32);
             my = my.max(y + dy as i32);
         }
@@ -104,15 +227,29 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         self.coord = coord
     }
 
-    fn layout_lines(&self, (x0, y0): BackendCoord) -> impl Iterator<Item = BackendCoord> {
+    /// Compute the position of each line together with its measured size
+    fn layout_lines(&self, (x0, y0): BackendCoord) -> FontResult<Vec<(BackendCoord, (u32, u32))>> {
+        let sizes = self
+            .lines
+            .iter()
+            .map(|line| self.style.font.box_size(line.borrow()))
+            .collect::<FontResult<Vec<_>>>()?;
+        let max_width = sizes.iter().map(|(w, _)| *w).max().unwrap_or(0);
         let font_height = self.style.font.get_size();
         let actual_line_height = font_height * self.line_height;
-        (0..self.lines.len() as u32).map(move |idx| {
-            let y = f64::from(y0) + f64::from(idx) * actual_line_height;
-            // TODO: Support text alignment as well, currently everything is left aligned
-            let x = f64::from(x0);
-            (x.round() as i32, y.round() as i32)
-        })
+        Ok(sizes
+            .into_iter()
+            .enumerate()
+            .map(|(idx, size)| {
+                let y = f64::from(y0) + idx as f64 * actual_line_height;
+                let dx = match self.alignment {
+                    HPos::Left => 0,
+                    HPos::Center => (max_width - size.0) / 2,
+                    HPos::Right => max_width - size.0,
+                };
+                ((x0 + dx as i32, y.round() as i32), size)
+            })
+            .collect())
     }
 }
 
@@ -152,15 +289,53 @@ fn layout_multiline_text<'a, F: FnMut(&'a str)>(
     }
 }
 
+/// Break a line of text at the word boundaries, so that the width of each line doesn't exceed
+/// `max_width`. The words wider than `max_width` are broken between the characters.
+fn wrap_line(
+    line: &str,
+    max_width: u32,
+    font: &FontDesc,
+    lines: &mut Vec<String>,
+) -> FontResult<()> {
+    let fits = |text: &str| -> FontResult<bool> { Ok(font.box_size(text)?.0 <= max_width) };
+    let mut current = String::new();
+
+    for word in line.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", current, word)
+        };
+        if fits(&candidate)? {
+            current = candidate;
+            continue;
+        }
+
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        for c in word.chars() {
+            current.push(c);
+            if current.chars().count() > 1 && !fits(&current)? {
+                current.pop();
+                lines.push(std::mem::replace(&mut current, c.to_string()));
+            }
+        }
+    }
+
+    lines.push(current);
+    Ok(())
+}
+
 impl<'a, T: Borrow<str>> MultiLineText<'a, BackendCoord, T> {
     /// Compute the line layout
     pub fn compute_line_layout(&self) -> FontResult<Vec<LayoutBox>> {
-        let mut ret = vec![];
-        for ((x, y), t) in self.layout_lines(self.coord).zip(self.lines.iter()) {
-            let (dx, dy) = self.style.font.box_size(t.borrow())?;
-            ret.push(((x, y), (x + dx as i32, y + dy as i32)));
-        }
-        Ok(ret)
+        Ok(self
+            .layout_lines(self.coord)?
+            .into_iter()
+            .map(|((x, y), (dx, dy))| ((x, y), (x + dx as i32, y + dy as i32)))
+            .collect())
     }
 }
 
@@ -213,6 +388,42 @@ impl<'a, Coord> MultiLineText<'a, Coord, String> {
     }
 }
 
+impl<'a, Coord> MultiLineText<'a, Coord, String> {
+    /// Create a multi-line element from the text which is wrapped to the given width.
+    ///
+    /// Unlike [`MultiLineText::from_string`], the lines are broken at the word boundaries, and a
+    /// word is only broken when it doesn't fit in a line by itself. The existing line breaks in
+    /// the text are kept.
+    ///
+    /// `text`: The text that is wrapped
+    /// `pos`: The position of the text
+    /// `style`: The style for this text
+    /// `max_width`: The maximum width of the lines in pixels, which is measured with the font of
+    /// the style. If 0 is given, do not do any line wrapping
+    pub fn wrapped<S: Into<TextStyle<'a>>>(
+        text: &str,
+        pos: Coord,
+        style: S,
+        max_width: u32,
+    ) -> FontResult<Self> {
+        let mut ret = MultiLineText::new(pos, style);
+        let mut lines = vec![];
+
+        for line in text.lines() {
+            if max_width == 0 {
+                lines.push(line.to_string());
+            } else {
+                wrap_line(line, max_width, &ret.style.font, &mut lines)?;
+            }
+        }
+
+        for line in lines {
+            ret.push_line(line);
+        }
+        Ok(ret)
+    }
+}
+
 impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
     for &'a MultiLineText<'b, Coord, T>
 {
@@ -233,10 +444,122 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            for (point, text) in self.layout_lines(a).zip(self.lines.iter()) {
+            let layout = self
+                .layout_lines(a)
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+            for ((point, _), text) in layout.into_iter().zip(self.lines.iter()) {
                 backend.draw_text(text.borrow(), &self.style, point)?;
             }
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::IntoFont;
+
+    #[test]
+    fn test_wrapped_text() {
+        let style: TextStyle = ("sans-serif", 20).into_font().into();
+        let max_width = style.font.box_size("quick brown").unwrap().0;
+
+        let text = MultiLineText::wrapped(
+            "the quick brown fox\njumps",
+            (0, 0),
+            style.clone(),
+            max_width,
+        )
+        .unwrap();
+        assert_eq!(text.lines, ["the quick", "brown fox", "jumps"]);
+        for line in text.lines.iter() {
+            assert!(style.font.box_size(line).unwrap().0 <= max_width);
+        }
+
+        let max_width = style.font.box_size("abc").unwrap().0;
+        let text = MultiLineText::wrapped("abcdefgh", (0, 0), style.clone(), max_width).unwrap();
+        assert_eq!(text.lines, ["abc", "def", "gh"]);
+
+        let text = MultiLineText::wrapped("a b\n\nc", (0, 0), style, 0).unwrap();
+        assert_eq!(text.lines, ["a b", "", "c"]);
+    }
+
+    #[test]
+    fn test_line_alignment() {
+        let style: TextStyle = ("sans-serif", 20).into_font().into();
+        let wide = style.font.box_size("a wide line").unwrap().0 as i32;
+        let narrow = style.font.box_size("narrow").unwrap().0 as i32;
+
+        let mut text = MultiLineText::<_, &str>::new((10, 10), style.clone());
+        text.push_line("a wide line");
+        text.push_line("narrow");
+        let left = text.compute_line_layout().unwrap();
+        assert_eq!(((left[0].0).0, (left[1].0).0), (10, 10));
+
+        text.set_alignment(HPos::Right);
+        let right = text.compute_line_layout().unwrap();
+        assert_eq!((right[0].0).0, 10);
+        assert_eq!((right[1].0).0, 10 + wide - narrow);
+        assert_eq!((right[1].1).0, 10 + wide);
+
+        text.set_alignment(HPos::Center);
+        let center = text.compute_line_layout().unwrap();
+        assert_eq!((center[1].0).0, 10 + (wide - narrow) / 2);
+        assert_eq!(text.estimate_dimension().unwrap(), {
+            let mut text = MultiLineText::<_, &str>::new((10, 10), style.clone());
+            text.push_line("a wide line");
+            text.push_line("narrow");
+            text.estimate_dimension().unwrap()
+        });
+
+        let mut single = MultiLineText::<_, &str>::new((10, 10), style.clone());
+        single.push_line("single");
+        single.set_alignment(HPos::Right);
+        assert_eq!((single.compute_line_layout().unwrap()[0].0).0, 10);
+
+        let mut equal = MultiLineText::<_, &str>::new((10, 10), style);
+        equal.push_line("same");
+        equal.push_line("same");
+        equal.set_alignment(HPos::Center);
+        let layout = equal.compute_line_layout().unwrap();
+        assert!(layout.iter().all(|((x, _), _)| *x == 10));
+    }
+
+    #[test]
+    fn test_background_text() {
+        use crate::prelude::*;
+        use crate::style::text_anchor::Pos;
+
+        let style = TextStyle::from(("sans-serif", 20).into_font())
+            .pos(Pos::new(HPos::Center, VPos::Center));
+        let (w, h) = style.font.box_size("label").unwrap();
+        let (w, h) = (w as i32, h as i32);
+
+        let da = crate::create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                assert_eq!(c, WHITE.mix(0.5));
+                assert!(filled);
+                assert_eq!(ul, (100 - w / 2 - 3, 100 - h / 2 - 3));
+                assert_eq!(br, (100 - w / 2 + w + 3, 100 - h / 2 + h + 3));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "label");
+                assert_eq!(pos, (100, 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+                assert_eq!(b.draw_count, 2);
+            });
+        });
+        da.draw(&BackgroundText::new(
+            "label",
+            (100, 100),
+            style,
+            WHITE.mix(0.5).filled(),
+            3,
+        ))
+        .expect("Drawing Failure");
+    }
+}
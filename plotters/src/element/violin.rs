@@ -0,0 +1,360 @@
+use std::marker::PhantomData;
+
+use crate::data::Quartiles;
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle, BLUE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The violin plot orientation trait
+pub trait ViolinOrient<K, V> {
+    /// The type of the X coordinate
+    type XType;
+    /// The type of the Y coordinate
+    type YType;
+
+    /// Make the guest coordinate of the value at the key
+    fn make_coord(key: K, val: V) -> (Self::XType, Self::YType);
+    /// Move the backend coordinate by the offset across the key axis
+    fn with_offset(coord: BackendCoord, offset: f64) -> BackendCoord;
+}
+
+/// The vertical violin plot phantom
+pub struct ViolinOrientV<K, V>(PhantomData<(K, V)>);
+
+/// The horizontal violin plot phantom
+pub struct ViolinOrientH<K, V>(PhantomData<(K, V)>);
+
+impl<K, V> ViolinOrient<K, V> for ViolinOrientV<K, V> {
+    type XType = K;
+    type YType = V;
+
+    fn make_coord(key: K, val: V) -> (K, V) {
+        (key, val)
+    }
+
+    fn with_offset(coord: BackendCoord, offset: f64) -> BackendCoord {
+        (coord.0 + offset.round() as i32, coord.1)
+    }
+}
+
+impl<K, V> ViolinOrient<K, V> for ViolinOrientH<K, V> {
+    type XType = V;
+    type YType = K;
+
+    fn make_coord(key: K, val: V) -> (V, K) {
+        (val, key)
+    }
+
+    fn with_offset(coord: BackendCoord, offset: f64) -> BackendCoord {
+        (coord.0, coord.1 + offset.round() as i32)
+    }
+}
+
+const DEFAULT_WIDTH: u32 = 20;
+
+/// The number of the points at which the density is evaluated
+const DENSITY_POINTS: usize = 64;
+
+/// How far the density is evaluated beyond the extreme samples, in bandwidths
+const DENSITY_CUT: f64 = 3.0;
+
+/**
+The violin plot element, which draws the kernel density estimate of the samples mirrored on
+both sides of the key, so the width of the violin shows how dense the samples are.
+
+The density is estimated with a Gaussian kernel, whose bandwidth follows Silverman's rule of
+thumb unless it's set by [`Violin::bandwidth()`]. The violin of samples which are all equal,
+for example a single sample, is a thin sliver at their value.
+
+```rust
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("violin.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0..3, 0f32..10f32)
+    .unwrap();
+let samples = [[1.0, 2.0, 2.5, 3.0, 3.2, 5.0], [4.0, 6.0, 6.5, 7.0, 7.2, 8.0]];
+chart_context.draw_series(samples.iter().enumerate().map(|(i, s)| {
+    Violin::new_vertical(i as i32 + 1, s).width(60).style(GREEN.mix(0.5).filled())
+})).unwrap();
+```
+*/
+pub struct Violin<K, O: ViolinOrient<K, f32>> {
+    style: ShapeStyle,
+    width: u32,
+    offset: f64,
+    key: K,
+    samples: Vec<f64>,
+    bandwidth: f64,
+    _p: PhantomData<O>,
+}
+
+impl<K: Clone> Violin<K, ViolinOrientV<K, f32>> {
+    /// Create a new vertical violin plot element.
+    ///
+    /// - `key`: The key (the X axis value)
+    /// - `samples`: The samples whose density is drawn along the Y axis
+    /// - **returns** The newly created violin plot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical("group", &[7, 15, 36, 39, 40, 41]);
+    /// ```
+    pub fn new_vertical<T: Into<f64> + Copy + PartialOrd>(key: K, samples: &[T]) -> Self {
+        Self::new(key, samples)
+    }
+}
+
+impl<K: Clone> Violin<K, ViolinOrientH<K, f32>> {
+    /// Create a new horizontal violin plot element.
+    ///
+    /// - `key`: The key (the Y axis value)
+    /// - `samples`: The samples whose density is drawn along the X axis
+    /// - **returns** The newly created violin plot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]);
+    /// ```
+    pub fn new_horizontal<T: Into<f64> + Copy + PartialOrd>(key: K, samples: &[T]) -> Self {
+        Self::new(key, samples)
+    }
+}
+
+impl<K, O: ViolinOrient<K, f32>> Violin<K, O> {
+    fn new<T: Into<f64> + Copy + PartialOrd>(key: K, samples: &[T]) -> Self {
+        let samples: Vec<f64> = samples.iter().map(|&v| v.into()).collect();
+        let bandwidth = silverman_bandwidth(&samples);
+        Self {
+            style: BLUE.mix(0.5).filled(),
+            width: DEFAULT_WIDTH,
+            offset: 0.0,
+            key,
+            samples,
+            bandwidth,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the violin plot, whose color fills the violin.
+    ///
+    /// - `S`: The required style
+    /// - **returns** The up-to-dated violin plot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical("group", &[7, 15, 36, 39, 40, 41]).style(&RED);
+    /// ```
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the violin at its densest value, in pixels.
+    ///
+    /// - `width`: The required width
+    /// - **returns** The up-to-dated violin plot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical("group", &[7, 15, 36, 39, 40, 41]).width(40);
+    /// ```
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the bandwidth of the kernel, in the unit of the samples. It's computed with
+    /// Silverman's rule of thumb by default, a smaller bandwidth shows more details of the
+    /// density and a larger one makes it smoother.
+    ///
+    /// - `bandwidth`: The required bandwidth
+    /// - **returns** The up-to-dated violin plot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical("group", &[7, 15, 36, 39, 40, 41]).bandwidth(2.5);
+    /// ```
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = bandwidth.abs();
+        self
+    }
+
+    /// Set the element offset on the key axis.
+    ///
+    /// - `offset`: The required offset (on the X axis for vertical, on the Y axis for horizontal)
+    /// - **returns** The up-to-dated violin plot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]).offset(-5);
+    /// ```
+    pub fn offset<T: Into<f64> + Copy>(mut self, offset: T) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// The values at which the density is evaluated, with the density relative to the largest
+    /// one. Samples which are all equal make a sliver of two points at their value.
+    fn profile(&self) -> Vec<(f64, f64)> {
+        let min = self.samples.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = self
+            .samples
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+        if !min.is_finite() || !max.is_finite() {
+            return vec![];
+        }
+        let h = self.bandwidth;
+        if !(h > 0.0 && h.is_finite()) {
+            return vec![(min, 1.0), (max, 1.0)];
+        }
+
+        let (lo, hi) = (min - DENSITY_CUT * h, max + DENSITY_CUT * h);
+        let profile: Vec<_> = (0..DENSITY_POINTS)
+            .map(|i| {
+                let v = lo + (hi - lo) * i as f64 / (DENSITY_POINTS - 1) as f64;
+                let density = self
+                    .samples
+                    .iter()
+                    .map(|s| (-0.5 * ((v - s) / h).powi(2)).exp())
+                    .sum::<f64>();
+                (v, density)
+            })
+            .collect();
+        let peak = profile.iter().map(|p| p.1).fold(0.0, f64::max);
+        profile.into_iter().map(|(v, d)| (v, d / peak)).collect()
+    }
+}
+
+/// Silverman's rule of thumb, `0.9 * min(sd, iqr / 1.34) * n^(-1/5)`, which falls back to the
+/// other spread when one of them is zero
+fn silverman_bandwidth(samples: &[f64]) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let sd = (samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    let [_, lower, _, upper, _] = Quartiles::new(samples).values();
+    let iqr = f64::from(upper - lower) / 1.34;
+    let spread = if iqr > 0.0 { sd.min(iqr) } else { sd };
+    0.9 * spread * n.powf(-0.2)
+}
+
+impl<'a, K: Clone, O: ViolinOrient<K, f32>> PointCollection<'a, (O::XType, O::YType)>
+    for &'a Violin<K, O>
+{
+    type Point = (O::XType, O::YType);
+    type IntoIter = Vec<Self::Point>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.profile()
+            .into_iter()
+            .map(|(v, _)| O::make_coord(self.key.clone(), v as f32))
+            .collect()
+    }
+}
+
+impl<K, DB: DrawingBackend, O: ViolinOrient<K, f32>> Drawable<DB> for Violin<K, O> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let profile = self.profile();
+        let points: Vec<_> = points.collect();
+        if points.len() != profile.len() || points.is_empty() {
+            return Ok(());
+        }
+        let half_width = f64::from(self.width) / 2.0;
+        let side = |sign: f64| {
+            let offset = self.offset;
+            points
+                .iter()
+                .zip(profile.iter())
+                .map(move |(&coord, &(_, density))| {
+                    O::with_offset(coord, offset + sign * density * half_width)
+                })
+        };
+        let outline: Vec<_> = side(-1.0).chain(side(1.0).rev()).collect();
+        backend.fill_polygon(outline, &self.style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_silverman_bandwidth() {
+        let samples: Vec<f64> = (1..=5).map(f64::from).collect();
+        // IQR / 1.34 = 1.49 is smaller than the standard deviation 1.58
+        let expected = 0.9 * (2.0 / 1.34) * 5f64.powf(-0.2);
+        assert!((silverman_bandwidth(&samples) - expected).abs() < 1e-9);
+        // The IQR is zero, so the standard deviation is used
+        let samples = [1.0, 2.0, 2.0, 2.0, 2.0, 2.0, 3.0];
+        let expected = 0.9 * (1.0f64 / 3.0).sqrt() * 7f64.powf(-0.2);
+        assert!((silverman_bandwidth(&samples) - expected).abs() < 1e-9);
+        assert_eq!(silverman_bandwidth(&[3.0]), 0.0);
+    }
+
+    #[test]
+    fn test_draw_violin() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 2 * DENSITY_POINTS);
+                // The violin is mirrored around the key, and it's 20 pixels wide at its widest
+                let left = path.iter().map(|p| p.0).min().unwrap();
+                let right = path.iter().map(|p| p.0).max().unwrap();
+                assert_eq!(right - left, 20);
+                for (l, r) in path.iter().zip(path.iter().rev()) {
+                    assert_eq!(l.1, r.1);
+                    assert!((l.0 + r.0 - left - right).abs() <= 1);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..2, 0f32..100f32)
+            .unwrap();
+        chart
+            .plotting_area()
+            .draw(&Violin::new_vertical(1, &[40, 45, 50, 50, 55, 60]).style(RED))
+            .expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_draw_single_sample() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|_, path| {
+                // A sliver across the key at the value of the sample
+                assert_eq!(path.len(), 4);
+                assert!(path.iter().all(|p| p.0 == path[0].0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0f32..100f32, 0..2)
+            .unwrap();
+        chart
+            .plotting_area()
+            .draw(&Violin::new_horizontal(1, &[6]))
+            .expect("Drawing Failure");
+    }
+}
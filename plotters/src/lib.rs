@@ -719,9 +719,13 @@ pub fn register_font(
 | errorbar | The errorbar element support | None | Yes |
 | candlestick | The candlestick element support | None | Yes |
 | boxplot | The boxplot element support | None | Yes |
+| violin | The violin plot element support | None | Yes |
 | area\_series | The area series support | None | Yes |
 | line\_series | The line series support | None | Yes |
 | histogram | The histogram series support | None | Yes |
+| heatmap\_series | The heatmap series support | None | Yes |
+| contour\_series | The contour series support | None | Yes |
+| slope\_series | The slope series support | None | Yes |
 | point\_series| The point series support | None | Yes |
 
 - Misc
@@ -799,7 +803,7 @@ pub mod style;
 pub mod evcxr;
 
 #[cfg(test)]
-pub use crate::drawing::{check_color, create_mocked_drawing_area};
+pub use crate::drawing::{check_color, create_mocked_drawing_area, record_draw_calls};
 
 #[cfg(feature = "palette_ext")]
 pub use palette;
@@ -807,17 +811,22 @@ pub use palette;
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
     // Chart related types
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        small_multiples, CaptionPosition, ChartBuilder, ChartContext, LabelAreaPosition, Legend,
+        SeriesLabelOrder, SeriesLabelPosition, SmallMultiples,
+    };
 
     // Coordinates
     pub use crate::coord::{
-        cartesian::Cartesian2d,
+        cartesian::{Cartesian2d, PixelRounding},
         combinators::{
             make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            IntoLinspace, IntoLogRange, IntoPartialAxis, IntoReversedAxis, Linspace, LogCoord,
+            LogScalable, NestedRange, NestedValue, ToGroupByRange,
         },
+        polar::Polar,
         ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
+        types::{RangedCategory, RangedSessions},
         CoordTranslate,
     };
 
@@ -837,14 +846,20 @@ pub mod prelude {
     // Series helpers
     #[cfg(feature = "area_series")]
     pub use crate::series::AreaSeries;
-    #[cfg(feature = "histogram")]
-    pub use crate::series::Histogram;
+    #[cfg(feature = "contour_series")]
+    pub use crate::series::ContourSeries;
+    #[cfg(feature = "heatmap_series")]
+    pub use crate::series::HeatmapSeries;
     #[cfg(feature = "line_series")]
     pub use crate::series::LineSeries;
-    #[cfg(feature = "point_series")]
-    pub use crate::series::PointSeries;
+    #[cfg(feature = "slope_series")]
+    pub use crate::series::SlopeSeries;
     #[cfg(feature = "surface_series")]
     pub use crate::series::SurfaceSeries;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::{Histogram, StackedHistogram};
+    #[cfg(feature = "point_series")]
+    pub use crate::series::{PointLabels, PointSeries};
 
     // Styles
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
@@ -853,26 +868,37 @@ pub mod prelude {
     pub use crate::style::full_palette;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBAColor,
-        RGBColor, ShapeStyle, TextStyle,
+        BrBG, ColorMap, ColorNorm, Coolwarm, DivergingNorm, Inferno, LinearNorm, LogNorm, Magma,
+        RdBu, Viridis,
+    };
+
+    pub use crate::style::{
+        AsRelative, Color, DistinctColors, FillPattern, FontDesc, FontFamily, FontStyle,
+        FontTransform, HSLColor, HSVColor, IntoFont, IntoTextStyle, Palette, Palette100, Palette99,
+        Palette9999, PaletteColor, RGBAColor, RGBColor, ShapeStyle, StrokeWidthKind, TextStyle,
     };
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pie, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        AreaBetween, Arrow, ArrowHead, BackgroundText, BezierPath, Circle, Cross, Cubiod,
+        DynElement, Ellipse, EmptyElement, GradientDirection, GradientRectangle, IntoDynElement,
+        MultiLineText, PathElement, PathMarkers, Pie, Pixel, PointCloud, PolarGrid, Polygon,
+        Rectangle, RoundedRectangle, Sphere, Surface, Tagged, Text, TriangleMarker,
     };
 
-    #[cfg(feature = "boxplot")]
-    pub use crate::element::Boxplot;
-    #[cfg(feature = "candlestick")]
-    pub use crate::element::CandleStick;
     #[cfg(feature = "errorbar")]
     pub use crate::element::ErrorBar;
+    #[cfg(feature = "violin")]
+    pub use crate::element::Violin;
+    #[cfg(feature = "boxplot")]
+    pub use crate::element::{Boxplot, BoxplotMean};
+    #[cfg(feature = "candlestick")]
+    pub use crate::element::{CandleStick, OhlcBar};
 
     #[cfg(feature = "bitmap_backend")]
     pub use crate::element::BitMapElement;
+    #[cfg(feature = "bitmap_backend")]
+    pub use plotters_backend::BitmapFormat;
 
     // Data
     pub use crate::data::Quartiles;
@@ -1,7 +1,8 @@
+use crate::coord::CoordTranslate;
 use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
 use crate::style::colors::TRANSPARENT;
 use crate::style::ShapeStyle;
-use plotters_backend::DrawingBackend;
+use plotters_backend::{BackendCoord, DrawingBackend};
 
 /**
 An area series is similar to a line series but uses a filled polygon.
@@ -67,6 +68,20 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         self.border_style = style.into();
         self
     }
+
+    /**
+    Maps the data points to the backend pixel coordinates the border is drawn through, without
+    drawing anything.
+
+    See [`LineSeries::backend_coords()`](crate::series::LineSeries::backend_coords) for more
+    information and examples.
+    */
+    pub fn backend_coords<CT: CoordTranslate<From = (X, Y)>>(
+        &self,
+        coord: &CT,
+    ) -> Vec<BackendCoord> {
+        self.data.iter().map(|p| coord.translate(p)).collect()
+    }
 }
 
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
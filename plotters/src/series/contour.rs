@@ -0,0 +1,264 @@
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/**
+A series of the contour lines of a scalar field sampled on a grid, computed with marching
+squares.
+
+Each level is drawn with its own style, and each segment of a contour line is emitted as a
+[`PathElement`] of two points, so the series can be drawn on any chart whose coordinate is
+`(f64, f64)`.
+
+An ambiguous cell, whose diagonally opposite corners are on the same side of the level, is
+resolved with the mean of its corners, as if it was the value at the center of the cell.
+The segments collapsing into a single point, which happens when the level is exactly equal to
+the value at a grid point, are dropped.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("contour_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+    .unwrap();
+let axis = (-20..=20).map(|v| v as f64 / 10.0);
+chart
+    .draw_series(ContourSeries::from_fn(
+        axis.clone(),
+        axis,
+        |x, y| x * x + y * y,
+        [(0.5, RED), (1.0, GREEN), (2.0, BLUE)],
+    ))
+    .unwrap();
+```
+*/
+pub struct ContourSeries {
+    segments: std::vec::IntoIter<PathElement<(f64, f64)>>,
+}
+
+impl ContourSeries {
+    /**
+    Creates the contour lines of a grid of sampled values.
+
+    - `xs`: The x coordinates of the grid columns
+    - `ys`: The y coordinates of the grid rows
+    - `values`: The rows of sampled values, where `values[j][i]` is the value at `(xs[i], ys[j])`
+    - `levels`: The levels of the contour lines, each with its style
+    - **returns**: The newly created series
+
+    The grid is truncated to the number of coordinates and to the shortest row.
+    */
+    pub fn new<XS, YS, R, V, L, S>(xs: XS, ys: YS, values: V, levels: L) -> Self
+    where
+        XS: IntoIterator<Item = f64>,
+        YS: IntoIterator<Item = f64>,
+        R: AsRef<[f64]>,
+        V: IntoIterator<Item = R>,
+        L: IntoIterator<Item = (f64, S)>,
+        S: Into<ShapeStyle>,
+    {
+        let xs: Vec<f64> = xs.into_iter().collect();
+        let rows: Vec<(f64, R)> = ys.into_iter().zip(values).collect();
+        let columns = rows
+            .iter()
+            .map(|(_, r)| r.as_ref().len())
+            .min()
+            .unwrap_or(0)
+            .min(xs.len());
+
+        let mut segments = vec![];
+        for (level, style) in levels {
+            let style = style.into();
+            for pair in rows.windows(2) {
+                let ((y0, lower), (y1, upper)) = (&pair[0], &pair[1]);
+                let (lower, upper) = (lower.as_ref(), upper.as_ref());
+                for i in 0..columns.saturating_sub(1) {
+                    let corners = [
+                        ((xs[i], *y0), lower[i]),
+                        ((xs[i + 1], *y0), lower[i + 1]),
+                        ((xs[i + 1], *y1), upper[i + 1]),
+                        ((xs[i], *y1), upper[i]),
+                    ];
+                    for (from, to) in march_cell(&corners, level) {
+                        segments.push(PathElement::new(vec![from, to], style));
+                    }
+                }
+            }
+        }
+
+        Self {
+            segments: segments.into_iter(),
+        }
+    }
+
+    /**
+    Creates the contour lines of a function sampled on a grid.
+
+    - `xs`: The x coordinates of the grid columns
+    - `ys`: The y coordinates of the grid rows
+    - `f`: The scalar field
+    - `levels`: The levels of the contour lines, each with its style
+    - **returns**: The newly created series
+
+    See [`ContourSeries`] for more information and examples.
+    */
+    pub fn from_fn<XS, YS, F, L, S>(xs: XS, ys: YS, f: F, levels: L) -> Self
+    where
+        XS: IntoIterator<Item = f64>,
+        YS: IntoIterator<Item = f64>,
+        F: Fn(f64, f64) -> f64,
+        L: IntoIterator<Item = (f64, S)>,
+        S: Into<ShapeStyle>,
+    {
+        let xs: Vec<f64> = xs.into_iter().collect();
+        let ys: Vec<f64> = ys.into_iter().collect();
+        let values: Vec<Vec<f64>> = ys
+            .iter()
+            .map(|&y| xs.iter().map(|&x| f(x, y)).collect())
+            .collect();
+        Self::new(xs, ys, values, levels)
+    }
+}
+
+impl Iterator for ContourSeries {
+    type Item = PathElement<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.segments.next()
+    }
+}
+
+/// Computes the segments of the contour line crossing a cell, whose corners are given
+/// counterclockwise starting from the lower left one. A corner is above the level when its
+/// value is greater than or equal to the level.
+fn march_cell(corners: &[((f64, f64), f64); 4], level: f64) -> Vec<((f64, f64), (f64, f64))> {
+    let above = |i: usize| corners[i].1 >= level;
+    // The crossing point on each edge, where the edge `i` joins the corners `i` and `i + 1`
+    let crossings: Vec<Option<(f64, f64)>> = (0..4)
+        .map(|i| {
+            let j = (i + 1) % 4;
+            if above(i) == above(j) {
+                return None;
+            }
+            let ((x0, y0), v0) = corners[i];
+            let ((x1, y1), v1) = corners[j];
+            let t = (level - v0) / (v1 - v0);
+            Some((x0 + (x1 - x0) * t, y0 + (y1 - y0) * t))
+        })
+        .collect();
+
+    let crossed: Vec<usize> = (0..4).filter(|&i| crossings[i].is_some()).collect();
+    let pairs = match crossed.len() {
+        2 => vec![(crossed[0], crossed[1])],
+        4 => {
+            let center = corners.iter().map(|c| c.1).sum::<f64>() / 4.0;
+            // Cut off the corners which are not connected to the center
+            if above(0) == (center >= level) {
+                vec![(0, 1), (2, 3)]
+            } else {
+                vec![(3, 0), (1, 2)]
+            }
+        }
+        _ => vec![],
+    };
+
+    pairs
+        .into_iter()
+        .filter_map(|(a, b)| Some((crossings[a]?, crossings[b]?)))
+        .filter(|(from, to)| from != to)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    fn segments(series: ContourSeries) -> Vec<Vec<(f64, f64)>> {
+        series.map(|e| (&e).point_iter().to_vec()).collect()
+    }
+
+    #[test]
+    fn test_contour_single_cell() {
+        let series = ContourSeries::new(
+            [0.0, 1.0],
+            [0.0, 1.0],
+            [[0.0, 2.0], [0.0, 2.0]],
+            [(1.0, RED)],
+        );
+        assert_eq!(segments(series), vec![vec![(0.5, 0.0), (0.5, 1.0)]]);
+    }
+
+    #[test]
+    fn test_contour_saddle() {
+        let values = [[1.0, 0.0], [0.0, 1.0]];
+        // The center is above the level, so the corners below it are cut off
+        let high = segments(ContourSeries::new(
+            [0.0, 1.0],
+            [0.0, 1.0],
+            values,
+            [(0.4, RED)],
+        ));
+        assert_eq!(
+            high,
+            vec![vec![(0.6, 0.0), (1.0, 0.4)], vec![(0.4, 1.0), (0.0, 0.6)]]
+        );
+        // The center is below the level, so the corners above it are cut off
+        let low = segments(ContourSeries::new(
+            [0.0, 1.0],
+            [0.0, 1.0],
+            values,
+            [(0.6, RED)],
+        ));
+        assert_eq!(
+            low,
+            vec![vec![(0.0, 0.4), (0.4, 0.0)], vec![(1.0, 0.6), (0.6, 1.0)]]
+        );
+    }
+
+    #[test]
+    fn test_contour_level_on_grid_value() {
+        let series =
+            ContourSeries::from_fn([0.0, 1.0, 2.0], [0.0, 1.0, 2.0], |x, y| x + y, [(2.0, RED)]);
+        let segments = segments(series);
+        assert!(!segments.is_empty());
+        for s in segments {
+            assert_ne!(s[0], s[1]);
+            for (x, y) in s {
+                assert!((x + y - 2.0).abs() < 1e-9);
+            }
+        }
+
+        // A single corner exactly on the level must not produce a degenerate segment
+        let series = ContourSeries::new(
+            [0.0, 1.0],
+            [0.0, 1.0],
+            [[1.0, 0.0], [0.0, 0.0]],
+            [(1.0, RED)],
+        );
+        assert_eq!(series.count(), 0);
+    }
+
+    #[test]
+    fn test_draw_contour_series() {
+        let da = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&da)
+            .build_cartesian_2d(0.0..2.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(ContourSeries::new(
+                [0.0, 1.0, 2.0],
+                [0.0, 1.0],
+                [[0.0, 2.0, 0.0], [0.0, 2.0, 0.0]],
+                [(1.0, &BLUE)],
+            ))
+            .unwrap();
+    }
+}
@@ -0,0 +1,130 @@
+use std::ops::Add;
+
+use crate::element::Rectangle;
+use crate::style::{ColorNorm, LinearNorm, RGBAColor, ShapeStyle};
+
+/**
+Presents a matrix as a grid of colored cells.
+
+Each data point `(x, y, value)` is drawn as a filled cell from `(x, y)` to `(x + 1, y + 1)`,
+so a cell covers one unit of the guest coordinate, and its size in pixels follows the spacing
+of the axes. The color of a cell is given by the colormap, which takes the value as is, or
+normalized to `[0, 1]` with [`HeatmapSeries::normalize()`].
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("heatmap_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0..10, 0..10)
+    .unwrap();
+let data = (0..10).flat_map(|x| (0..10).map(move |y| (x, y, f64::from(x * y))));
+chart_context
+    .draw_series(
+        HeatmapSeries::new(data, |v| RdBu.get_color(v).to_rgba()).normalize(),
+    )
+    .unwrap();
+```
+*/
+pub struct HeatmapSeries<'a, X, Y> {
+    data: std::vec::IntoIter<(X, Y, f64)>,
+    colormap: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    range: Option<(f64, f64)>,
+    norm: Option<LinearNorm>,
+}
+
+impl<'a, X, Y> HeatmapSeries<'a, X, Y> {
+    /**
+    Creates a heatmap series.
+
+    - `data`: The cells, each of which is its lower corner and its value
+    - `colormap`: The function mapping a value to the color of its cell
+    - **returns** The newly created series
+
+    See [`HeatmapSeries`] for more information and examples.
+    */
+    pub fn new<I, F>(data: I, colormap: F) -> Self
+    where
+        I: IntoIterator<Item = (X, Y, f64)>,
+        F: Fn(f64) -> RGBAColor + 'a,
+    {
+        let mut range: Option<(f64, f64)> = None;
+        let data: Vec<_> = data
+            .into_iter()
+            .inspect(|&(_, _, v)| {
+                if v.is_finite() {
+                    range = Some(range.map_or((v, v), |(min, max)| (min.min(v), max.max(v))));
+                }
+            })
+            .collect();
+        Self {
+            data: data.into_iter(),
+            colormap: Box::new(colormap),
+            range,
+            norm: None,
+        }
+    }
+
+    /**
+    Scales the values to `[0, 1]` before they are passed to the colormap, so that the minimum
+    value gets the color of 0 and the maximum value gets the color of 1.
+
+    See [`HeatmapSeries`] for more information and examples.
+    */
+    pub fn normalize(mut self) -> Self {
+        self.norm = self.range.map(|(min, max)| LinearNorm(min, max));
+        self
+    }
+}
+
+impl<'a, X, Y> Iterator for HeatmapSeries<'a, X, Y>
+where
+    X: Clone + Add<Output = X> + From<u8>,
+    Y: Clone + Add<Output = Y> + From<u8>,
+{
+    type Item = Rectangle<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, value) = self.data.next()?;
+        let value = self.norm.map_or(value, |norm| norm.normalize(value));
+        let style: ShapeStyle = (self.colormap)(value).into();
+        let far = (x.clone() + X::from(1), y.clone() + Y::from(1));
+        Some(Rectangle::new([(x, y), far], style.filled()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heatmap_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            for expected in [0, 128, 255] {
+                m.check_draw_rect(move |c, _, filled, u, d| {
+                    assert!(filled);
+                    assert_eq!(c, RGBAColor(expected, 0, 0, 1.0));
+                    // Each cell is a unit of the 4 by 2 grid
+                    assert!((d.0 - u.0 - 25).abs() <= 1);
+                    assert!((d.1 - u.1 - 50).abs() <= 1);
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..4, 0..2)
+            .unwrap();
+        chart
+            .draw_series(
+                HeatmapSeries::new([(0, 0, 10.0), (1, 0, 15.0), (3, 1, 20.0)], |v| {
+                    RGBAColor((v * 255.0).round() as u8, 0, 0, 1.0)
+                })
+                .normalize(),
+            )
+            .unwrap();
+    }
+}
@@ -1,13 +1,15 @@
-use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
+use std::collections::{btree_map::IntoIter as BTreeMapIter, BTreeMap, VecDeque};
 use std::marker::PhantomData;
 use std::ops::AddAssign;
 
 use crate::chart::ChartContext;
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{DiscreteRanged, Ranged};
-use crate::element::Rectangle;
-use crate::style::{Color, ShapeStyle, GREEN};
-use plotters_backend::DrawingBackend;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Drawable, PointCollection, Rectangle};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ShapeStyle, TextStyle, GREEN};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 pub trait HistogramType {}
 pub struct Vertical;
@@ -76,6 +78,21 @@ to the right of `.margin(10)`. The lower portion of the bars are removed; the re
 to the right:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@a617d37/apidoc/histogram_baseline_func.svg)
+
+Bars with values below the baseline grow downward from it, and [`Histogram::style_below_baseline()`]
+gives them a distinct style. This is how anomalies from a reference value are usually plotted:
+
+```
+use plotters::prelude::*;
+let anomalies = [(0, 12.5), (1, 14.0), (2, 11.0), (3, 13.5)];
+let drawing_area = SVGBackend::new("histogram_anomaly.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d((0..3).into_segmented(), 10.0..15.0).unwrap();
+chart_context.draw_series(Histogram::vertical(&chart_context).baseline(12.0)
+    .style(RED.filled()).style_below_baseline(BLUE.filled())
+    .data(anomalies)).unwrap();
+```
 */
 pub struct Histogram<'a, BR, A, Tag = Vertical>
 where
@@ -84,8 +101,13 @@ where
     Tag: HistogramType,
 {
     style: Box<dyn Fn(&BR::ValueType, &A) -> ShapeStyle + 'a>,
+    below_style: Option<ShapeStyle>,
+    is_below: fn(&A, &A) -> bool,
     margin: u32,
-    iter: HashMapIter<usize, A>,
+    width_fraction: Option<f64>,
+    pixel_range: (i32, i32),
+    value_range: (i32, i32),
+    iter: BTreeMapIter<usize, A>,
     baseline: Box<dyn Fn(&BR::ValueType) -> A + 'a>,
     br: BR,
     _p: PhantomData<Tag>,
@@ -97,11 +119,16 @@ where
     A: AddAssign<A> + Default + 'a,
     Tag: HistogramType,
 {
-    fn empty(br: &BR) -> Self {
+    fn empty(br: &BR, pixel_range: (i32, i32), value_range: (i32, i32)) -> Self {
         Self {
             style: Box::new(|_, _| GREEN.filled()),
+            below_style: None,
+            is_below: |_, _| false,
             margin: 5,
-            iter: HashMap::new().into_iter(),
+            width_fraction: None,
+            pixel_range,
+            value_range,
+            iter: BTreeMap::new().into_iter(),
             baseline: Box::new(|_| A::default()),
             br: br.clone(),
             _p: PhantomData,
@@ -134,7 +161,25 @@ where
     }
 
     /**
-    Sets the baseline of the histogram.
+    Sets the style of the bars below the baseline, which grow downward (or leftward for
+    horizontal histograms) from the baseline. Without this, all bars use the style set by
+    [`Histogram::style()`] or [`Histogram::style_func()`].
+
+    This is useful for anomaly plots, where the bars below the reference value have a
+    different color than the bars above it.
+    */
+    pub fn style_below_baseline<S: Into<ShapeStyle>>(mut self, style: S) -> Self
+    where
+        A: PartialOrd,
+    {
+        self.below_style = Some(style.into());
+        self.is_below = |value, base| value < base;
+        self
+    }
+
+    /**
+    Sets the baseline of the histogram. The bars extend from the baseline to the values,
+    bars with values below the baseline grow downward.
 
     See [`Histogram`] for more information and examples.
     */
@@ -168,6 +213,50 @@ where
         self
     }
 
+    /**
+    Sets the width of each bar as a fraction of its slot, the bar is centered in the slot.
+    This overrides the margin set by [`Histogram::margin()`].
+
+    - `fraction`: The fraction of the slot width, a fraction of 0 draws thin lines and 1
+      makes the bars fill the whole slot
+
+    See [`Histogram`] for more information and examples.
+    */
+    pub fn bar_width_fraction(mut self, fraction: f64) -> Self {
+        self.width_fraction = Some(fraction.clamp(0.0, 1.0));
+        self
+    }
+
+    /**
+    Draws the value of each bar as a text label at the end of the bar.
+
+    - `style`: The style of the labels
+    - `fmt`: The formatter that turns the value of a bar into its label
+    - **returns** The histogram with the value labels, see [`LabeledBar`] for how the labels are placed
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("histogram_value_labels.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d((0..3).into_segmented(), 0..10).unwrap();
+    chart_context.draw_series(Histogram::vertical(&chart_context).style(BLUE.filled())
+        .data([(0, 3), (1, 7), (2, 5), (3, 9)])
+        .with_value_labels(("sans-serif", 12), |v| v.to_string())).unwrap();
+    ```
+    */
+    pub fn with_value_labels<S: Into<TextStyle<'a>>, F: Fn(&A) -> String + 'a>(
+        self,
+        style: S,
+        fmt: F,
+    ) -> LabeledHistogram<'a, BR, A, Tag> {
+        LabeledHistogram {
+            histogram: self,
+            style: style.into(),
+            fmt: Box::new(fmt),
+        }
+    }
+
     /**
     Specifies the input data for the histogram through an appropriate data iterator.
 
@@ -177,7 +266,7 @@ where
         mut self,
         iter: I,
     ) -> Self {
-        let mut buffer = HashMap::<usize, A>::new();
+        let mut buffer = BTreeMap::<usize, A>::new();
         for (x, y) in iter.into_iter() {
             if let Some(x) = self.br.index_of(&x.into()) {
                 *buffer.entry(x).or_insert_with(Default::default) += y;
@@ -205,8 +294,13 @@ where
         ACoord: Ranged<ValueType = A>,
     {
         let dp = parent.as_coord_spec().x_spec();
+        let (pixel_range, value_range) = parent.plotting_area().get_pixel_range();
 
-        Self::empty(dp)
+        Self::empty(
+            dp,
+            (pixel_range.start, pixel_range.end),
+            (value_range.start, value_range.end),
+        )
     }
 }
 
@@ -227,34 +321,100 @@ where
         ACoord: Ranged<ValueType = A>,
     {
         let dp = parent.as_coord_spec().y_spec();
-        Self::empty(dp)
+        let (value_range, pixel_range) = parent.plotting_area().get_pixel_range();
+        Self::empty(
+            dp,
+            (pixel_range.start, pixel_range.end),
+            (value_range.start, value_range.end),
+        )
     }
 }
 
-impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
+impl<'a, BR, A, Tag> Histogram<'a, BR, A, Tag>
 where
     BR: DiscreteRanged,
     A: AddAssign<A> + Default,
+    Tag: HistogramType,
 {
-    type Item = Rectangle<(BR::ValueType, A)>;
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some((x, y)) = self.iter.next() {
-            if let Some((x, Some(nx))) = self
+    fn bar_style(&self, pos: &BR::ValueType, value: &A, base: &A) -> ShapeStyle {
+        match self.below_style {
+            Some(style) if (self.is_below)(value, base) => style,
+            _ => (self.style)(pos, value),
+        }
+    }
+
+    /// The margin on both sides of the bar in the slot from `pos` to `next`
+    fn bar_margin(&self, pos: &BR::ValueType, next: &BR::ValueType) -> u32 {
+        match self.width_fraction {
+            Some(fraction) => {
+                let slot = (self.br.map(next, self.pixel_range)
+                    - self.br.map(pos, self.pixel_range))
+                .unsigned_abs();
+                (slot as f64 * (1.0 - fraction) / 2.0).round() as u32
+            }
+            None => self.margin,
+        }
+    }
+
+    fn next_bar(&mut self) -> Option<Bar<BR::ValueType, A>> {
+        while let Some((idx, value)) = self.iter.next() {
+            if let Some((pos, Some(next))) = self
                 .br
-                .from_index(x)
-                .map(|v| (v, self.br.from_index(x + 1)))
+                .from_index(idx)
+                .map(|v| (v, self.br.from_index(idx + 1)))
             {
-                let base = (self.baseline)(&x);
-                let style = (self.style)(&x, &y);
-                let mut rect = Rectangle::new([(x, y), (nx, base)], style);
-                rect.set_margin(0, 0, self.margin, self.margin);
-                return Some(rect);
+                let base = (self.baseline)(&pos);
+                let style = self.bar_style(&pos, &value, &base);
+                let margin = self.bar_margin(&pos, &next);
+                return Some(Bar {
+                    pos,
+                    next,
+                    value,
+                    base,
+                    style,
+                    margin,
+                });
             }
         }
         None
     }
 }
 
+/// A bar of the histogram, before it's turned into a rectangle
+struct Bar<X, A> {
+    pos: X,
+    next: X,
+    value: A,
+    base: A,
+    style: ShapeStyle,
+    margin: u32,
+}
+
+impl<X, A> Bar<X, A> {
+    fn into_vertical(self) -> Rectangle<(X, A)> {
+        let mut rect = Rectangle::new([(self.pos, self.value), (self.next, self.base)], self.style);
+        rect.set_margin(0, 0, self.margin, self.margin);
+        rect
+    }
+
+    fn into_horizontal(self) -> Rectangle<(A, X)> {
+        let mut rect = Rectangle::new([(self.value, self.pos), (self.base, self.next)], self.style);
+        rect.set_margin(self.margin, self.margin, 0, 0);
+        rect
+    }
+}
+
+impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    type Item = Rectangle<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar().map(Bar::into_vertical)
+    }
+}
+
 impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Horizontal>
 where
     BR: DiscreteRanged,
@@ -262,19 +422,556 @@ where
 {
     type Item = Rectangle<(A, BR::ValueType)>;
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some((y, x)) = self.iter.next() {
-            if let Some((y, Some(ny))) = self
-                .br
-                .from_index(y)
-                .map(|v| (v, self.br.from_index(y + 1)))
-            {
-                let base = (self.baseline)(&y);
-                let style = (self.style)(&y, &x);
-                let mut rect = Rectangle::new([(x, y), (base, ny)], style);
-                rect.set_margin(self.margin, self.margin, 0, 0);
-                return Some(rect);
+        self.next_bar().map(Bar::into_horizontal)
+    }
+}
+
+/**
+A histogram which draws the value of each bar as a text label, see [`Histogram::with_value_labels()`].
+*/
+pub struct LabeledHistogram<'a, BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+    Tag: HistogramType,
+{
+    histogram: Histogram<'a, BR, A, Tag>,
+    style: TextStyle<'a>,
+    fmt: Box<dyn Fn(&A) -> String + 'a>,
+}
+
+impl<'a, BR, A> Iterator for LabeledHistogram<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    type Item = LabeledBar<'a, (BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let bar = self.histogram.next_bar()?;
+        Some(LabeledBar {
+            label: (self.fmt)(&bar.value),
+            style: self.style.clone(),
+            horizontal: false,
+            limit: self.histogram.value_range,
+            rect: bar.into_vertical(),
+        })
+    }
+}
+
+impl<'a, BR, A> Iterator for LabeledHistogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    type Item = LabeledBar<'a, (A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let bar = self.histogram.next_bar()?;
+        Some(LabeledBar {
+            label: (self.fmt)(&bar.value),
+            style: self.style.clone(),
+            horizontal: true,
+            limit: self.histogram.value_range,
+            rect: bar.into_horizontal(),
+        })
+    }
+}
+
+/**
+A histogram bar with its value label, which is produced by [`LabeledHistogram`].
+
+The label is placed right after the end of the bar, that is, above a vertical bar growing upward
+and below a vertical bar growing downward. If the label would be clipped by the plotting area,
+it's placed inside the bar instead.
+*/
+pub struct LabeledBar<'a, Coord> {
+    rect: Rectangle<Coord>,
+    label: String,
+    style: TextStyle<'a>,
+    horizontal: bool,
+    limit: (i32, i32),
+}
+
+/// The gap between the end of the bar and its value label, in pixels
+const LABEL_GAP: i32 = 3;
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a LabeledBar<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        (&self.rect).point_iter()
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for LabeledBar<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let (end, base) = match points[..] {
+            [end, base] => (end, base),
+            _ => return Ok(()),
+        };
+        self.rect.draw(points.into_iter(), backend, parent_dim)?;
+
+        let (w, h) = backend.estimate_text_size(&self.label, &self.style)?;
+        let (lo, hi) = (
+            self.limit.0.min(self.limit.1),
+            self.limit.0.max(self.limit.1),
+        );
+
+        let (pos, anchor) = if self.horizontal {
+            let y = (end.1 + base.1) / 2;
+            let fits_right = end.0 + LABEL_GAP + w as i32 <= hi;
+            let fits_left = end.0 - LABEL_GAP - w as i32 >= lo;
+            let right = if end.0 >= base.0 {
+                fits_right
+            } else {
+                !fits_left
+            };
+            if right {
+                ((end.0 + LABEL_GAP, y), Pos::new(HPos::Left, VPos::Center))
+            } else {
+                ((end.0 - LABEL_GAP, y), Pos::new(HPos::Right, VPos::Center))
+            }
+        } else {
+            let x = (end.0 + base.0) / 2;
+            let fits_above = end.1 - LABEL_GAP - h as i32 >= lo;
+            let fits_below = end.1 + LABEL_GAP + h as i32 <= hi;
+            let above = if end.1 <= base.1 {
+                fits_above
+            } else {
+                !fits_below
+            };
+            if above {
+                ((x, end.1 - LABEL_GAP), Pos::new(HPos::Center, VPos::Bottom))
+            } else {
+                ((x, end.1 + LABEL_GAP), Pos::new(HPos::Center, VPos::Top))
+            }
+        };
+
+        backend.draw_text(&self.label, &self.style.pos(anchor), pos)
+    }
+}
+
+/// The label, the style and the bars of a data series of a [`StackedHistogram`]
+type SeriesBars<X, A> = (String, ShapeStyle, Vec<Bar<X, A>>);
+
+/**
+Presents multiple data series as stacked histogram bars, where the bars of each series start
+from the end of the bars of the previous series in the same slot, so the height of a stack is
+the total of all the series.
+
+The positive values stack upward from zero and the negative values stack downward from zero,
+separately, so a negative value never hides a positive segment. The segments of each series are
+drawn after the segments of the series below them.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("histogram_stacked.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d((0..3).into_segmented(), -5..15).unwrap();
+chart_context.draw_series(StackedHistogram::vertical(&chart_context).margin(5)
+    .series("red", RED.filled(), [(0, 3), (1, 5), (2, 2), (3, 4)])
+    .series("green", GREEN.filled(), [(0, 4), (1, -2), (2, 6), (3, 1)])
+    .series("blue", BLUE.filled(), [(0, 2), (1, 3), (2, -3), (3, 5)])).unwrap();
+```
+
+Drawing it as a single series gives no legend entry. [`StackedHistogram::draw_with_legend()`]
+draws each data series separately instead, labeled with its label and shown in the legend with
+its style:
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("histogram_stacked_legend.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d((0..3).into_segmented(), -5..15).unwrap();
+StackedHistogram::vertical(&chart_context)
+    .series("red", RED.filled(), [(0, 3), (1, 5), (2, 2), (3, 4)])
+    .series("green", GREEN.filled(), [(0, 4), (1, -2), (2, 6), (3, 1)])
+    .draw_with_legend(&mut chart_context).unwrap();
+chart_context.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct StackedHistogram<'a, BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+    Tag: HistogramType,
+{
+    histogram: Histogram<'a, BR, A, Tag>,
+    /// The running top of the positive stack and the running bottom of the negative stack
+    stacks: BTreeMap<usize, (A, A)>,
+    /// The slot index, the start, the end and the style of each segment, in drawing order
+    segments: VecDeque<(usize, A, A, ShapeStyle)>,
+    /// The label, the style and the number of the segments of each data series, in drawing order
+    series: VecDeque<(String, ShapeStyle, usize)>,
+}
+
+impl<'a, BR, A, Tag> StackedHistogram<'a, BR, A, Tag>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + PartialOrd + Clone + 'a,
+    Tag: HistogramType,
+{
+    fn new(histogram: Histogram<'a, BR, A, Tag>) -> Self {
+        Self {
+            histogram,
+            stacks: BTreeMap::new(),
+            segments: VecDeque::new(),
+            series: VecDeque::new(),
+        }
+    }
+
+    /**
+    Sets the margin for each bar, in backend pixels.
+
+    See [`StackedHistogram`] for more information and examples.
+    */
+    pub fn margin(mut self, value: u32) -> Self {
+        self.histogram = self.histogram.margin(value);
+        self
+    }
+
+    /**
+    Sets the width of each bar as a fraction of its slot, see [`Histogram::bar_width_fraction()`].
+    */
+    pub fn bar_width_fraction(mut self, fraction: f64) -> Self {
+        self.histogram = self.histogram.bar_width_fraction(fraction);
+        self
+    }
+
+    /**
+    Stacks a data series on top of the series added before it. The values falling into the
+    same slot are summed, like [`Histogram::data()`].
+
+    - `label`: The label of this series, see [`StackedHistogram::draw_with_legend()`]
+    - `style`: The style of the segments of this series
+    - `iter`: The data of this series
+
+    See [`StackedHistogram`] for more information and examples.
+    */
+    pub fn series<L, S, TB, I>(mut self, label: L, style: S, iter: I) -> Self
+    where
+        L: Into<String>,
+        S: Into<ShapeStyle>,
+        TB: Into<BR::ValueType>,
+        I: IntoIterator<Item = (TB, A)>,
+    {
+        let style = style.into();
+        let mut buffer = BTreeMap::<usize, A>::new();
+        for (x, y) in iter.into_iter() {
+            if let Some(x) = self.histogram.br.index_of(&x.into()) {
+                *buffer.entry(x).or_insert_with(Default::default) += y;
+            }
+        }
+
+        let zero = A::default();
+        let num_segments = self.segments.len();
+        for (idx, value) in buffer {
+            if value == zero {
+                continue;
+            }
+            let (top, bottom) = self
+                .stacks
+                .entry(idx)
+                .or_insert_with(|| (A::default(), A::default()));
+            let end = if value > zero { top } else { bottom };
+            let start = end.clone();
+            *end += value;
+            self.segments.push_back((idx, start, end.clone(), style));
+        }
+        let num_segments = self.segments.len() - num_segments;
+        self.series.push_back((label.into(), style, num_segments));
+        self
+    }
+
+    /// Take the bars of the data series drawn next, with its label and style
+    fn next_series(&mut self) -> Option<SeriesBars<BR::ValueType, A>> {
+        let (label, style, num_segments) = self.series.pop_front()?;
+        let rest = self.segments.split_off(num_segments);
+        let bars = std::iter::from_fn(|| self.next_bar()).collect();
+        self.segments = rest;
+        Some((label, style, bars))
+    }
+
+    fn next_bar(&mut self) -> Option<Bar<BR::ValueType, A>> {
+        while let Some((idx, base, value, style)) = self.segments.pop_front() {
+            let br = &self.histogram.br;
+            if let (Some(pos), Some(next)) = (br.from_index(idx), br.from_index(idx + 1)) {
+                let margin = self.histogram.bar_margin(&pos, &next);
+                return Some(Bar {
+                    pos,
+                    next,
+                    value,
+                    base,
+                    style,
+                    margin,
+                });
             }
         }
         None
     }
 }
+
+impl<'a, BR, A> StackedHistogram<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + PartialOrd + Clone + 'a,
+{
+    /**
+    Creates a vertical stacked histogram.
+
+    See [`StackedHistogram`] for more information and examples.
+    */
+    pub fn vertical<ACoord, DB: DrawingBackend + 'a>(
+        parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::new(Histogram::vertical(parent))
+    }
+
+    /**
+    Draws each data series as a separate series of the chart, labeled with the label given to
+    [`StackedHistogram::series()`], and with a legend element filled with its style.
+
+    See [`StackedHistogram`] for more information and examples.
+    */
+    pub fn draw_with_legend<'b, ACoord, DB: DrawingBackend + 'b>(
+        mut self,
+        chart: &mut ChartContext<'b, DB, Cartesian2d<BR, ACoord>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        while let Some((label, style, bars)) = self.next_series() {
+            chart
+                .draw_series(bars.into_iter().map(Bar::into_vertical))?
+                .label(label)
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], style));
+        }
+        Ok(())
+    }
+}
+
+impl<'a, BR, A> StackedHistogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + PartialOrd + Clone + 'a,
+{
+    /**
+    Creates a horizontal stacked histogram.
+
+    See [`StackedHistogram`] for more information and examples.
+    */
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::new(Histogram::horizontal(parent))
+    }
+
+    /**
+    Draws each data series as a separate series of the chart, see
+    [`StackedHistogram::draw_with_legend()`] of the vertical stacked histogram.
+    */
+    pub fn draw_with_legend<'b, ACoord, DB: DrawingBackend + 'b>(
+        mut self,
+        chart: &mut ChartContext<'b, DB, Cartesian2d<ACoord, BR>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        while let Some((label, style, bars)) = self.next_series() {
+            chart
+                .draw_series(bars.into_iter().map(Bar::into_horizontal))?
+                .label(label)
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], style));
+        }
+        Ok(())
+    }
+}
+
+impl<'a, BR, A> Iterator for StackedHistogram<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + PartialOrd + Clone + 'a,
+{
+    type Item = Rectangle<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar().map(Bar::into_vertical)
+    }
+}
+
+impl<'a, BR, A> Iterator for StackedHistogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + PartialOrd + Clone + 'a,
+{
+    type Item = Rectangle<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar().map(Bar::into_horizontal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LABEL_GAP;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_histogram_below_baseline() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                // The baseline 5.0 is mapped to y = 50
+                if c == BLUE.to_rgba() {
+                    assert_eq!(u.1, 50);
+                    assert!(d.1 > 50);
+                } else {
+                    assert_eq!(c, RED.to_rgba());
+                    assert!(u.1 < 50);
+                    assert_eq!(d.1, 50);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..3).into_segmented(), 0.0..10.0)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .baseline(5.0)
+                    .style(RED.filled())
+                    .style_below_baseline(BLUE.filled())
+                    .data([(0, 8.0), (1, 2.0), (2, 6.0), (3, 3.0)]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_bar_width_fraction() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|_, _, _, u, d| {
+                // Each slot is about 25 pixels wide, the bar takes 60% of it
+                assert!((d.0 - u.0 - 15).abs() <= 1);
+                assert!((u.0 % 25 - 5).abs() <= 1);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..3).into_segmented(), 0..10)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .bar_width_fraction(0.6)
+                    .data((0..4).map(|x| (x, 5))),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_value_labels() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, text| match text {
+                // The end of the bar is out of the plotting area, so the label goes inside
+                "10" => assert_eq!(pos.1, LABEL_GAP),
+                "5" | "1" => assert!(pos.1 < 90 && pos.1 > 40),
+                _ => panic!("Unexpected label {}", text),
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), 0..10)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .data([(0, 10), (1, 5), (2, 1)])
+                    .with_value_labels(("sans-serif", 12), |v| v.to_string()),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_stacked_histogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            // The value 0 is mapped to y = 50, and each unit is 5 pixels
+            let expected = [
+                (RED, 50, 35),
+                (RED, 50, 40),
+                (GREEN, 35, 25),
+                (GREEN, 50, 60),
+                (BLUE, 25, 20),
+                (BLUE, 60, 65),
+            ];
+            for (color, base, end) in expected {
+                m.check_draw_rect(move |c, _, filled, u, d| {
+                    assert!(filled);
+                    assert_eq!(c, color.to_rgba());
+                    assert_eq!((u.1, d.1), (base.min(end), base.max(end)));
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 6);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), -10..10)
+            .unwrap();
+        chart
+            .draw_series(
+                StackedHistogram::vertical(&chart)
+                    .series("red", RED.filled(), [(0, 3), (1, 2)])
+                    .series("green", GREEN.filled(), [(0, 2), (1, -2)])
+                    .series("blue", BLUE.filled(), [(0, 1), (1, -1)]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_stacked_histogram_legend() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            for color in [RED, RED, BLUE] {
+                m.check_draw_rect(move |c, _, _, _, _| assert_eq!(c, color.to_rgba()));
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), -10..10)
+            .unwrap();
+        StackedHistogram::vertical(&chart)
+            .series("red", RED.filled(), [(0, 3), (1, 2)])
+            .series("blue", BLUE.filled(), [(1, -1)])
+            .draw_with_legend(&mut chart)
+            .unwrap();
+        let labels: Vec<_> = chart.series_anno.iter().map(|a| a.get_label()).collect();
+        assert_eq!(labels, ["red", "blue"]);
+    }
+}
@@ -1,6 +1,8 @@
+use crate::coord::CoordTranslate;
 use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
 use crate::style::ShapeStyle;
-use plotters_backend::DrawingBackend;
+use plotters_backend::{BackendCoord, DrawingBackend};
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 
 /**
@@ -28,6 +30,11 @@ chart_context.draw_series(LineSeries::new(x_values.map(|x| (x, 2. - 0.1 * x * x)
 The result is a chart with three line series; two of them have their data points highlighted:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@64e0a28/apidoc/line_series_point_size.svg)
+
+The points are connected in the order they are given, so the line doubles back if the x values
+aren't increasing, which is what a parametric curve needs. For a function plot, use
+[`LineSeries::sort_by_x()`] to connect the points from left to right, or
+[`LineSeries::require_monotonic_x()`] to reject such input.
 */
 pub struct LineSeries<DB: DrawingBackend, Coord> {
     style: ShapeStyle,
@@ -82,10 +89,99 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
         self.point_size = size;
         self
     }
+
+    /**
+    Maps the data points to the backend pixel coordinates the line is drawn through, without
+    drawing anything. This is useful to place annotations exactly on the rendered line.
+
+    - `coord`: The coordinate spec of the chart the series is drawn on, see
+      [`crate::chart::ChartContext::as_coord_spec()`]
+    - Returns: The pixel coordinates of the points of the line, in order
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("line_series_coords.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+        .unwrap();
+    let series = LineSeries::new([(0.0, 1.0), (2.0, 3.0), (4.0, 2.0)], BLUE);
+    let pixels = series.backend_coords(chart.as_coord_spec());
+    chart.draw_series(series).unwrap();
+    drawing_area
+        .draw(&Text::new("peak", pixels[1], ("sans-serif", 12)))
+        .unwrap();
+    ```
+    */
+    pub fn backend_coords<CT: CoordTranslate<From = Coord>>(
+        &self,
+        coord: &CT,
+    ) -> Vec<BackendCoord> {
+        self.data.iter().map(|p| coord.translate(p)).collect()
+    }
+}
+
+/// The error returned by [`LineSeries::require_monotonic_x()`] when the x values of the series
+/// aren't strictly increasing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonMonotonicX {
+    index: usize,
+}
+
+impl NonMonotonicX {
+    /// Get the index of the first data point whose x value isn't greater than the previous one
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl std::fmt::Display for NonMonotonicX {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "the x value of the data point {} isn't greater than the previous one",
+            self.index
+        )
+    }
+}
+
+impl std::error::Error for NonMonotonicX {}
+
+impl<DB: DrawingBackend, X: PartialOrd, Y> LineSeries<DB, (X, Y)> {
+    /**
+    Sorts the data points by the x values, so that the line is drawn from left to right. The
+    sort is stable, the points with the same x value are kept in the order they are given.
+
+    See [`LineSeries`] for more information and examples.
+    */
+    pub fn sort_by_x(mut self) -> Self {
+        self.data
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        self
+    }
+
+    /**
+    Checks that the x values of the data points are strictly increasing, which is expected by a
+    function plot.
+
+    - Returns: The series, or the error with the index of the first offending point
+
+    See [`LineSeries`] for more information and examples.
+    */
+    pub fn require_monotonic_x(self) -> Result<Self, NonMonotonicX> {
+        match self
+            .data
+            .windows(2)
+            .position(|w| w[0].0.partial_cmp(&w[1].0) != Some(Ordering::Less))
+        {
+            Some(idx) => Err(NonMonotonicX { index: idx + 1 }),
+            None => Ok(self),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::drawing::MockedBackend;
     use crate::prelude::*;
 
     #[test]
@@ -118,4 +214,100 @@ mod test {
             ))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_line_series_x_order() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.windows(2).all(|w| w[0].0 <= w[1].0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+
+        let data = [(3, 1), (1, 2), (5, 3), (1, 4)];
+        assert_eq!(
+            LineSeries::<MockedBackend, _>::new(data, &RED)
+                .require_monotonic_x()
+                .err()
+                .map(|e| e.index()),
+            Some(1)
+        );
+        assert!(LineSeries::<MockedBackend, _>::new(data, &RED)
+            .sort_by_x()
+            .require_monotonic_x()
+            .is_err());
+        assert!(LineSeries::<MockedBackend, _>::new([(1, 2), (3, 1)], &RED)
+            .require_monotonic_x()
+            .is_ok());
+
+        chart
+            .draw_series(LineSeries::new(data, &RED).sort_by_x())
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_backend_coords() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drawn = Rc::new(RefCell::new(vec![]));
+        let drawn_log = drawn.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |_, _, path| {
+                *drawn_log.borrow_mut() = path;
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+
+        let series = LineSeries::new([(0, 0), (5, 8), (10, 3)], &RED);
+        let coords = series.backend_coords(chart.as_coord_spec());
+        assert_eq!(coords[0], chart.backend_coord(&(0, 0)));
+        chart.draw_series(series).expect("Drawing Error");
+
+        assert_eq!(*drawn.borrow(), coords);
+    }
+
+    #[test]
+    fn test_line_series_non_finite() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lengths = Rc::new(RefCell::new(vec![]));
+        let lengths_log = lengths.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |_, _, path| {
+                for (x, y) in path.iter() {
+                    assert!((0..200).contains(x) && (0..200).contains(y));
+                }
+                lengths_log.borrow_mut().push(path.len());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(LineSeries::new(
+                (0..10).map(|x| match x {
+                    5 => (x as f64, f64::NAN),
+                    8 => (f64::INFINITY, x as f64),
+                    _ => (x as f64, x as f64),
+                }),
+                &RED,
+            ))
+            .expect("Drawing Error");
+
+        assert_eq!(*lengths.borrow(), vec![5, 2, 1]);
+    }
 }
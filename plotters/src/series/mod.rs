@@ -12,22 +12,34 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+#[cfg(feature = "contour_series")]
+mod contour;
+#[cfg(feature = "heatmap_series")]
+mod heatmap;
 #[cfg(feature = "histogram")]
 mod histogram;
 #[cfg(feature = "line_series")]
 mod line_series;
 #[cfg(feature = "point_series")]
 mod point_series;
+#[cfg(feature = "slope_series")]
+mod slope_series;
 #[cfg(feature = "surface_series")]
 mod surface;
 
 #[cfg(feature = "area_series")]
 pub use area_series::AreaSeries;
+#[cfg(feature = "contour_series")]
+pub use contour::ContourSeries;
+#[cfg(feature = "heatmap_series")]
+pub use heatmap::HeatmapSeries;
 #[cfg(feature = "histogram")]
-pub use histogram::Histogram;
+pub use histogram::{Histogram, LabeledBar, LabeledHistogram, StackedHistogram};
 #[cfg(feature = "line_series")]
-pub use line_series::LineSeries;
+pub use line_series::{LineSeries, NonMonotonicX};
 #[cfg(feature = "point_series")]
-pub use point_series::PointSeries;
+pub use point_series::{PointLabel, PointLabels, PointSeries};
+#[cfg(feature = "slope_series")]
+pub use slope_series::{Slope, SlopeSeries};
 #[cfg(feature = "surface_series")]
 pub use surface::SurfaceSeries;
@@ -1,5 +1,14 @@
-use crate::element::PointElement;
-use crate::style::{ShapeStyle, SizeDesc};
+use std::borrow::Borrow;
+use std::ops::Range;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::coord::CoordTranslate;
+use crate::element::{Drawable, PointCollection, PointElement, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{ShapeStyle, SizeDesc, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
 /// and create an element for each point
@@ -59,3 +68,169 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
         }
     }
 }
+
+/**
+The series of text labels next to the data points, which takes an iterator of `(x, y, label)`
+and creates a [`PointLabel`] for each point in the plotting area. The points out of the
+plotting area are skipped.
+
+The label is placed off the point by a pixel offset, which is `(5, -5)` by default, and it's
+anchored at the side facing the point. For example, with the default offset, the lower left
+corner of the label is 5 pixels to the right and 5 pixels above the point.
+
+```
+use plotters::prelude::*;
+let data = [(1.0, 3.3, "a"), (2.0, 2.1, "b"), (3.0, 1.5, "c")];
+let drawing_area = SVGBackend::new("point_labels.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..4.0, 0.0..4.0).unwrap();
+chart_context.draw_series(data.map(|(x, y, _)| Circle::new((x, y), 3, BLUE.filled()))).unwrap();
+chart_context.draw_series(PointLabels::new(&chart_context, data, ("sans-serif", 12)).offset((0, -5))).unwrap();
+```
+*/
+pub struct PointLabels<'a, X: Ranged, Y: Ranged, I: IntoIterator> {
+    coord: Cartesian2d<X, Y>,
+    data_iter: I::IntoIter,
+    style: TextStyle<'a>,
+    offset: BackendCoord,
+}
+
+impl<'a, X, Y, I, T> PointLabels<'a, X, Y, I>
+where
+    X: Ranged + Clone,
+    Y: Ranged + Clone,
+    I: IntoIterator<Item = (X::ValueType, Y::ValueType, T)>,
+    T: Borrow<str>,
+{
+    /// Create a new point label series
+    ///
+    /// - `parent`: The chart the labels are drawn on, which is used to skip the points out of the plotting area
+    /// - `iter`: The iterator of the points and their labels
+    /// - `style`: The text style of the labels
+    /// - **returns** The newly created point label series
+    pub fn new<DB: DrawingBackend, S: Into<TextStyle<'a>>>(
+        parent: &ChartContext<DB, Cartesian2d<X, Y>>,
+        iter: I,
+        style: S,
+    ) -> Self {
+        Self {
+            coord: parent.as_coord_spec().clone(),
+            data_iter: iter.into_iter(),
+            style: style.into(),
+            offset: (0, 0),
+        }
+        .offset((5, -5))
+    }
+
+    /// Set the offset of the labels from the points, the anchor of the labels is adjusted
+    /// so that the labels stay on the side of the offset
+    ///
+    /// - `offset`: The offset in pixels
+    /// - **returns** The up-to-dated point label series
+    pub fn offset(mut self, offset: BackendCoord) -> Self {
+        let h_pos = match offset.0 {
+            dx if dx > 0 => HPos::Left,
+            dx if dx < 0 => HPos::Right,
+            _ => HPos::Center,
+        };
+        let v_pos = match offset.1 {
+            dy if dy > 0 => VPos::Top,
+            dy if dy < 0 => VPos::Bottom,
+            _ => VPos::Center,
+        };
+        self.offset = offset;
+        self.style = self.style.pos(Pos::new(h_pos, v_pos));
+        self
+    }
+}
+
+impl<'a, X, Y, I, T> Iterator for PointLabels<'a, X, Y, I>
+where
+    X: Ranged,
+    Y: Ranged,
+    I: IntoIterator<Item = (X::ValueType, Y::ValueType, T)>,
+    T: Borrow<str>,
+{
+    type Item = PointLabel<'a, (X::ValueType, Y::ValueType), T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let x_range = self.coord.get_x_axis_pixel_range();
+        let y_range = self.coord.get_y_axis_pixel_range();
+        let in_range = |v: i32, r: &Range<i32>| r.start.min(r.end) <= v && v <= r.start.max(r.end);
+        for (x, y, label) in self.data_iter.by_ref() {
+            let coord = (x, y);
+            let (px, py) = self.coord.translate(&coord);
+            if in_range(px, &x_range) && in_range(py, &y_range) {
+                return Some(PointLabel {
+                    coord,
+                    offset: self.offset,
+                    text: Text::new(label, self.offset, self.style.clone()),
+                });
+            }
+        }
+        None
+    }
+}
+
+/// A text label placed off a data point by a pixel offset, see [`PointLabels`]
+pub struct PointLabel<'a, Coord, T: Borrow<str>> {
+    coord: Coord,
+    offset: BackendCoord,
+    text: Text<'a, BackendCoord, T>,
+}
+
+impl<'a, 'b, Coord, T: Borrow<str>> PointCollection<'a, Coord> for &'a PointLabel<'b, Coord, T> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord, T: Borrow<str>, DB: DrawingBackend> Drawable<DB> for PointLabel<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (dx, dy) = self.offset;
+        self.text
+            .draw(points.map(|(x, y)| (x + dx, y + dy)), backend, parent_dim)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_point_labels() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, text| match text {
+                "a" => assert_eq!(pos, (5, 45)),
+                "b" => assert_eq!(pos, (64, 94)),
+                _ => panic!("Unexpected label {}", text),
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        chart
+            .draw_series(PointLabels::new(
+                &chart,
+                [
+                    (0.0, 5.0, "a"),
+                    (6.0, 0.0, "b"),
+                    (11.0, 5.0, "c"),
+                    (5.0, -1.0, "d"),
+                ],
+                ("sans-serif", 10),
+            ))
+            .unwrap();
+    }
+}
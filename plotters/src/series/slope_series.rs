@@ -0,0 +1,220 @@
+use std::borrow::Borrow;
+
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The gap between the end markers and the labels, in pixels
+const LABEL_GAP: i32 = 5;
+
+/**
+The slope graph series, which takes an iterator of `(label, left_value, right_value)` and
+creates a [`Slope`] for each item, connecting the two values at the two x positions.
+
+The slope is drawn with the up style when the right value is greater than or equal to the left
+value, and with the down style otherwise. The labels at the ends are optional, the left labels
+are drawn on the left of the left markers, and the right labels on the right of the right
+markers.
+
+```
+use plotters::prelude::*;
+let data = [("apple", 3.0, 4.5), ("banana", 4.0, 2.5), ("cherry", 1.5, 2.0)];
+let drawing_area = SVGBackend::new("slope_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(-1.0..2.0, 0.0..5.0)
+    .unwrap();
+chart
+    .draw_series(
+        SlopeSeries::new(data, (0.0, 1.0), GREEN, RED)
+            .left_labels(("sans-serif", 12))
+            .right_labels(("sans-serif", 12)),
+    )
+    .unwrap();
+```
+*/
+pub struct SlopeSeries<'a, X, I: IntoIterator> {
+    data_iter: I::IntoIter,
+    x: (X, X),
+    up_style: ShapeStyle,
+    down_style: ShapeStyle,
+    marker_size: u32,
+    left_labels: Option<TextStyle<'a>>,
+    right_labels: Option<TextStyle<'a>>,
+}
+
+impl<'a, X, I: IntoIterator> SlopeSeries<'a, X, I> {
+    /// Create a new slope graph series
+    ///
+    /// - `iter`: The iterator of the labels and the left and right values
+    /// - `x`: The x positions of the left and right values
+    /// - `up_style`: The style of the slopes going up or staying flat
+    /// - `down_style`: The style of the slopes going down
+    /// - **returns** The newly created slope graph series
+    pub fn new<S: Into<ShapeStyle>, T: Into<ShapeStyle>>(
+        iter: I,
+        x: (X, X),
+        up_style: S,
+        down_style: T,
+    ) -> Self {
+        Self {
+            data_iter: iter.into_iter(),
+            x,
+            up_style: up_style.into(),
+            down_style: down_style.into(),
+            marker_size: 3,
+            left_labels: None,
+            right_labels: None,
+        }
+    }
+
+    /// Set the radius of the markers at the ends of the slopes, which is 3 pixels by default
+    ///
+    /// - `size`: The radius in pixels, 0 for no markers
+    /// - **returns** The up-to-dated slope graph series
+    pub fn marker_size(mut self, size: u32) -> Self {
+        self.marker_size = size;
+        self
+    }
+
+    /// Draw the labels on the left of the left ends
+    ///
+    /// - `style`: The text style of the labels
+    /// - **returns** The up-to-dated slope graph series
+    pub fn left_labels<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.left_labels = Some(style.into().pos(Pos::new(HPos::Right, VPos::Center)));
+        self
+    }
+
+    /// Draw the labels on the right of the right ends
+    ///
+    /// - `style`: The text style of the labels
+    /// - **returns** The up-to-dated slope graph series
+    pub fn right_labels<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.right_labels = Some(style.into().pos(Pos::new(HPos::Left, VPos::Center)));
+        self
+    }
+}
+
+impl<'a, X, Y, T, I> Iterator for SlopeSeries<'a, X, I>
+where
+    X: Clone,
+    Y: PartialOrd,
+    T: Borrow<str>,
+    I: IntoIterator<Item = (T, Y, Y)>,
+{
+    type Item = Slope<'a, (X, Y), T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (label, left, right) = self.data_iter.next()?;
+        let style = if right < left {
+            self.down_style
+        } else {
+            self.up_style
+        };
+        Some(Slope {
+            points: [(self.x.0.clone(), left), (self.x.1.clone(), right)],
+            label,
+            style,
+            marker_size: self.marker_size,
+            left_label: self.left_labels.clone(),
+            right_label: self.right_labels.clone(),
+        })
+    }
+}
+
+/// A line connecting a pair of values with the markers and the labels at its ends, see
+/// [`SlopeSeries`]
+pub struct Slope<'a, Coord, T: Borrow<str>> {
+    points: [Coord; 2],
+    label: T,
+    style: ShapeStyle,
+    marker_size: u32,
+    left_label: Option<TextStyle<'a>>,
+    right_label: Option<TextStyle<'a>>,
+}
+
+impl<'a, 'b, Coord, T: Borrow<str>> PointCollection<'a, Coord> for &'a Slope<'b, Coord, T> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, T: Borrow<str>, DB: DrawingBackend> Drawable<DB> for Slope<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (left, right) = match (points.next(), points.next()) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Ok(()),
+        };
+
+        backend.draw_line(left, right, &self.style)?;
+        if self.marker_size > 0 {
+            let marker = self.style.filled();
+            backend.draw_circle(left, self.marker_size, &marker, true)?;
+            backend.draw_circle(right, self.marker_size, &marker, true)?;
+        }
+
+        let gap = self.marker_size as i32 + LABEL_GAP;
+        if let Some(style) = &self.left_label {
+            backend.draw_text(self.label.borrow(), style, (left.0 - gap, left.1))?;
+        }
+        if let Some(style) = &self.right_label {
+            backend.draw_text(self.label.borrow(), style, (right.0 + gap, right.1))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_slope_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, GREEN.to_rgba());
+                assert!(from.1 > to.1);
+            });
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(from.1 < to.1);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "up");
+                assert_eq!(pos.0, 87);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "down");
+                assert_eq!(pos.0, 87);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 2);
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..5.0)
+            .unwrap();
+        chart
+            .draw_series(
+                SlopeSeries::new(
+                    [("up", 1.0, 2.0), ("down", 4.0, 3.0)],
+                    (1.0, 4.0),
+                    GREEN,
+                    RED,
+                )
+                .right_labels(("sans-serif", 10)),
+            )
+            .unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use super::colors::{BLACK, WHITE};
 use super::palette::Palette;
 use super::ShapeStyle;
 
@@ -51,6 +52,24 @@ pub trait Color {
     {
         Into::<ShapeStyle>::into(self).stroke_width(width)
     }
+
+    /**
+    Rotate the hue of the color on the HSL color wheel, keeping its saturation, lightness and
+    alpha. Gray colors have no hue, so they are unchanged.
+
+    - `degrees`: The angle of the rotation, which may be negative or more than a full turn
+
+    ```
+    use plotters::prelude::*;
+    assert_eq!(RED.rotate_hue(120.0), GREEN.to_rgba());
+    assert_eq!(RED.mix(0.5).rotate_hue(-120.0), BLUE.mix(0.5));
+    ```
+    */
+    fn rotate_hue(&self, degrees: f64) -> RGBAColor {
+        let HSLColor(h, s, l) = HSLColor::from(RGBColor::from_tuple(self.rgb()));
+        let (r, g, b) = HSLColor((h + degrees / 360.0).rem_euclid(1.0), s, l).rgb();
+        RGBAColor(r, g, b, self.alpha())
+    }
 }
 
 impl<T: Color> Color for &'_ T {
@@ -76,6 +95,58 @@ impl Color for RGBAColor {
     }
 }
 
+impl RGBAColor {
+    /**
+    Pick black or white, whichever gives the better contrast as the color of a text drawn on
+    this color, such as a value label on a bar, a pie slice or a heat map cell.
+
+    The choice is made by the relative luminance and the contrast ratio defined by WCAG.
+    A translucent color is composited over a white background first, use
+    [`RGBAColor::contrasting_text_color_over`] for other backgrounds.
+
+    ```
+    use plotters::prelude::*;
+    assert_eq!(RGBAColor::from(BLUE).contrasting_text_color(), WHITE);
+    assert_eq!(RGBAColor::from(YELLOW).contrasting_text_color(), BLACK);
+    assert_eq!(BLUE.mix(0.1).contrasting_text_color(), BLACK);
+    ```
+    */
+    pub fn contrasting_text_color(&self) -> RGBColor {
+        self.contrasting_text_color_over(WHITE)
+    }
+
+    /// Pick black or white as the color of a text drawn on this color, which is composited over
+    /// the given background first. See [`RGBAColor::contrasting_text_color`] for details.
+    pub fn contrasting_text_color_over(&self, background: RGBColor) -> RGBColor {
+        let alpha = self.3.clamp(0.0, 1.0);
+        let blend = |fg: u8, bg: u8| fg as f64 * alpha + bg as f64 * (1.0 - alpha);
+        let luminance = relative_luminance((
+            blend(self.0, background.0),
+            blend(self.1, background.1),
+            blend(self.2, background.2),
+        ));
+        // Compare the contrast ratios (L1 + 0.05) / (L2 + 0.05) against black and white
+        if (luminance + 0.05) * (luminance + 0.05) > 0.05 * 1.05 {
+            BLACK
+        } else {
+            WHITE
+        }
+    }
+}
+
+/// The relative luminance of a color given by its 0-255 sRGB channels, as defined by WCAG
+fn relative_luminance((r, g, b): (f64, f64, f64)) -> f64 {
+    let linear = |c: f64| {
+        let c = c / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
 impl From<RGBColor> for RGBAColor {
     fn from(rgb: RGBColor) -> Self {
         Self(rgb.0, rgb.1, rgb.2, 1.0)
@@ -107,6 +178,12 @@ impl<P: Palette> Color for PaletteColor<P> {
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub struct RGBColor(pub u8, pub u8, pub u8);
 
+impl RGBColor {
+    fn from_tuple((r, g, b): (u8, u8, u8)) -> Self {
+        RGBColor(r, g, b)
+    }
+}
+
 impl BackendStyle for RGBAColor {
     fn color(&self) -> BackendColor {
         self.to_backend_color()
@@ -128,10 +205,47 @@ impl BackendStyle for RGBColor {
     }
 }
 
-/// The color described by HSL color space
+/// The color described by HSL color space, which is the hue, the saturation and the lightness,
+/// all in `[0, 1]`. A hue of 1 is a full turn of the color wheel, so evenly spaced hues make
+/// distinct colors, for example `HSLColor(i as f64 / n as f64, 0.7, 0.5)`.
 #[derive(Copy, Clone, PartialEq, Debug, Default)]
 pub struct HSLColor(pub f64, pub f64, pub f64);
 
+/// Convert the RGB color to the hue, the saturation and the lightness of the HSL, and the value
+/// of the HSV. The hue of a gray color is 0.
+fn rgb_to_hue_sat_light_value(color: RGBColor) -> (f64, f64, f64, f64) {
+    let (r, g, b) = (
+        f64::from(color.0) / 255.0,
+        f64::from(color.1) / 255.0,
+        f64::from(color.2) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    let l = (max + min) / 2.0;
+    if chroma == 0.0 {
+        return (0.0, 0.0, l, max);
+    }
+
+    let h = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    } / 6.0;
+    let s = chroma / (1.0 - (2.0 * l - 1.0).abs());
+    (h, s.min(1.0), l, max)
+}
+
+impl From<RGBColor> for HSLColor {
+    /// Convert the RGB color to HSL, the conversion back to RGB gives the same color
+    fn from(color: RGBColor) -> Self {
+        let (h, s, l, _) = rgb_to_hue_sat_light_value(color);
+        HSLColor(h, s, l)
+    }
+}
+
 impl Color for HSLColor {
     #[inline(always)]
     #[allow(clippy::many_single_char_names)]
@@ -182,3 +296,90 @@ impl Color for HSLColor {
         }
     }
 }
+
+/// The color described by HSV color space, which is the hue, the saturation and the value, all
+/// in `[0, 1]`. A hue of 1 is a full turn of the color wheel.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct HSVColor(pub f64, pub f64, pub f64);
+
+impl Color for HSVColor {
+    #[inline(always)]
+    fn to_backend_color(&self) -> BackendColor {
+        let h = if self.0 >= 1.0 {
+            0.0
+        } else {
+            self.0.max(0.0) * 6.0
+        };
+        let (s, v) = (self.1.clamp(0.0, 1.0), self.2.clamp(0.0, 1.0));
+        let chroma = v * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = v - chroma;
+        let cvt = |c: f64| ((c + m) * 255.0).round() as u8;
+        BackendColor {
+            rgb: (cvt(r), cvt(g), cvt(b)),
+            alpha: 1.0,
+        }
+    }
+}
+
+impl From<RGBColor> for HSVColor {
+    /// Convert the RGB color to HSV, the conversion back to RGB gives the same color
+    fn from(color: RGBColor) -> Self {
+        let (h, _, _, v) = rgb_to_hue_sat_light_value(color);
+        let s = if v == 0.0 {
+            0.0
+        } else {
+            let min = f64::from(color.0.min(color.1).min(color.2)) / 255.0;
+            (v - min) / v
+        };
+        HSVColor(h, s, v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hsl_hsv_round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = RGBColor(r, g, b);
+                    assert_eq!(HSLColor::from(color).rgb(), (r, g, b));
+                    assert_eq!(HSVColor::from(color).rgb(), (r, g, b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_achromatic_colors() {
+        assert_eq!(
+            HSLColor::from(RGBColor(128, 128, 128)),
+            HSLColor(0.0, 0.0, 128.0 / 255.0)
+        );
+        assert_eq!(HSVColor::from(BLACK), HSVColor(0.0, 0.0, 0.0));
+        assert_eq!(HSVColor(0.5, 0.0, 1.0).rgb(), (255, 255, 255));
+        assert_eq!(WHITE.rotate_hue(90.0), WHITE.to_rgba());
+    }
+
+    #[test]
+    fn test_rotate_hue() {
+        assert_eq!(HSVColor(0.0, 1.0, 1.0).rgb(), (255, 0, 0));
+        assert_eq!(HSVColor(0.5, 1.0, 1.0).rgb(), (0, 255, 255));
+        assert_eq!(RGBColor(255, 0, 0).rotate_hue(180.0).rgb(), (0, 255, 255));
+        assert_eq!(
+            RGBColor(255, 0, 0).rotate_hue(-60.0 + 720.0).rgb(),
+            (255, 0, 255)
+        );
+    }
+}
@@ -0,0 +1,390 @@
+//! Color maps and color normalization, which map the data values to colors.
+use crate::style::RGBColor;
+
+/// The normalization of the data values to `[0, 1]`, which is usually used to pick the color
+/// of a value from a color gradient in a heatmap
+pub trait ColorNorm {
+    /// Map the value to `[0, 1]`, the values out of the range of the normalization are clamped
+    fn normalize(&self, value: f64) -> f64;
+}
+
+/**
+The linear normalization, which maps `min` to 0 and `max` to 1.
+
+```
+use plotters::prelude::*;
+assert_eq!(LinearNorm(0.0, 10.0).normalize(2.5), 0.25);
+assert_eq!(LinearNorm(0.0, 10.0).normalize(20.0), 1.0);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearNorm(pub f64, pub f64);
+
+impl ColorNorm for LinearNorm {
+    fn normalize(&self, value: f64) -> f64 {
+        let LinearNorm(min, max) = *self;
+        if max == min {
+            return 0.5;
+        }
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    }
+}
+
+/**
+The diverging normalization, which maps `center` to 0.5 and extends symmetrically by
+`half_range` to both sides, that is, `center - half_range` is mapped to 0 and
+`center + half_range` is mapped to 1.
+
+This makes sure the center value always gets the neutral color of a diverging color gradient,
+regardless of the data extent.
+
+```
+use plotters::prelude::*;
+let norm = DivergingNorm(0.0, 4.0);
+assert_eq!(norm.normalize(0.0), 0.5);
+assert_eq!(norm.normalize(-2.0), 0.25);
+assert_eq!(norm.normalize(10.0), 1.0);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DivergingNorm(pub f64, pub f64);
+
+impl DivergingNorm {
+    /// Create a diverging normalization centered at `center`, which covers the largest distance
+    /// from the center to the data values
+    ///
+    /// - `center`: The value mapped to 0.5
+    /// - `values`: The data values
+    /// - **returns** The newly created normalization
+    pub fn covering<I: IntoIterator<Item = f64>>(center: f64, values: I) -> Self {
+        let half_range = values
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .map(|v| (v - center).abs())
+            .fold(0.0, f64::max);
+        DivergingNorm(center, half_range)
+    }
+}
+
+impl ColorNorm for DivergingNorm {
+    fn normalize(&self, value: f64) -> f64 {
+        let DivergingNorm(center, half_range) = *self;
+        if half_range == 0.0 {
+            return 0.5;
+        }
+        (0.5 + (value - center) / half_range / 2.0).clamp(0.0, 1.0)
+    }
+}
+
+/**
+The logarithmic normalization, which maps `min` to 0 and `max` to 1 on the log scale. Both
+`min` and `max` should be positive, and the non-positive values are mapped to 0.
+
+This keeps the small values distinguishable when the data spans orders of magnitude.
+
+```
+use plotters::prelude::*;
+let norm = LogNorm(1.0, 1000.0);
+assert!((norm.normalize(10.0) - 1.0 / 3.0).abs() < 1e-9);
+assert_eq!(norm.normalize(0.0), 0.0);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogNorm(pub f64, pub f64);
+
+impl ColorNorm for LogNorm {
+    fn normalize(&self, value: f64) -> f64 {
+        let LogNorm(min, max) = *self;
+        if value <= 0.0 {
+            return 0.0;
+        }
+        LinearNorm(min.ln(), max.ln()).normalize(value.max(min).ln())
+    }
+}
+
+/**
+A color map, which maps a number in `[0, 1]` to a color. It's usually paired with a
+[`ColorNorm`], which maps the data values to `[0, 1]` first.
+
+```
+use plotters::prelude::*;
+let norm = DivergingNorm(0.0, 2.0);
+assert_eq!(RdBu.get_color_normalized(0.0, &norm), RdBu.get_color(0.5));
+```
+*/
+pub trait ColorMap {
+    /// Get the color of the number, the number is clamped to `[0, 1]`
+    fn get_color(&self, h: f64) -> RGBColor;
+
+    /// Get the color of the data value, which is normalized by `norm` first
+    fn get_color_normalized<N: ColorNorm>(&self, value: f64, norm: &N) -> RGBColor {
+        self.get_color(norm.normalize(value))
+    }
+}
+
+/// Linearly interpolate the evenly spaced color stops
+fn interpolate_stops(stops: &[(u8, u8, u8)], h: f64) -> RGBColor {
+    // A NaN falls back to the middle of the map, which is the neutral color of diverging maps
+    let h = if h.is_nan() { 0.5 } else { h.clamp(0.0, 1.0) };
+    let pos = h * (stops.len() - 1) as f64;
+    let idx = (pos.floor() as usize).min(stops.len() - 2);
+    let t = pos - idx as f64;
+    let (from, to) = (stops[idx], stops[idx + 1]);
+    let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    RGBColor(lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+}
+
+macro_rules! define_diverging_colormap {
+    ($name:ident, $doc:expr, [$($stop:expr),+ $(,)?]) => {
+        #[doc = $doc]
+        ///
+        /// This is a diverging color map, 0.5 is mapped to the neutral midpoint color, which
+        /// is where [`DivergingNorm`] maps its center.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl $name {
+            const STOPS: &'static [(u8, u8, u8)] = &[$($stop),+];
+        }
+
+        impl ColorMap for $name {
+            fn get_color(&self, h: f64) -> RGBColor {
+                interpolate_stops(Self::STOPS, h)
+            }
+        }
+    };
+}
+
+define_diverging_colormap!(
+    RdBu,
+    "The red-white-blue color map, from dark red at 0 to dark blue at 1",
+    [
+        (103, 0, 31),
+        (178, 24, 43),
+        (214, 96, 77),
+        (244, 165, 130),
+        (253, 219, 199),
+        (247, 247, 247),
+        (209, 229, 240),
+        (146, 197, 222),
+        (67, 147, 195),
+        (33, 102, 172),
+        (5, 48, 97),
+    ]
+);
+
+define_diverging_colormap!(
+    Coolwarm,
+    "The cool-warm color map, from blue at 0 through light gray to red at 1",
+    [
+        (59, 76, 192),
+        (98, 130, 234),
+        (141, 176, 254),
+        (184, 208, 249),
+        (221, 221, 221),
+        (245, 196, 173),
+        (244, 154, 123),
+        (222, 96, 77),
+        (180, 4, 38),
+    ]
+);
+
+define_diverging_colormap!(
+    BrBG,
+    "The brown-white-blue-green color map, from dark brown at 0 to dark teal at 1",
+    [
+        (84, 48, 5),
+        (140, 81, 10),
+        (191, 129, 45),
+        (223, 194, 125),
+        (246, 232, 195),
+        (245, 245, 245),
+        (199, 234, 229),
+        (128, 205, 193),
+        (53, 151, 143),
+        (1, 102, 94),
+        (0, 60, 48),
+    ]
+);
+
+/// Convert an sRGB channel to the linear RGB
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear RGB channel to sRGB
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Interpolate the evenly spaced color stops in the linear RGB, which keeps the blend of two
+/// stops as bright as they are, rather than darker as the blend in sRGB
+fn interpolate_stops_linear(stops: &[(u8, u8, u8)], h: f64) -> RGBColor {
+    let h = if h.is_nan() { 0.5 } else { h.clamp(0.0, 1.0) };
+    let pos = h * (stops.len() - 1) as f64;
+    let idx = (pos.floor() as usize).min(stops.len() - 2);
+    let t = pos - idx as f64;
+    let (from, to) = (stops[idx], stops[idx + 1]);
+    let lerp = |a: u8, b: u8| {
+        let (a, b) = (srgb_to_linear(a), srgb_to_linear(b));
+        linear_to_srgb(a + (b - a) * t)
+    };
+    RGBColor(lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+}
+
+macro_rules! define_sequential_colormap {
+    ($name:ident, $doc:expr, [$($stop:expr),+ $(,)?]) => {
+        #[doc = $doc]
+        ///
+        /// This is a perceptually uniform sequential color map, whose lightness increases
+        /// monotonically from 0 to 1, so it stays readable in grayscale. The colors between the
+        /// control points are interpolated in the linear RGB.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl $name {
+            const STOPS: &'static [(u8, u8, u8)] = &[$($stop),+];
+        }
+
+        impl ColorMap for $name {
+            fn get_color(&self, h: f64) -> RGBColor {
+                interpolate_stops_linear(Self::STOPS, h)
+            }
+        }
+    };
+}
+
+define_sequential_colormap!(
+    Viridis,
+    "The viridis color map, from dark purple at 0 through teal to yellow at 1",
+    [
+        (68, 1, 84),
+        (72, 36, 117),
+        (65, 68, 135),
+        (53, 95, 141),
+        (42, 120, 142),
+        (33, 145, 140),
+        (34, 168, 132),
+        (68, 191, 112),
+        (122, 209, 81),
+        (189, 223, 38),
+        (253, 231, 37),
+    ]
+);
+
+define_sequential_colormap!(
+    Magma,
+    "The magma color map, from black at 0 through purple and pink to light yellow at 1",
+    [
+        (0, 0, 4),
+        (20, 14, 54),
+        (59, 15, 112),
+        (100, 26, 128),
+        (140, 41, 129),
+        (183, 55, 121),
+        (222, 73, 104),
+        (247, 112, 92),
+        (254, 159, 109),
+        (254, 207, 146),
+        (252, 253, 191),
+    ]
+);
+
+define_sequential_colormap!(
+    Inferno,
+    "The inferno color map, from black at 0 through purple and orange to light yellow at 1",
+    [
+        (0, 0, 4),
+        (22, 11, 57),
+        (66, 10, 104),
+        (106, 23, 110),
+        (147, 38, 103),
+        (188, 55, 84),
+        (221, 81, 58),
+        (243, 120, 25),
+        (252, 165, 10),
+        (246, 215, 70),
+        (252, 255, 164),
+    ]
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diverging_norm() {
+        let norm = DivergingNorm::covering(0.0, [-1.0, 0.5, 4.0]);
+        assert_eq!(norm, DivergingNorm(0.0, 4.0));
+        assert_eq!(norm.normalize(0.0), 0.5);
+        assert_eq!(norm.normalize(-1.0), 0.375);
+        assert_eq!(norm.normalize(4.0), 1.0);
+        assert_eq!(norm.normalize(-8.0), 0.0);
+        assert_eq!(DivergingNorm(1.0, 0.0).normalize(3.0), 0.5);
+    }
+
+    #[test]
+    fn test_diverging_colormaps() {
+        assert_eq!(RdBu.get_color(0.0), RGBColor(103, 0, 31));
+        assert_eq!(RdBu.get_color(0.5), RGBColor(247, 247, 247));
+        assert_eq!(RdBu.get_color(1.0), RGBColor(5, 48, 97));
+        assert_eq!(RdBu.get_color(2.0), RGBColor(5, 48, 97));
+        assert_eq!(Coolwarm.get_color(0.5), RGBColor(221, 221, 221));
+        assert_eq!(Coolwarm.get_color(f64::NAN), RGBColor(221, 221, 221));
+        assert_eq!(BrBG.get_color(0.5), RGBColor(245, 245, 245));
+        assert_eq!(BrBG.get_color(0.05), RGBColor(112, 65, 8));
+
+        let norm = DivergingNorm(10.0, 5.0);
+        assert_eq!(RdBu.get_color_normalized(10.0, &norm), RdBu.get_color(0.5));
+        assert_eq!(RdBu.get_color_normalized(-10.0, &norm), RdBu.get_color(0.0));
+    }
+
+    #[test]
+    fn test_log_norm() {
+        let norm = LogNorm(0.1, 1000.0);
+        assert!((norm.normalize(1.0) - 0.25).abs() < 1e-9);
+        assert!((norm.normalize(100.0) - 0.75).abs() < 1e-9);
+        assert_eq!(norm.normalize(-5.0), 0.0);
+        assert_eq!(norm.normalize(0.01), 0.0);
+        assert_eq!(norm.normalize(1e9), 1.0);
+    }
+
+    #[test]
+    fn test_sequential_colormaps() {
+        assert_eq!(Viridis.get_color(0.0), RGBColor(68, 1, 84));
+        assert_eq!(Viridis.get_color(0.5), RGBColor(33, 145, 140));
+        assert_eq!(Viridis.get_color(1.0), RGBColor(253, 231, 37));
+        assert_eq!(Magma.get_color(-1.0), RGBColor(0, 0, 4));
+        assert_eq!(Inferno.get_color(1.0), RGBColor(252, 255, 164));
+
+        // The blend of black and white in the linear RGB is lighter than the sRGB midpoint
+        let gray = interpolate_stops_linear(&[(0, 0, 0), (255, 255, 255)], 0.5);
+        assert_eq!(gray, RGBColor(188, 188, 188));
+
+        let luminance = |c: RGBColor| {
+            0.2126 * f64::from(c.0) + 0.7152 * f64::from(c.1) + 0.0722 * f64::from(c.2)
+        };
+        for i in 0..20 {
+            let (a, b) = (i as f64 / 20.0, (i + 1) as f64 / 20.0);
+            assert!(luminance(Viridis.get_color(a)) < luminance(Viridis.get_color(b)));
+            assert!(luminance(Magma.get_color(a)) < luminance(Magma.get_color(b)));
+            assert!(luminance(Inferno.get_color(a)) < luminance(Inferno.get_color(b)));
+        }
+
+        let norm = LinearNorm(10.0, 20.0);
+        assert_eq!(
+            Viridis.get_color_normalized(15.0, &norm),
+            Viridis.get_color(0.5)
+        );
+    }
+}
@@ -153,8 +153,21 @@ impl<'a> FontDesc<'a> {
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
-        let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
-        Ok((w.unsigned_abs(), h.unsigned_abs()))
+        let (w, h) = ((max_x - min_x) as u32, (max_y - min_y) as u32);
+        Ok(self.get_transform().transform_size(w, h))
+    }
+
+    /// Check if every character of the text has a glyph in this font, or the font has a
+    /// placeholder glyph to draw instead. The characters failing this check are skipped when
+    /// the text is drawn or measured.
+    ///
+    /// - `text`: The text to check
+    /// - **returns**: The error of the first character which can't be drawn, if any
+    pub fn check_glyphs(&self, text: &str) -> FontResult<()> {
+        match &self.data {
+            Ok(ref font) => font.check_glyphs(text),
+            Err(e) => Err(e.clone()),
+        }
     }
 
     /// Actually draws a font with a drawing function
@@ -65,6 +65,11 @@ pub trait FontData: Clone {
     type ErrorType: Sized + std::error::Error + Clone;
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, Self::ErrorType>;
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType>;
+    /// Check if every character of the text can be drawn with this font, the characters
+    /// without any glyph are silently skipped by `estimate_layout` and `draw`
+    fn check_glyphs(&self, _text: &str) -> Result<(), Self::ErrorType> {
+        Ok(())
+    }
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
         _pos: (i32, i32),
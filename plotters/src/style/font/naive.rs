@@ -29,12 +29,91 @@ impl FontData for FontDataInternal {
     /// the layout.
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
         let em = size / 1.24 / 1.24;
+        let width: f64 = text.chars().map(char_width).sum();
         Ok((
             (0, -em.round() as i32),
-            (
-                (em * 0.7 * text.len() as f64).round() as i32,
-                (em * 0.24).round() as i32,
-            ),
+            ((em * width).round() as i32, (em * 0.24).round() as i32),
         ))
     }
 }
+
+/// The zero width characters, which are the combining marks, the zero width spaces and joiners
+/// and the variation selectors
+const ZERO_WIDTH_RANGES: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x20D0, 0x20FF),
+    (0x302A, 0x302D),
+    (0x3099, 0x309A),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0xE0100, 0xE01EF),
+];
+
+/// The East Asian wide and fullwidth characters, which take a whole em
+const WIDE_RANGES: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xA960, 0xA97F),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x1F300, 0x1F64F),
+    (0x1F900, 0x1F9FF),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges.iter().any(|&(start, end)| start <= c && c <= end)
+}
+
+/// The estimated width of a character in em
+fn char_width(c: char) -> f64 {
+    if in_ranges(c, ZERO_WIDTH_RANGES) {
+        0.0
+    } else if in_ranges(c, WIDE_RANGES) {
+        1.0
+    } else {
+        0.7
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unicode_width_estimation() {
+        let font = FontDataInternal::new(FontFamily::SansSerif, FontStyle::Normal).unwrap();
+        let width = |text: &str| {
+            let ((x0, _), (x1, _)) = font.estimate_layout(100.0, text).unwrap();
+            x1 - x0
+        };
+
+        assert_eq!(width("ab"), width("\u{e9}\u{e8}"));
+        // The combining marks don't take any space
+        assert_eq!(width("e\u{301}"), width("e"));
+        // The CJK characters are wider than the latin ones
+        assert_eq!(width("\u{4e2d}\u{6587}"), 130);
+        assert!(width("\u{4e2d}\u{6587}") > width("ab"));
+    }
+}
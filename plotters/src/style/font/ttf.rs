@@ -26,14 +26,41 @@ use super::{FontData, FontFamily, FontStyle, LayoutBox};
 
 type FontResult<T> = Result<T, FontError>;
 
+/// The error of the TTF font backend.
+///
+/// When drawing on a backend, this error is boxed into
+/// [`DrawingErrorKind::FontError`](plotters_backend::DrawingErrorKind::FontError), use
+/// [`DrawingAreaErrorKind::font_error`](crate::drawing::DrawingAreaErrorKind::font_error) to get
+/// it back, so that the caller can react to it, for example, fall back to another font family.
 #[derive(Debug, Clone)]
 pub enum FontError {
+    /// Could not lock the font cache
     LockError,
+    /// The font family with the style, which are the 2 fields, is not found on the system
     NoSuchFont(String, String),
+    /// The font file is found but it's not a valid font file
+    FontParseError(Arc<FontLoadingError>),
+    /// The font file is found but can't be loaded, for example, because of an I/O error
     FontLoadError(Arc<FontLoadingError>),
+    /// The font has neither the glyph of the character nor the placeholder glyph, which is
+    /// only reported by [`FontDesc::check_glyphs`](crate::style::FontDesc::check_glyphs), since
+    /// such characters are skipped when the text is drawn
+    GlyphMissing(char),
+    /// The glyph is found but it can't be rasterized
     GlyphError(Arc<GlyphLoadingError>),
 }
 
+impl From<FontLoadingError> for FontError {
+    fn from(e: FontLoadingError) -> Self {
+        match e {
+            FontLoadingError::UnknownFormat | FontLoadingError::Parse => {
+                FontError::FontParseError(Arc::new(e))
+            }
+            e => FontError::FontLoadError(Arc::new(e)),
+        }
+    }
+}
+
 impl std::fmt::Display for FontError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match self {
@@ -41,7 +68,9 @@ impl std::fmt::Display for FontError {
             FontError::NoSuchFont(family, style) => {
                 write!(fmt, "No such font: {} {}", family, style)
             }
+            FontError::FontParseError(e) => write!(fmt, "Font parsing error {}", e),
             FontError::FontLoadError(e) => write!(fmt, "Font loading error {}", e),
+            FontError::GlyphMissing(c) => write!(fmt, "Missing glyph for {:?}", c),
             FontError::GlyphError(e) => write!(fmt, "Glyph error {}", e),
         }
     }
@@ -132,12 +161,9 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
     // Then we need to check if the data cache contains the font data
     let cache = DATA_CACHE.read().unwrap();
     if let Some(data) = cache.get(Borrow::<str>::borrow(&key)) {
-        return data.clone().map(|handle| {
-            handle
-                .load()
-                .map(FontExt::new)
-                .map_err(|e| FontError::FontLoadError(Arc::new(e)))
-        })?;
+        return data
+            .clone()
+            .map(|handle| handle.load().map(FontExt::new).map_err(FontError::from))?;
     }
     drop(cache);
 
@@ -163,10 +189,7 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
     if let Ok(handle) = FONT_SOURCE
         .with(|source| source.select_best_match(&[family, FamilyName::SansSerif], &properties))
     {
-        let font = handle
-            .load()
-            .map(FontExt::new)
-            .map_err(|e| FontError::FontLoadError(Arc::new(e)));
+        let font = handle.load().map(FontExt::new).map_err(FontError::from);
         let (should_cache, data) = match font.as_ref().map(|f| f.handle()) {
             Ok(None) => (false, Err(FontError::LockError)),
             Ok(Some(handle)) => (true, Ok(handle)),
@@ -216,15 +239,18 @@ impl FontData for FontDataInternal {
         let place_holder = font.glyph_for_char(PLACEHOLDER_CHAR);
 
         for c in text.chars() {
-            if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
-                if let Ok(size) = font.advance(glyph_id) {
-                    x_in_unit += size.x();
-                }
-                if let Some(pc) = prev {
-                    x_in_unit += font.query_kerning_table(pc, glyph_id);
-                }
-                prev = Some(glyph_id);
+            // The characters without any glyph are skipped, see `check_glyphs`
+            let glyph_id = match font.glyph_for_char(c).or(place_holder) {
+                Some(glyph_id) => glyph_id,
+                None => continue,
+            };
+            if let Ok(size) = font.advance(glyph_id) {
+                x_in_unit += size.x();
+            }
+            if let Some(pc) = prev {
+                x_in_unit += font.query_kerning_table(pc, glyph_id);
             }
+            prev = Some(glyph_id);
         }
 
         let x_pixels = x_in_unit * pixel_per_em as f32 / metrics.units_per_em as f32;
@@ -232,6 +258,17 @@ impl FontData for FontDataInternal {
         Ok(((0, 0), (x_pixels as i32, pixel_per_em as i32)))
     }
 
+    fn check_glyphs(&self, text: &str) -> Result<(), Self::ErrorType> {
+        let font = &self.0;
+        if font.glyph_for_char(PLACEHOLDER_CHAR).is_some() {
+            return Ok(());
+        }
+        match text.chars().find(|&c| font.glyph_for_char(c).is_none()) {
+            Some(c) => Err(FontError::GlyphMissing(c)),
+            None => Ok(()),
+        }
+    }
+
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
         (base_x, mut base_y): (i32, i32),
@@ -255,41 +292,43 @@ impl FontData for FontDataInternal {
         let mut result = Ok(());
 
         for c in text.chars() {
-            if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
-                if let Some(pc) = prev {
-                    x += font.query_kerning_table(pc, glyph_id) * em / metrics.units_per_em as f32;
-                }
+            let glyph_id = match font.glyph_for_char(c).or(place_holder) {
+                Some(glyph_id) => glyph_id,
+                None => continue,
+            };
+            if let Some(pc) = prev {
+                x += font.query_kerning_table(pc, glyph_id) * em / metrics.units_per_em as f32;
+            }
 
-                let mut canvas = Canvas::new(Vector2I::splat(canvas_size as i32), Format::A8);
-
-                result = font
-                    .rasterize_glyph(
-                        &mut canvas,
-                        glyph_id,
-                        em as f32,
-                        Transform2F::from_translation(Vector2F::new(0.0, em as f32)),
-                        HintingOptions::None,
-                        RasterizationOptions::GrayscaleAa,
-                    )
-                    .map_err(|e| FontError::GlyphError(Arc::new(e)))
-                    .and(result);
-
-                let base_x = x as i32;
-
-                for dy in 0..canvas_size {
-                    for dx in 0..canvas_size {
-                        let alpha = canvas.pixels[dy * canvas_size + dx] as f32 / 255.0;
-                        if let Err(e) = draw(base_x + dx as i32, base_y + dy as i32, alpha) {
-                            return Ok(Err(e));
-                        }
+            let mut canvas = Canvas::new(Vector2I::splat(canvas_size as i32), Format::A8);
+
+            result = font
+                .rasterize_glyph(
+                    &mut canvas,
+                    glyph_id,
+                    em as f32,
+                    Transform2F::from_translation(Vector2F::new(0.0, em as f32)),
+                    HintingOptions::None,
+                    RasterizationOptions::GrayscaleAa,
+                )
+                .map_err(|e| FontError::GlyphError(Arc::new(e)))
+                .and(result);
+
+            let base_x = x as i32;
+
+            for dy in 0..canvas_size {
+                for dx in 0..canvas_size {
+                    let alpha = canvas.pixels[dy * canvas_size + dx] as f32 / 255.0;
+                    if let Err(e) = draw(base_x + dx as i32, base_y + dy as i32, alpha) {
+                        return Ok(Err(e));
                     }
                 }
+            }
 
-                x += font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0) * em
-                    / metrics.units_per_em as f32;
+            x += font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0) * em
+                / metrics.units_per_em as f32;
 
-                prev = Some(glyph_id);
-            }
+            prev = Some(glyph_id);
         }
         result?;
         Ok(Ok(()))
@@ -316,4 +355,43 @@ mod test {
 
         return Ok(());
     }
+
+    #[test]
+    fn test_font_error_kind() {
+        use crate::drawing::DrawingAreaErrorKind;
+        use plotters_backend::DrawingErrorKind;
+
+        assert!(matches!(
+            FontError::from(FontLoadingError::Parse),
+            FontError::FontParseError(_)
+        ));
+        assert!(matches!(
+            FontError::from(FontLoadingError::NoFilesystem),
+            FontError::FontLoadError(_)
+        ));
+
+        let e: DrawingAreaErrorKind<std::io::Error> = DrawingAreaErrorKind::BackendError(
+            DrawingErrorKind::FontError(Box::new(FontError::GlyphMissing('x'))),
+        );
+        assert!(matches!(e.font_error(), Some(FontError::GlyphMissing('x'))));
+        assert!(DrawingAreaErrorKind::<std::io::Error>::LayoutError
+            .font_error()
+            .is_none());
+    }
+
+    #[test]
+    fn test_glyph_check() -> FontResult<()> {
+        let font = FontDataInternal::new(FontFamily::Serif, FontStyle::Normal)?;
+        font.check_glyphs("abc")?;
+        // A missing glyph never fails the layout, it's either drawn as the placeholder or skipped
+        let text = "a\u{10FFFD}b";
+        assert!(font.estimate_layout(12.0, text).is_ok());
+        if font.0.glyph_for_char(PLACEHOLDER_CHAR).is_none() {
+            assert!(matches!(
+                font.check_glyphs(text),
+                Err(FontError::GlyphMissing('\u{10FFFD}'))
+            ));
+        }
+        Ok(())
+    }
 }
@@ -5,13 +5,19 @@ mod color;
 pub mod colors;
 mod font;
 mod palette;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod shape;
 mod size;
 mod text;
 
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
-pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor};
+pub use color::{Color, HSLColor, HSVColor, PaletteColor, RGBAColor, RGBColor};
+pub use colors::colormaps::{
+    BrBG, ColorMap, ColorNorm, Coolwarm, DivergingNorm, Inferno, LinearNorm, LogNorm, Magma, RdBu,
+    Viridis,
+};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
 #[cfg(feature = "full_palette")]
@@ -23,7 +29,9 @@ pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
 };
 
-pub use shape::ShapeStyle;
+pub use plotters_backend::FillPattern;
+pub(crate) use shape::{with_default_stroke_width, with_stroke_width_parent};
+pub use shape::{DashPattern, PatternFill, ShapeStyle, StrokeWidthKind};
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
-pub use text::{IntoTextStyle, TextStyle};
+pub use text::{IntoTextStyle, TextStyle, TextStyleSpec};
@@ -1,4 +1,4 @@
-use super::color::PaletteColor;
+use super::color::{Color, HSLColor, PaletteColor, RGBAColor};
 
 /// Represents a color palette
 pub trait Palette {
@@ -64,3 +64,109 @@ impl Palette for Palette100 {
     const COLORS: &'static [(u8, u8, u8)] =
         &[(255, 225, 25), (0, 130, 200), (128, 128, 128), (0, 0, 0)];
 }
+
+/// The named colors used by [`DistinctColors`] when only a few colors are requested
+const NAMED_DISTINCT_COLORS: [RGBAColor; 6] = [
+    RGBAColor(255, 0, 0, 1.0),
+    RGBAColor(0, 0, 255, 1.0),
+    RGBAColor(0, 255, 0, 1.0),
+    RGBAColor(255, 0, 255, 1.0),
+    RGBAColor(0, 255, 255, 1.0),
+    RGBAColor(255, 255, 0, 1.0),
+];
+
+/**
+An iterator over `n` colors which are easy to tell apart, for drawing a variable number of
+series.
+
+Up to 6 colors, the named colors `RED`, `BLUE`, `GREEN`, `MAGENTA`, `CYAN` and `YELLOW` are used
+in this order. For more colors, the hues are evenly spaced on the color wheel and the lightness
+alternates between neighbouring hues. The colors only depend on `n`.
+
+```
+use plotters::prelude::*;
+let data = vec![vec![(0, 1), (1, 2)], vec![(0, 2), (1, 0)], vec![(0, 0), (1, 1)]];
+let drawing_area = SVGBackend::new("distinct_colors.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0..1, 0..2)
+    .unwrap();
+for (series, color) in data.iter().zip(DistinctColors::new(data.len())) {
+    chart
+        .draw_series(LineSeries::new(series.iter().copied(), color))
+        .unwrap();
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct DistinctColors {
+    idx: usize,
+    n: usize,
+}
+
+impl DistinctColors {
+    /// Create an iterator over `n` distinct colors
+    pub fn new(n: usize) -> Self {
+        Self { idx: 0, n }
+    }
+
+    /// Get the `idx`-th color of the `n` distinct colors
+    fn color(idx: usize, n: usize) -> RGBAColor {
+        if n <= NAMED_DISTINCT_COLORS.len() {
+            return NAMED_DISTINCT_COLORS[idx];
+        }
+        let lightness = if idx % 2 == 0 { 0.45 } else { 0.65 };
+        HSLColor(idx as f64 / n as f64, 0.75, lightness).to_rgba()
+    }
+}
+
+impl Iterator for DistinctColors {
+    type Item = RGBAColor;
+    fn next(&mut self) -> Option<RGBAColor> {
+        if self.idx >= self.n {
+            return None;
+        }
+        self.idx += 1;
+        Some(Self::color(self.idx - 1, self.n))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.n - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for DistinctColors {}
+
+/// Returns `n` colors which are easy to tell apart, see [`DistinctColors`] for details
+pub fn distinct_colors(n: usize) -> Vec<RGBAColor> {
+    DistinctColors::new(n).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{RGBColor, BLUE, GREEN, RED};
+
+    #[test]
+    fn test_distinct_colors() {
+        assert_eq!(
+            distinct_colors(3),
+            vec![RED.to_rgba(), BLUE.to_rgba(), GREEN.to_rgba()]
+        );
+        assert!(distinct_colors(0).is_empty());
+
+        let colors = distinct_colors(12);
+        assert_eq!(colors, distinct_colors(12));
+        assert_eq!(DistinctColors::new(12).len(), 12);
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        // Neighbouring hues have different lightness
+        assert_ne!(
+            HSLColor::from(RGBColor(colors[0].0, colors[0].1, colors[0].2)).2,
+            HSLColor::from(RGBColor(colors[1].0, colors[1].1, colors[1].2)).2
+        );
+    }
+}
@@ -0,0 +1,389 @@
+//! The serialization support for colors and styles, this is enabled by the `serde` feature.
+//!
+//! Colors are represented by the hex string, for example `"#ff0000"` for the opaque red.
+//! A translucent color is represented as `"#rrggbbaa"` when the alpha channel is exactly
+//! representable with 8 bits, otherwise it falls back to `"rgba(r, g, b, a)"` so that the
+//! alpha value round-trips without loss.
+use super::color::{RGBAColor, RGBColor};
+use super::font::{FontDesc, FontFamily, FontStyle, FontTransform};
+use super::shape::DashPattern;
+
+use once_cell::sync::Lazy;
+use serde::de::{Deserializer, Error};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+fn parse_hex(value: &str) -> Option<Vec<u8>> {
+    let digits = value.strip_prefix('#')?;
+    if digits.len() % 2 != 0 || !digits.is_ascii() {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&digits[idx..idx + 2], 16).ok())
+        .collect()
+}
+
+fn parse_rgba(value: &str) -> Option<RGBAColor> {
+    let body = value.trim().strip_prefix("rgba(")?.strip_suffix(')')?;
+    let mut parts = body.split(',').map(str::trim);
+    let r = parts.next()?.parse().ok()?;
+    let g = parts.next()?.parse().ok()?;
+    let b = parts.next()?.parse().ok()?;
+    let a = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(RGBAColor(r, g, b, a))
+}
+
+fn parse_color(value: &str) -> Option<RGBAColor> {
+    if value.trim_start().starts_with("rgba(") {
+        return parse_rgba(value);
+    }
+    match parse_hex(value.trim())?[..] {
+        [r, g, b] => Some(RGBAColor(r, g, b, 1.0)),
+        [r, g, b, a] => Some(RGBAColor(r, g, b, f64::from(a) / 255.0)),
+        _ => None,
+    }
+}
+
+impl Serialize for RGBColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2))
+    }
+}
+
+impl<'de> Deserialize<'de> for RGBColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match parse_hex(value.trim()).as_deref() {
+            Some(&[r, g, b]) => Ok(RGBColor(r, g, b)),
+            _ => Err(D::Error::custom(format!(
+                "invalid RGB color {:?}, expecting \"#rrggbb\"",
+                value
+            ))),
+        }
+    }
+}
+
+impl Serialize for RGBAColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let RGBAColor(r, g, b, a) = *self;
+        let alpha_byte = (a * 255.0).round();
+        let text = if a == 1.0 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else if (0.0..=255.0).contains(&alpha_byte) && alpha_byte / 255.0 == a {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, alpha_byte as u8)
+        } else {
+            format!("rgba({}, {}, {}, {})", r, g, b, a)
+        };
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for RGBAColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_color(&value).ok_or_else(|| {
+            D::Error::custom(format!(
+                "invalid color {:?}, expecting \"#rrggbb\", \"#rrggbbaa\" or \"rgba(r, g, b, a)\"",
+                value
+            ))
+        })
+    }
+}
+
+fn transform_name(trans: &FontTransform) -> String {
+    match trans {
+        FontTransform::None => "none".to_string(),
+        FontTransform::Rotate90 => "rotate90".to_string(),
+        FontTransform::Rotate180 => "rotate180".to_string(),
+        FontTransform::Rotate270 => "rotate270".to_string(),
+        FontTransform::RotateAngle(angle) => format!("rotate({})", angle),
+    }
+}
+
+fn parse_transform(name: &str) -> Option<FontTransform> {
+    match name.to_lowercase().as_str() {
+        "none" => Some(FontTransform::None),
+        "rotate90" => Some(FontTransform::Rotate90),
+        "rotate180" => Some(FontTransform::Rotate180),
+        "rotate270" => Some(FontTransform::Rotate270),
+        name => {
+            let angle = name.strip_prefix("rotate(")?.strip_suffix(')')?;
+            angle.trim().parse().ok().map(FontTransform::RotateAngle)
+        }
+    }
+}
+
+/// The serialized form of a font description
+#[derive(Serialize, Deserialize)]
+struct FontDescRepr<'a> {
+    family: Cow<'a, str>,
+    size: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    style: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transform: Option<String>,
+}
+
+impl<'a> Serialize for FontDesc<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let style = self.get_style();
+        let transform = self.get_transform();
+        FontDescRepr {
+            family: Cow::Borrowed(self.get_name()),
+            size: self.get_size(),
+            style: match style {
+                FontStyle::Normal => None,
+                _ => Some(style.as_str().to_string()),
+            },
+            transform: match transform {
+                FontTransform::None => None,
+                _ => Some(transform_name(&transform)),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+/// The font family names deserialized so far
+static FAMILY_NAMES: Lazy<Mutex<HashSet<&'static str>>> = Lazy::new(Default::default);
+
+/// Get a font family name which lives as long as the program, each distinct name is only
+/// allocated once
+fn intern_family_name(name: String) -> &'static str {
+    let mut names = FAMILY_NAMES.lock().unwrap();
+    if let Some(interned) = names.get(name.as_str()) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
+/// A font description doesn't own its family name, thus a specific family name is interned,
+/// see [`intern_family_name`], so that it can be deserialized from any source, for example
+/// `serde_json::from_reader` or a config file, not only from a borrowed string.
+impl<'de, 'a> Deserialize<'de> for FontDesc<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = FontDescRepr::deserialize(deserializer)?;
+        let style = repr
+            .style
+            .as_deref()
+            .map_or(FontStyle::Normal, FontStyle::from);
+        let family = match FontFamily::from(repr.family.as_ref()) {
+            FontFamily::Name(_) => FontFamily::Name(intern_family_name(repr.family.into_owned())),
+            FontFamily::Serif => FontFamily::Serif,
+            FontFamily::SansSerif => FontFamily::SansSerif,
+            FontFamily::Monospace => FontFamily::Monospace,
+        };
+        let transform = match repr.transform {
+            Some(name) => parse_transform(&name)
+                .ok_or_else(|| D::Error::custom(format!("invalid font transform {:?}", name)))?,
+            None => FontTransform::None,
+        };
+        Ok(FontDesc::new(family, repr.size, style).transform(transform))
+    }
+}
+
+/// The serialized form of a dash pattern
+#[derive(Serialize, Deserialize)]
+struct DashPatternRepr {
+    segments: Vec<u32>,
+    #[serde(default)]
+    offset: u32,
+}
+
+impl Serialize for DashPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DashPatternRepr {
+            segments: self.segments().to_vec(),
+            offset: self.offset(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// A dash pattern is built with [`DashPattern::new`], thus the extra segment lengths are dropped
+/// the same way as in the code
+impl<'de> Deserialize<'de> for DashPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = DashPatternRepr::deserialize(deserializer)?;
+        Ok(DashPattern::new(&repr.segments, repr.offset))
+    }
+}
+
+/// The fill pattern of a shape style is represented by its name, for example
+/// `"diagonal_lines"`
+pub(super) mod fill_pattern {
+    use plotters_backend::FillPattern;
+    use serde::de::{Deserializer, Error};
+    use serde::ser::Serializer;
+    use serde::Deserialize;
+
+    const NAMES: [(FillPattern, &str); 5] = [
+        (FillPattern::Solid, "solid"),
+        (FillPattern::HorizontalLines, "horizontal_lines"),
+        (FillPattern::VerticalLines, "vertical_lines"),
+        (FillPattern::DiagonalLines, "diagonal_lines"),
+        (FillPattern::CrossHatch, "cross_hatch"),
+    ];
+
+    pub fn serialize<S: Serializer>(
+        pattern: &FillPattern,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let name = NAMES
+            .iter()
+            .find(|(p, _)| p == pattern)
+            .map_or("solid", |(_, n)| n);
+        serializer.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<FillPattern, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(&value))
+            .map(|(p, _)| *p)
+            .ok_or_else(|| D::Error::custom(format!("invalid fill pattern {:?}", value)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::style::DashPattern;
+
+    #[test]
+    fn test_color_serialization() {
+        assert_eq!(serde_json::to_string(&RED).unwrap(), "\"#ff0000\"");
+        assert_eq!(
+            serde_json::from_str::<RGBColor>("\"#0a0B0c\"").unwrap(),
+            RGBColor(10, 11, 12)
+        );
+        assert!(serde_json::from_str::<RGBColor>("\"#0a0b\"").is_err());
+        assert!(serde_json::from_str::<RGBColor>("\"red\"").is_err());
+
+        for color in [
+            RED.to_rgba(),
+            RGBAColor(1, 2, 3, 0.0),
+            RGBAColor(1, 2, 3, 128.0 / 255.0),
+            BLUE.mix(0.3),
+            RGBAColor(255, 255, 255, 0.123456789),
+        ] {
+            let text = serde_json::to_string(&color).unwrap();
+            assert_eq!(serde_json::from_str::<RGBAColor>(&text).unwrap(), color);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&RGBAColor(1, 2, 3, 128.0 / 255.0)).unwrap(),
+            "\"#01020380\""
+        );
+        assert_eq!(
+            serde_json::from_str::<RGBAColor>("\"#00ff00\"").unwrap(),
+            GREEN.to_rgba()
+        );
+    }
+
+    #[test]
+    fn test_shape_style_serialization() {
+        let style = BLUE.mix(0.6).stroke_width(3).filled();
+        let text = serde_json::to_string(&style).unwrap();
+        let restored: ShapeStyle = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.color, style.color);
+        assert_eq!(restored.filled, style.filled);
+        assert_eq!(restored.stroke_width, 3);
+
+        let restored: ShapeStyle =
+            serde_json::from_str(r##"{"color":"#ff000080","filled":false,"stroke_width":2}"##)
+                .unwrap();
+        assert_eq!(restored.color, RGBAColor(255, 0, 0, 128.0 / 255.0));
+        assert_eq!(restored.stroke_width, 2);
+        assert_eq!(restored.pattern, None);
+
+        let style = RED
+            .filled()
+            .pattern(FillPattern::CrossHatch)
+            .pattern_spacing(5);
+        let text = serde_json::to_string(&style).unwrap();
+        assert!(text.contains(r#""pattern":"cross_hatch""#));
+        let restored: ShapeStyle = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.pattern, style.pattern);
+
+        let style = RED.stroke_width(2).dashed(&[4, 2]).dash_offset(1);
+        let text = serde_json::to_string(&style).unwrap();
+        assert!(text.contains(r#""dash":{"segments":[4,2],"offset":1}"#));
+        let restored: ShapeStyle = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.dash, style.dash);
+
+        // The internal length can't be injected, and the extra lengths are dropped
+        let dash: DashPattern =
+            serde_json::from_str(r#"{"segments":[1,2,3,4,5,6,7,8,9,10],"len":20}"#).unwrap();
+        assert_eq!(dash.segments(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(dash.offset(), 0);
+    }
+
+    #[test]
+    fn test_font_desc_serialization() {
+        let font = ("serif", 20).into_font().style(FontStyle::Italic);
+        let text = serde_json::to_string(&font).unwrap();
+        assert_eq!(text, r#"{"family":"serif","size":20.0,"style":"italic"}"#);
+
+        let font: FontDesc = serde_json::from_str(&text).unwrap();
+        assert_eq!(font.get_name(), "serif");
+        assert_eq!(font.get_size(), 20.0);
+        assert!(matches!(font.get_style(), FontStyle::Italic));
+
+        let font: FontDesc =
+            serde_json::from_str(r#"{"family":"Arial","size":12,"transform":"rotate90"}"#).unwrap();
+        assert_eq!(font.get_name(), "Arial");
+        assert!(matches!(font.get_transform(), FontTransform::Rotate90));
+
+        let font = ("serif", 10)
+            .into_font()
+            .transform(FontTransform::RotateAngle(-45.0));
+        let text = serde_json::to_string(&font).unwrap();
+        assert_eq!(
+            text,
+            r#"{"family":"serif","size":10.0,"transform":"rotate(-45)"}"#
+        );
+        let font: FontDesc = serde_json::from_str(&text).unwrap();
+        assert!(matches!(font.get_transform(), FontTransform::RotateAngle(a) if a == -45.0));
+        assert!(serde_json::from_str::<FontDesc>(
+            r#"{"family":"serif","size":10,"transform":"rotate(x)"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_deserialize_owned_source() {
+        let style = RED.mix(0.5).filled().pattern(FillPattern::DiagonalLines);
+        let value = serde_json::to_value(style).unwrap();
+        let restored: ShapeStyle = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.color, style.color);
+        assert_eq!(restored.pattern, style.pattern);
+
+        let color: RGBColor = serde_json::from_reader(&b"\"#0a0b0c\""[..]).unwrap();
+        assert_eq!(color, RGBColor(10, 11, 12));
+
+        let text = r#"{"family":"Fira Sans","size":12.0,"style":"bold"}"#;
+        let font: FontDesc = serde_json::from_reader(text.as_bytes()).unwrap();
+        assert_eq!(font.get_name(), "Fira Sans");
+        assert!(matches!(font.get_style(), FontStyle::Bold));
+        let font: FontDesc = serde_json::from_value(serde_json::to_value(font).unwrap()).unwrap();
+        assert_eq!(font.get_name(), "Fira Sans");
+
+        // The escaped strings can't be borrowed
+        let font: FontDesc =
+            serde_json::from_str(r#"{"family":"Fira\u0020Sans","size":12}"#).unwrap();
+        assert_eq!(font.get_name(), "Fira Sans");
+    }
+}
@@ -1,8 +1,11 @@
 use super::color::{Color, RGBAColor};
-use plotters_backend::{BackendColor, BackendStyle};
+use super::size::{HasDimension, RelativeSizeWithBound, SizeDesc};
+use plotters_backend::{BackendColor, BackendStyle, FillPattern};
+use std::cell::Cell;
 
 /// Style for any shape
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeStyle {
     /// Specification of the color.
     pub color: RGBAColor,
@@ -10,6 +13,209 @@ pub struct ShapeStyle {
     pub filled: bool,
     /// Stroke width.
     pub stroke_width: u32,
+    /// How the stroke width is decided when the style is drawn, see [`StrokeWidthKind`].
+    #[cfg_attr(feature = "serde", serde(default = "StrokeWidthKind::explicit"))]
+    pub stroke_width_kind: StrokeWidthKind,
+    /// The dash pattern of the stroke, `None` for a solid stroke.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dash: Option<DashPattern>,
+    /// The pattern filling the shape, `None` for a solid fill.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pattern: Option<PatternFill>,
+}
+
+/**
+How the stroke width of a [`ShapeStyle`] is decided when the style is drawn.
+
+A style made from a color, for example `&RED` or `RED.filled()`, doesn't set its stroke width.
+Such a style is drawn with the default stroke width of the chart, see
+[`ChartBuilder::default_stroke_width()`](crate::chart::ChartBuilder::default_stroke_width),
+when it's used by a series or a legend of the chart, and with `stroke_width` otherwise.
+
+A relative stroke width, see [`ShapeStyle::relative_stroke_width()`], is resolved against the
+drawing area the style is drawn on.
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum StrokeWidthKind {
+    /// The stroke width isn't set, the default stroke width of the chart is used if any
+    Unset,
+    /// The stroke width is `stroke_width` pixels
+    Pixels,
+    /// The stroke width is relative to the drawing area the style is drawn on, `stroke_width` is
+    /// only used when the style is drawn on a backend directly
+    Relative(RelativeSizeWithBound),
+}
+
+impl StrokeWidthKind {
+    /// The kind of a stroke width given in the serialized style, which is always explicit
+    #[cfg(feature = "serde")]
+    fn explicit() -> Self {
+        StrokeWidthKind::Pixels
+    }
+}
+
+/// What the stroke widths which aren't given in pixels are resolved against
+#[derive(Clone, Copy, Default)]
+struct StrokeWidthContext {
+    /// The default stroke width of the chart whose series are being drawn
+    default: Option<u32>,
+    /// The drawing area being drawn on
+    parent: Option<StrokeWidthParent>,
+}
+
+/// The dimension and the size scale of a drawing area
+#[derive(Clone, Copy)]
+struct StrokeWidthParent((u32, u32), f64);
+
+impl HasDimension for StrokeWidthParent {
+    fn dim(&self) -> (u32, u32) {
+        self.0
+    }
+
+    fn size_scale(&self) -> f64 {
+        self.1
+    }
+}
+
+thread_local! {
+    /// The stroke width context of the drawing going on this thread
+    static STROKE_WIDTH_CONTEXT: Cell<StrokeWidthContext> = Cell::new(StrokeWidthContext::default());
+}
+
+/// Restores the stroke width context of the enclosing drawing when it's dropped
+struct StrokeWidthContextGuard(StrokeWidthContext);
+
+impl Drop for StrokeWidthContextGuard {
+    fn drop(&mut self) {
+        STROKE_WIDTH_CONTEXT.with(|context| context.set(self.0));
+    }
+}
+
+fn with_stroke_width_context<R>(
+    update: impl FnOnce(&mut StrokeWidthContext),
+    draw: impl FnOnce() -> R,
+) -> R {
+    let previous = STROKE_WIDTH_CONTEXT.with(Cell::get);
+    let _guard = StrokeWidthContextGuard(previous);
+    let mut context = previous;
+    update(&mut context);
+    STROKE_WIDTH_CONTEXT.with(|c| c.set(context));
+    draw()
+}
+
+/// Run the drawing code with the default stroke width, which is used by the styles with an
+/// unset stroke width, see [`StrokeWidthKind::Unset`]
+pub(crate) fn with_default_stroke_width<R>(width: u32, draw: impl FnOnce() -> R) -> R {
+    with_stroke_width_context(|context| context.default = Some(width), draw)
+}
+
+/// Run the drawing code on a drawing area, which the relative stroke widths are resolved
+/// against, see [`StrokeWidthKind::Relative`]
+pub(crate) fn with_stroke_width_parent<P: HasDimension, R>(
+    parent: &P,
+    draw: impl FnOnce() -> R,
+) -> R {
+    let parent = StrokeWidthParent(parent.dim(), parent.size_scale());
+    with_stroke_width_context(|context| context.parent = Some(parent), draw)
+}
+
+/// The maximum number of the segment lengths in a [`DashPattern`]
+const MAX_DASH_SEGMENTS: usize = 8;
+
+/**
+The dash pattern of a stroke, which is the lengths of the on and off segments in pixels,
+alternately, and how far into the pattern the stroke starts.
+
+See [`ShapeStyle::dashed()`] for more information and examples.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DashPattern {
+    segments: [u32; MAX_DASH_SEGMENTS],
+    len: usize,
+    offset: u32,
+}
+
+impl DashPattern {
+    /**
+    Creates a dash pattern.
+
+    - `segments`: The lengths of the on and off segments in pixels, alternately. At most 8
+      lengths are taken, and a pattern with an odd number of lengths is repeated twice, so
+      that the on and off segments alternate
+    - `offset`: How far into the pattern the stroke starts, in pixels
+    */
+    pub fn new(segments: &[u32], offset: u32) -> Self {
+        let len = segments.len().min(MAX_DASH_SEGMENTS);
+        let mut ret = Self {
+            segments: [0; MAX_DASH_SEGMENTS],
+            len,
+            offset,
+        };
+        ret.segments[..len].copy_from_slice(&segments[..len]);
+        ret
+    }
+
+    /// Get the lengths of the on and off segments
+    pub fn segments(&self) -> &[u32] {
+        &self.segments[..self.len]
+    }
+
+    /// Get the offset of the pattern
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+}
+
+/// The default distance between the lines of a [`PatternFill`] in pixels
+const DEFAULT_PATTERN_SPACING: u32 = 8;
+
+/**
+The pattern filling a shape, which is the kind of the lines, and the distance between them and
+their width in pixels.
+
+See [`ShapeStyle::pattern()`] for more information and examples.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternFill {
+    #[cfg_attr(feature = "serde", serde(with = "super::serde_impl::fill_pattern"))]
+    pattern: FillPattern,
+    spacing: u32,
+    line_width: u32,
+}
+
+impl PatternFill {
+    /**
+    Creates a fill pattern.
+
+    - `pattern`: The kind of the lines
+    - `spacing`: The distance between the lines in pixels
+    - `line_width`: The width of the lines in pixels
+    */
+    pub fn new(pattern: FillPattern, spacing: u32, line_width: u32) -> Self {
+        Self {
+            pattern,
+            spacing,
+            line_width,
+        }
+    }
+
+    /// Get the kind of the lines
+    pub fn pattern(&self) -> FillPattern {
+        self.pattern
+    }
+
+    /// Get the distance between the lines
+    pub fn spacing(&self) -> u32 {
+        self.spacing
+    }
+
+    /// Get the width of the lines
+    pub fn line_width(&self) -> u32 {
+        self.line_width
+    }
 }
 
 impl ShapeStyle {
@@ -24,6 +230,9 @@ impl ShapeStyle {
         color: BLUE.mix(0.6),
         filled: false,
         stroke_width: 2,
+        stroke_width_kind: StrokeWidthKind::Pixels,
+        dash: None,
+        pattern: None,
     };
     let filled_style = original_style.filled();
     let drawing_area = SVGBackend::new("shape_style_filled.svg", (400, 200)).into_drawing_area();
@@ -41,6 +250,9 @@ impl ShapeStyle {
             color: self.color.to_rgba(),
             filled: true,
             stroke_width: self.stroke_width,
+            stroke_width_kind: self.stroke_width_kind,
+            dash: self.dash,
+            pattern: self.pattern,
         }
     }
 
@@ -55,6 +267,9 @@ impl ShapeStyle {
         color: BLUE.mix(0.6),
         filled: false,
         stroke_width: 2,
+        stroke_width_kind: StrokeWidthKind::Pixels,
+        dash: None,
+        pattern: None,
     };
     let new_style = original_style.stroke_width(5);
     let drawing_area = SVGBackend::new("shape_style_stroke_width.svg", (400, 200)).into_drawing_area();
@@ -72,6 +287,139 @@ impl ShapeStyle {
             color: self.color.to_rgba(),
             filled: self.filled,
             stroke_width: width,
+            stroke_width_kind: StrokeWidthKind::Pixels,
+            dash: self.dash,
+            pattern: self.pattern,
+        }
+    }
+
+    /**
+    Returns a new style with the same color and stroke width, and a dashed stroke.
+
+    - `pattern`: The lengths of the on and off segments in pixels, alternately, see
+      [`DashPattern::new()`]. An empty pattern makes the stroke solid
+
+    The pattern applies to the lines and the paths. It carries on across the vertices of a path,
+    rather than restarting at each segment.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("shape_style_dashed.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let style = ShapeStyle::from(&BLUE).stroke_width(2).dashed(&[10, 5]);
+    drawing_area.draw(&PathElement::new(vec![(20, 180), (200, 20), (380, 180)], style)).unwrap();
+    ```
+    */
+    pub fn dashed(&self, pattern: &[u32]) -> Self {
+        let offset = self.dash.map_or(0, |dash| dash.offset());
+        Self {
+            dash: Some(DashPattern::new(pattern, offset)).filter(|_| !pattern.is_empty()),
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the dash pattern starting at the given offset, which has no
+    effect on a solid stroke.
+
+    - `offset`: How far into the dash pattern the stroke starts, in pixels
+
+    See [`ShapeStyle::dashed()`] for more information and examples.
+    */
+    pub fn dash_offset(&self, offset: u32) -> Self {
+        Self {
+            dash: self
+                .dash
+                .map(|dash| DashPattern::new(dash.segments(), offset)),
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style which fills the shapes with the lines of a pattern, rather than with
+    the solid color. The lines are 1 pixel wide and 8 pixels apart, unless they are set by
+    [`ShapeStyle::pattern_spacing()`] and [`ShapeStyle::pattern_line_width()`].
+
+    - `pattern`: The kind of the lines, `FillPattern::Solid` makes the fill solid
+
+    The pattern applies to the filled rectangles and polygons. It is useful to tell the series
+    apart when the chart is printed in grayscale.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("shape_style_pattern.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let style = BLUE.filled().pattern(FillPattern::DiagonalLines).pattern_spacing(6);
+    drawing_area.draw(&Rectangle::new([(20, 20), (180, 180)], style)).unwrap();
+    drawing_area.draw(&Rectangle::new([(220, 20), (380, 180)], RED.filled().pattern(FillPattern::CrossHatch))).unwrap();
+    ```
+    */
+    pub fn pattern(&self, pattern: FillPattern) -> Self {
+        let (spacing, line_width) = self.pattern.map_or((DEFAULT_PATTERN_SPACING, 1), |p| {
+            (p.spacing(), p.line_width())
+        });
+        Self {
+            pattern: Some(PatternFill::new(pattern, spacing, line_width))
+                .filter(|_| pattern != FillPattern::Solid),
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the lines of the fill pattern the given distance apart, which has
+    no effect on a solid fill.
+
+    - `spacing`: The distance between the lines in pixels
+
+    See [`ShapeStyle::pattern()`] for more information and examples.
+    */
+    pub fn pattern_spacing(&self, spacing: u32) -> Self {
+        Self {
+            pattern: self
+                .pattern
+                .map(|p| PatternFill::new(p.pattern(), spacing, p.line_width())),
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the lines of the fill pattern of the given width, which has no
+    effect on a solid fill.
+
+    - `width`: The width of the lines in pixels
+
+    See [`ShapeStyle::pattern()`] for more information and examples.
+    */
+    pub fn pattern_line_width(&self, width: u32) -> Self {
+        Self {
+            pattern: self
+                .pattern
+                .map(|p| PatternFill::new(p.pattern(), p.spacing(), width)),
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the same color and a stroke width relative to the drawing area,
+    for example `2.percent()` of the smaller dimension of the area.
+
+    The width is resolved against the drawing area the style is drawn on, at the time it's
+    drawn, so the same style scales with the drawing area when a chart is rendered at different
+    sizes.
+
+    ```
+    use plotters::prelude::*;
+    let style = ShapeStyle::from(&BLUE).relative_stroke_width(2.percent().min(1));
+    ```
+    */
+    pub fn relative_stroke_width<S: Into<RelativeSizeWithBound>>(&self, width: S) -> Self {
+        Self {
+            stroke_width_kind: StrokeWidthKind::Relative(width.into()),
+            ..*self
         }
     }
 }
@@ -82,6 +430,9 @@ impl<T: Color> From<T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            stroke_width_kind: StrokeWidthKind::Unset,
+            dash: None,
+            pattern: None,
         }
     }
 }
@@ -91,8 +442,69 @@ impl BackendStyle for ShapeStyle {
     fn color(&self) -> BackendColor {
         self.color.to_backend_color()
     }
-    /// Returns the stroke width.
+    /// Returns the stroke width, which is the default stroke width of the chart for an unset
+    /// stroke width drawn by a series of the chart, and is resolved against the drawing area
+    /// for a relative stroke width.
     fn stroke_width(&self) -> u32 {
-        self.stroke_width
+        let context = STROKE_WIDTH_CONTEXT.with(Cell::get);
+        match self.stroke_width_kind {
+            StrokeWidthKind::Unset => context.default.unwrap_or(self.stroke_width),
+            StrokeWidthKind::Pixels => self.stroke_width,
+            StrokeWidthKind::Relative(width) => {
+                context.parent.map_or(self.stroke_width, |parent| {
+                    width.in_pixels(&parent).max(0) as u32
+                })
+            }
+        }
+    }
+    /// Returns the dash pattern.
+    fn dash_pattern(&self) -> Option<&[u32]> {
+        self.dash.as_ref().map(|dash| dash.segments())
+    }
+    /// Returns the offset of the dash pattern.
+    fn dash_offset(&self) -> u32 {
+        self.dash.map_or(0, |dash| dash.offset())
+    }
+    /// Returns the fill pattern.
+    fn fill_pattern(&self) -> FillPattern {
+        self.pattern.map_or(FillPattern::Solid, |p| p.pattern())
+    }
+    /// Returns the distance between the lines of the fill pattern.
+    fn fill_pattern_spacing(&self) -> u32 {
+        self.pattern
+            .map_or(DEFAULT_PATTERN_SPACING, |p| p.spacing())
+    }
+    /// Returns the width of the lines of the fill pattern.
+    fn fill_pattern_line_width(&self) -> u32 {
+        self.pattern.map_or(1, |p| p.line_width())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_relative_stroke_width() {
+        let style = ShapeStyle::from(&BLUE).relative_stroke_width(2.percent());
+
+        for (size, width) in [((400, 200), 4), ((1000, 800), 16)] {
+            let drawing_area = create_mocked_drawing_area(size.0, size.1, |m| {
+                m.check_draw_circle(move |_, s, _, _, _| assert_eq!(s, width));
+                m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+            });
+            drawing_area
+                .draw(&Circle::new((100, 100), 50, style))
+                .expect("Drawing error");
+        }
+
+        // The width is resolved against the sub-area the style is drawn on
+        let drawing_area = create_mocked_drawing_area(400, 200, |m| {
+            m.check_draw_circle(|_, s, _, _, _| assert_eq!(s, 2));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+        let (left, _) = drawing_area.scaled(0.5).split_horizontally(200);
+        left.draw(&Circle::new((50, 50), 20, style))
+            .expect("Drawing error");
     }
 }
@@ -9,12 +9,21 @@ use plotters_backend::DrawingBackend;
 pub trait HasDimension {
     /// Get the dimensional data for this object
     fn dim(&self) -> (u32, u32);
+
+    /// Get the factor the absolute sizes are multiplied by, see [`DrawingArea::scaled`]
+    fn size_scale(&self) -> f64 {
+        1.0
+    }
 }
 
 impl<D: DrawingBackend, C: CoordTranslate> HasDimension for DrawingArea<D, C> {
     fn dim(&self) -> (u32, u32) {
         self.dim_in_pixel()
     }
+
+    fn size_scale(&self) -> f64 {
+        DrawingArea::size_scale(self)
+    }
 }
 
 impl HasDimension for (u32, u32) {
@@ -34,26 +43,26 @@ pub trait SizeDesc {
 }
 
 impl SizeDesc for i32 {
-    fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
-        *self
+    fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
+        (f64::from(*self) * parent.size_scale()).round() as i32
     }
 }
 
 impl SizeDesc for u32 {
-    fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
-        *self as i32
+    fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
+        (f64::from(*self) * parent.size_scale()).round() as i32
     }
 }
 
 impl SizeDesc for f32 {
-    fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
-        *self as i32
+    fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
+        (f64::from(*self) * parent.size_scale()).round() as i32
     }
 }
 
 impl SizeDesc for f64 {
-    fn in_pixels<D: HasDimension>(&self, _parent: &D) -> i32 {
-        *self as i32
+    fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
+        (*self * parent.size_scale()).round() as i32
     }
 }
 
@@ -61,6 +70,8 @@ impl SizeDesc for f64 {
 ///     1. portion of height
 ///     2. portion of width
 ///     3. portion of the minimal of height and weight
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelativeSize {
     /// Percentage height
     Height(f64),
@@ -127,6 +138,8 @@ pub trait AsRelative: Into<f64> {
 impl<T: Into<f64>> AsRelative for T {}
 
 /// The struct describes a relative size with upper bound and lower bound
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelativeSizeWithBound {
     size: RelativeSize,
     min: Option<i32>,
@@ -153,6 +166,16 @@ impl RelativeSizeWithBound {
     }
 }
 
+impl From<RelativeSize> for RelativeSizeWithBound {
+    fn from(size: RelativeSize) -> Self {
+        RelativeSizeWithBound {
+            size,
+            min: None,
+            max: None,
+        }
+    }
+}
+
 impl SizeDesc for RelativeSizeWithBound {
     fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
         let size = self.size.in_pixels(parent);
@@ -183,4 +206,24 @@ mod test {
         assert_eq!(size.in_pixels(&(100, 200)), 10);
         assert_eq!(size.in_pixels(&(400, 200)), 20);
     }
+
+    #[test]
+    fn test_scaled_size() {
+        let area = crate::create_mocked_drawing_area(100, 200, |_| {});
+        assert_eq!(20.in_pixels(&area), 20);
+
+        let (left, _) = area.scaled(0.5).split_horizontally(50);
+        assert_eq!(20.in_pixels(&left), 10);
+        assert_eq!(15u32.in_pixels(&left), 8);
+        assert_eq!((10).percent_height().in_pixels(&left), 20);
+
+        let nested = left.scaled(0.5);
+        assert_eq!(nested.size_scale(), 0.25);
+        assert_eq!(20.0.in_pixels(&nested), 5);
+
+        // The floating point sizes are rounded like the integer ones
+        assert_eq!(15.0.in_pixels(&nested), 4);
+        assert_eq!(15.0f32.in_pixels(&nested), 4);
+        assert_eq!(2.6.in_pixels(&nested), 1);
+    }
 }
@@ -134,7 +134,102 @@ impl<'a, T: IntoTextStyle<'a>> IntoTextStyle<'a> for TextStyleBuilder<'a, T> {
     }
 }
 
+/**
+A fluent builder of [`TextStyle`], created by [`TextStyle::builder()`].
+
+Every property that is not specified keeps the same default as the other conversions:
+a 12 pixel, normal, sans-serif black font anchored at the left top corner.
+
+```rust
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+let style = TextStyle::builder()
+    .family("serif")
+    .size(20)
+    .color(&RED)
+    .anchor(Pos::new(HPos::Center, VPos::Center))
+    .transform(FontTransform::Rotate90)
+    .build();
+assert_eq!(style.font.get_size(), 20.0);
+```
+*/
+#[derive(Clone)]
+pub struct TextStyleSpec<'a> {
+    family: FontFamily<'a>,
+    size: f64,
+    style: FontStyle,
+    transform: FontTransform,
+    color: BackendColor,
+    pos: text_anchor::Pos,
+}
+
+impl<'a> TextStyleSpec<'a> {
+    /// Sets the font family
+    pub fn family<F: Into<FontFamily<'a>>>(mut self, family: F) -> Self {
+        self.family = family.into();
+        self
+    }
+
+    /// Sets the font size in pixels
+    pub fn size<S: Into<f64>>(mut self, size: S) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Sets the font style, for example bold or italic
+    pub fn style<S: Into<FontStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the font transformation
+    pub fn transform(mut self, trans: FontTransform) -> Self {
+        self.transform = trans;
+        self
+    }
+
+    /// Sets the text color
+    pub fn color<C: Color>(mut self, color: &C) -> Self {
+        self.color = color.to_backend_color();
+        self
+    }
+
+    /// Sets the position of the text anchor relative to the text element
+    pub fn anchor(mut self, pos: text_anchor::Pos) -> Self {
+        self.pos = pos;
+        self
+    }
+
+    /// Builds the text style
+    pub fn build(self) -> TextStyle<'a> {
+        TextStyle {
+            font: FontDesc::new(self.family, self.size, self.style).transform(self.transform),
+            color: self.color,
+            pos: self.pos,
+        }
+    }
+}
+
+impl<'a> IntoTextStyle<'a> for TextStyleSpec<'a> {
+    fn into_text_style<P: HasDimension>(self, _: &P) -> TextStyle<'a> {
+        self.build()
+    }
+}
+
 impl<'a> TextStyle<'a> {
+    /// Creates a builder of the text style, see [`TextStyleSpec`] for details.
+    pub fn builder() -> TextStyleSpec<'a> {
+        TextStyleSpec {
+            family: FontFamily::SansSerif,
+            size: 12.0,
+            style: FontStyle::Normal,
+            transform: FontTransform::None,
+            color: BLACK.to_backend_color(),
+            pos: text_anchor::Pos::default(),
+        }
+    }
+
     /// Sets the color of the style.
     ///
     /// - `color`: The required color
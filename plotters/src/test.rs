@@ -20,3 +20,40 @@ fn from_trait_impl_rgba_color() {
 
     assert_eq!(c.rgb(), rgb.rgb());
 }
+
+#[test]
+fn test_deterministic_output() {
+    fn draw(root: &DrawingArea<crate::drawing::MockedBackend, crate::coord::Shift>) {
+        root.fill(&WHITE).unwrap();
+        let mut chart = ChartBuilder::on(root)
+            .caption("Deterministic", ("sans-serif", 20))
+            .set_all_label_area_size(30)
+            .build_cartesian_2d((0u32..20u32).into_segmented(), 0u32..20u32)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(RED.filled())
+                    .data((0..100).map(|x| ((x * 7) % 20, 1))),
+            )
+            .unwrap()
+            .label("Histogram")
+            .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], RED.filled()));
+        chart
+            .draw_series(LineSeries::new(
+                (0..20).map(|x| (SegmentValue::CenterOf(x), x)),
+                &BLUE,
+            ))
+            .unwrap()
+            .label("Line")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], BLUE));
+        chart.configure_series_labels().draw().unwrap();
+    }
+
+    let first = record_draw_calls(400, 300, draw);
+    assert!(!first.is_empty());
+    for _ in 0..5 {
+        assert_eq!(record_draw_calls(400, 300, draw), first);
+    }
+}